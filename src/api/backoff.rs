@@ -0,0 +1,97 @@
+//! Reconnect backoff for the streaming providers
+//!
+//! Exponential delays (base, 2x base, 4x base, ... up to the cap) with random
+//! jitter so clients that dropped together don't reconnect in lockstep.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::config::ReconnectConfig;
+
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    /// A connection that stays up at least this long resets the delay to `base`
+    reset_after: Duration,
+    /// Delay before jitter for the next reconnect
+    current: Duration,
+}
+
+impl Backoff {
+    pub fn new(config: &ReconnectConfig) -> Self {
+        let base = Duration::from_millis(config.base_ms.max(1));
+        Self {
+            base,
+            max: Duration::from_millis(config.max_ms).max(base),
+            reset_after: Duration::from_secs(config.reset_after_secs),
+            current: base,
+        }
+    }
+
+    /// Record how long the last connection stayed up (None = never connected)
+    pub fn connection_ended(&mut self, uptime: Option<Duration>) {
+        if uptime.is_some_and(|up| up >= self.reset_after) {
+            self.current = self.base;
+        }
+    }
+
+    /// Delay to wait before the next attempt; doubles the following one up to the cap
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = (self.current * 2).min(self.max);
+
+        // Up to +50% jitter, still never past the cap
+        let jitter = delay.mul_f64(rand::thread_rng().gen_range(0.0..0.5));
+        (delay + jitter).min(self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backoff(base_ms: u64, max_ms: u64, reset_after_secs: u64) -> Backoff {
+        Backoff::new(&ReconnectConfig {
+            base_ms,
+            max_ms,
+            reset_after_secs,
+        })
+    }
+
+    /// Delay with jitter stays within [expected, 1.5x expected] and under the cap
+    fn assert_delay(delay: Duration, expected_ms: u64, max_ms: u64) {
+        let ms = delay.as_millis() as u64;
+        assert!(
+            ms >= expected_ms && ms <= (expected_ms * 3 / 2).min(max_ms),
+            "{}ms outside {}ms + jitter (cap {}ms)",
+            ms,
+            expected_ms,
+            max_ms
+        );
+    }
+
+    #[test]
+    fn delays_double_up_to_the_cap() {
+        let mut backoff = backoff(10, 50, 1);
+        for expected in [10, 20, 40, 50, 50] {
+            assert_delay(backoff.next_delay(), expected, 50);
+        }
+    }
+
+    #[test]
+    fn only_a_long_enough_connection_resets_the_delay() {
+        let mut backoff = backoff(10, 1000, 1);
+        backoff.next_delay();
+        backoff.next_delay();
+
+        // Dropped before the reset threshold, or never connected: keep escalating
+        backoff.connection_ended(Some(Duration::from_millis(500)));
+        assert_delay(backoff.next_delay(), 40, 1000);
+        backoff.connection_ended(None);
+        assert_delay(backoff.next_delay(), 80, 1000);
+
+        backoff.connection_ended(Some(Duration::from_secs(1)));
+        assert_delay(backoff.next_delay(), 10, 1000);
+    }
+}
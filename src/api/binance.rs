@@ -2,11 +2,16 @@ use futures_util::{SinkExt, StreamExt};
 use serde::Deserialize;
 use std::env;
 use std::sync::RwLock;
-use std::time::Duration;
+use std::time::Instant;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::backoff::Backoff;
 use super::{Candle, PriceUpdate};
+use crate::config::ReconnectConfig;
+
+/// Provider name reported with connection status updates
+const NAME: &str = "binance";
 
 /// Get the appropriate Binance WebSocket URL based on environment
 fn get_binance_ws_url() -> String {
@@ -100,6 +105,7 @@ struct RawStreamMessage {
 pub struct BinanceProvider {
     pairs: Vec<String>,
     current_interval: RwLock<String>,
+    reconnect: ReconnectConfig,
 }
 
 impl BinanceProvider {
@@ -107,24 +113,38 @@ impl BinanceProvider {
         Self {
             pairs,
             current_interval: RwLock::new(initial_interval.to_string()),
+            reconnect: ReconnectConfig::default(),
         }
     }
 
+    /// Use `reconnect` for the delays between reconnect attempts
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
     /// Run the WebSocket connection and send updates through the channel
     pub async fn run(self, tx: mpsc::Sender<PriceUpdate>, mut interval_rx: mpsc::Receiver<String>) {
+        let mut backoff = Backoff::new(&self.reconnect);
         loop {
-            match self.connect_and_stream(&tx, &mut interval_rx).await {
+            let mut connected_at = None;
+            match self
+                .connect_and_stream(&tx, &mut interval_rx, &mut connected_at)
+                .await
+            {
                 Ok(_) => {
-                    let _ = tx.send(PriceUpdate::Disconnected).await;
+                    let _ = tx.send(PriceUpdate::Disconnected { provider: NAME }).await;
                 }
                 Err(e) => {
                     let _ = tx.send(PriceUpdate::Error(e.to_string())).await;
-                    let _ = tx.send(PriceUpdate::Disconnected).await;
+                    let _ = tx.send(PriceUpdate::Disconnected { provider: NAME }).await;
                 }
             }
 
-            // Wait before reconnecting
-            tokio::time::sleep(Duration::from_secs(5)).await;
+            // Wait before reconnecting (exponential backoff, reset after a stable connection)
+            backoff.connection_ended(connected_at.map(|at: Instant| at.elapsed()));
+            tokio::time::sleep(backoff.next_delay()).await;
+            let _ = tx.send(PriceUpdate::Reconnecting { provider: NAME }).await;
         }
     }
 
@@ -132,6 +152,7 @@ impl BinanceProvider {
         &self,
         tx: &mpsc::Sender<PriceUpdate>,
         interval_rx: &mut mpsc::Receiver<String>,
+        connected_at: &mut Option<Instant>,
     ) -> anyhow::Result<()> {
         // Build combined stream: ticker + kline for all pairs
         let current = self.current_interval.read().unwrap().clone();
@@ -153,7 +174,8 @@ impl BinanceProvider {
         let (mut write, mut read) = ws_stream.split();
 
         // Send connected status
-        tx.send(PriceUpdate::Connected).await?;
+        tx.send(PriceUpdate::Connected { provider: NAME }).await?;
+        *connected_at = Some(Instant::now());
 
         // Process incoming messages and interval changes
         loop {
@@ -275,6 +297,8 @@ impl BinanceProvider {
                 low: parse_string_number(&serde_json::Value::String(k.low)),
                 close: parse_string_number(&serde_json::Value::String(k.close)),
                 volume: parse_string_number(&serde_json::Value::String(k.volume)),
+                // Not parsed from the stream; REST history fills them in
+                ..Default::default()
             };
 
             let symbol = data.symbol.trim_end_matches("USDT").to_string();
@@ -292,17 +316,29 @@ impl BinanceProvider {
     }
 }
 
-/// Fetch historical candle data from Binance REST API
+/// Default number of candles fetched for a full history load
+pub const FULL_CANDLE_LIMIT: u32 = 300;
+
+/// Most klines Binance returns for a single request
+pub const MAX_CANDLE_LIMIT: u32 = 1000;
+
+/// Fetch the latest `limit` candles from Binance REST API (clamped to `MAX_CANDLE_LIMIT`)
 /// Returns candles in chronological order (oldest first)
-pub async fn fetch_candles(symbol: &str, interval: &str) -> anyhow::Result<Vec<Candle>> {
+pub async fn fetch_candles(
+    symbol: &str,
+    interval: &str,
+    limit: u32,
+) -> anyhow::Result<Vec<Candle>> {
+    let limit = limit.clamp(1, MAX_CANDLE_LIMIT);
     let url = format!(
-        "{}/api/v3/klines?symbol={}&interval={}&limit=300",
+        "{}/api/v3/klines?symbol={}&interval={}&limit={}",
         get_binance_rest_url(),
         symbol,
-        interval
+        interval,
+        limit
     );
 
-    let resp = reqwest::get(&url).await?;
+    let resp = reqwest::get(&url).await?.error_for_status()?;
     let data: Vec<Vec<serde_json::Value>> = resp.json().await?;
 
     // Binance returns: [open_time, open, high, low, close, volume, close_time, quote_volume, trades, ...]
@@ -319,6 +355,8 @@ pub async fn fetch_candles(symbol: &str, interval: &str) -> anyhow::Result<Vec<C
                 low: parse_string_number(&c[3]),
                 close: parse_string_number(&c[4]),
                 volume: parse_string_number(&c[5]),
+                quote_volume: c.get(7).map(parse_string_number).unwrap_or(0.0),
+                trades: c.get(8).and_then(|t| t.as_u64()).unwrap_or(0),
             })
         })
         .collect();
@@ -327,14 +365,51 @@ pub async fn fetch_candles(symbol: &str, interval: &str) -> anyhow::Result<Vec<C
     Ok(candles)
 }
 
+/// Kline intervals supported by Binance and their length in seconds
+/// ("1M" is left out because months have no fixed length)
+pub const SUPPORTED_INTERVALS: [(&str, u32); 14] = [
+    ("1m", 60),
+    ("3m", 180),
+    ("5m", 300),
+    ("15m", 900),
+    ("30m", 1800),
+    ("1h", 3600),
+    ("2h", 7200),
+    ("4h", 14400),
+    ("6h", 21600),
+    ("8h", 28800),
+    ("12h", 43200),
+    ("1d", 86400),
+    ("3d", 259200),
+    ("1w", 604800),
+];
+
+/// Weekly klines open on Monday 00:00 UTC; the Unix epoch fell on a Thursday
+const WEEK_OPEN_OFFSET_SECS: i64 = 4 * 86400;
+
 /// Map TimeWindow granularity to Binance interval string
 pub fn granularity_to_interval(granularity: u32) -> &'static str {
-    match granularity {
-        900 => "15m",
-        3600 => "1h",
-        14400 => "4h",
-        86400 => "1d",
-        _ => "1h",
+    SUPPORTED_INTERVALS
+        .iter()
+        .find(|(_, secs)| *secs == granularity)
+        .map(|(interval, _)| *interval)
+        .unwrap_or("1h")
+}
+
+/// Look up a Binance interval string, returning its canonical name and length in seconds
+pub fn lookup_interval(interval: &str) -> Option<(&'static str, u32)> {
+    SUPPORTED_INTERVALS
+        .iter()
+        .find(|(name, _)| *name == interval)
+        .copied()
+}
+
+/// Offset of candle boundaries from the Unix epoch for the given interval
+pub fn candle_open_offset(granularity: u32) -> i64 {
+    if granularity == 604800 {
+        WEEK_OPEN_OFFSET_SECS
+    } else {
+        0
     }
 }
 
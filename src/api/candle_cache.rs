@@ -0,0 +1,158 @@
+//! On-disk cache of REST candle history
+//!
+//! One JSON file per (pair, interval) under `candle_cache/`. A fresh entry is
+//! served without touching the network; a stale one only needs the candles
+//! since its last bar, which are fetched and merged back in. Anything that
+//! can't be read is treated as a miss.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::Candle;
+use crate::clock::unix_now;
+
+/// Cache directory (relative to the working directory, like news_cache.db)
+const CACHE_DIR: &str = "candle_cache";
+
+/// Upper bound on an entry's freshness, so long intervals still refresh hourly
+const MAX_TTL_SECS: i64 = 3600;
+
+#[derive(Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Unix seconds of the last write
+    pub fetched_at: i64,
+    pub candles: Vec<Candle>,
+    /// A full history request returned fewer candles than asked for, so the
+    /// series already reaches back to the exchange's first bar
+    #[serde(default)]
+    pub complete: bool,
+}
+
+pub struct CandleCache {
+    dir: PathBuf,
+}
+
+impl CandleCache {
+    pub fn new() -> Self {
+        Self {
+            dir: PathBuf::from(CACHE_DIR),
+        }
+    }
+
+    fn path(&self, pair: &str, granularity: u32) -> PathBuf {
+        self.dir.join(format!("{}_{}.json", pair, granularity))
+    }
+
+    /// Cached candles for a pair/interval (None on miss or unreadable file)
+    pub fn load(&self, pair: &str, granularity: u32) -> Option<CacheEntry> {
+        let contents = fs::read_to_string(self.path(pair, granularity)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+        (!entry.candles.is_empty()).then_some(entry)
+    }
+
+    /// Merge `candles` into the cached series (newer wins per timestamp),
+    /// keep the latest `limit` and write it back; returns the merged series
+    pub fn merge(
+        &self,
+        pair: &str,
+        granularity: u32,
+        candles: Vec<Candle>,
+        limit: u32,
+    ) -> Vec<Candle> {
+        let (cached, complete) = self
+            .load(pair, granularity)
+            .map(|entry| (entry.candles, entry.complete))
+            .unwrap_or_default();
+        let merged = merge_candles(cached, candles, limit);
+        self.store(pair, granularity, &merged, complete);
+        merged
+    }
+
+    /// Replace the cached series for a pair/interval (`complete`: see `CacheEntry`)
+    pub fn store(&self, pair: &str, granularity: u32, candles: &[Candle], complete: bool) {
+        let entry = CacheEntryRef {
+            fetched_at: now_secs(),
+            candles,
+            complete,
+        };
+        let result = fs::create_dir_all(&self.dir)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| Ok(serde_json::to_string(&entry)?))
+            .and_then(|json| Ok(fs::write(self.path(pair, granularity), json)?));
+        if let Err(e) = result {
+            eprintln!("Failed to write candle cache for {}: {}", pair, e);
+        }
+    }
+}
+
+/// Borrowing twin of `CacheEntry` so writes don't clone the series
+#[derive(Serialize)]
+struct CacheEntryRef<'a> {
+    fetched_at: i64,
+    candles: &'a [Candle],
+    complete: bool,
+}
+
+impl CacheEntry {
+    /// Whether the entry can stand in for a full history of `limit` candles:
+    /// it has that many, or the exchange had no more to give
+    pub fn covers(&self, limit: u32) -> bool {
+        self.complete || self.candles.len() >= limit as usize
+    }
+
+    /// Whether the entry is recent enough to skip the network: one interval,
+    /// capped at an hour (1m candles go stale after a minute, 1d after an hour)
+    pub fn is_fresh(&self, granularity: u32) -> bool {
+        let ttl = (granularity as i64).min(MAX_TTL_SECS);
+        now_secs() - self.fetched_at < ttl
+    }
+
+    /// Candles needed to bring the entry up to date (the last cached bar,
+    /// which may have been open when written, plus every bar since)
+    pub fn missing_count(&self, granularity: u32) -> u32 {
+        let last = self.candles.last().map(|c| c.time).unwrap_or(0);
+        let elapsed = (now_secs() - last).max(0);
+        (elapsed / granularity.max(1) as i64 + 1).min(u32::MAX as i64) as u32
+    }
+}
+
+/// Union of two series by timestamp (`newer` wins), sorted, latest `limit` kept
+pub fn merge_candles(older: Vec<Candle>, newer: Vec<Candle>, limit: u32) -> Vec<Candle> {
+    let mut by_time: BTreeMap<i64, Candle> = older.into_iter().map(|c| (c.time, c)).collect();
+    by_time.extend(newer.into_iter().map(|c| (c.time, c)));
+    let skip = by_time.len().saturating_sub(limit as usize);
+    by_time.into_values().skip(skip).collect()
+}
+
+fn now_secs() -> i64 {
+    unix_now() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(count: usize, complete: bool) -> CacheEntry {
+        CacheEntry {
+            fetched_at: 0,
+            candles: vec![Candle::default(); count],
+            complete,
+        }
+    }
+
+    #[test]
+    fn short_series_covers_the_limit_only_when_complete() {
+        assert!(entry(300, false).covers(300));
+        assert!(!entry(120, false).covers(300));
+        assert!(entry(120, true).covers(300));
+    }
+
+    #[test]
+    fn entries_written_before_the_flag_read_as_incomplete() {
+        let entry: CacheEntry = serde_json::from_str(r#"{"fetched_at":0,"candles":[]}"#).unwrap();
+        assert!(!entry.complete);
+    }
+}
@@ -6,11 +6,18 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::binance::candle_open_offset;
 use super::{Candle, PriceUpdate};
 
+/// Provider name reported with connection status updates
+const NAME: &str = "coinbase";
+
 const COINBASE_WS_URL: &str = "wss://ws-feed.exchange.coinbase.com";
 const COINBASE_REST_URL: &str = "https://api.exchange.coinbase.com";
 
+/// Candle sizes in seconds accepted by the candles endpoint
+const SUPPORTED_GRANULARITIES: [u32; 6] = [60, 300, 900, 3600, 21600, 86400];
+
 #[derive(Serialize)]
 struct SubscribeMessage {
     #[serde(rename = "type")]
@@ -52,16 +59,17 @@ impl CoinbaseProvider {
             match self.connect_and_stream(&tx).await {
                 Ok(_) => {
                     // Connection closed normally
-                    let _ = tx.send(PriceUpdate::Disconnected).await;
+                    let _ = tx.send(PriceUpdate::Disconnected { provider: NAME }).await;
                 }
                 Err(e) => {
                     let _ = tx.send(PriceUpdate::Error(e.to_string())).await;
-                    let _ = tx.send(PriceUpdate::Disconnected).await;
+                    let _ = tx.send(PriceUpdate::Disconnected { provider: NAME }).await;
                 }
             }
 
             // Wait before reconnecting
             tokio::time::sleep(Duration::from_secs(5)).await;
+            let _ = tx.send(PriceUpdate::Reconnecting { provider: NAME }).await;
         }
     }
 
@@ -70,7 +78,7 @@ impl CoinbaseProvider {
         let (mut write, mut read) = ws_stream.split();
 
         // Send connected status
-        tx.send(PriceUpdate::Connected).await?;
+        tx.send(PriceUpdate::Connected { provider: NAME }).await?;
 
         // Subscribe to ticker channel
         let subscribe = SubscribeMessage {
@@ -144,15 +152,30 @@ impl CoinbaseProvider {
     }
 }
 
+/// Coinbase candle size for a window of `granularity` seconds
+///
+/// Coinbase takes the size in seconds but only accepts a few of them; this is
+/// the largest supported size that evenly divides `granularity` (60 if none).
+pub fn granularity_to_coinbase(granularity: u32) -> u32 {
+    SUPPORTED_GRANULARITIES
+        .iter()
+        .rev()
+        .copied()
+        .find(|&g| granularity.is_multiple_of(g))
+        .unwrap_or(SUPPORTED_GRANULARITIES[0])
+}
+
 /// Fetch historical candle data from Coinbase REST API
-/// Returns candles in chronological order (oldest first)
+/// Returns candles in chronological order (oldest first); sizes Coinbase
+/// doesn't serve (e.g. 4h) are built from smaller candles
 pub async fn fetch_candles(product_id: &str, granularity: u32) -> anyhow::Result<Vec<Candle>> {
+    let source_granularity = granularity_to_coinbase(granularity);
     let url = format!(
         "{}/products/{}/candles?granularity={}",
-        COINBASE_REST_URL, product_id, granularity
+        COINBASE_REST_URL, product_id, source_granularity
     );
 
-    let resp = reqwest::get(&url).await?;
+    let resp = reqwest::get(&url).await?.error_for_status()?;
     let data: Vec<Vec<serde_json::Value>> = resp.json().await?;
 
     // Coinbase returns [time, low, high, open, close, volume] arrays
@@ -170,15 +193,56 @@ pub async fn fetch_candles(product_id: &str, granularity: u32) -> anyhow::Result
                 open: parse_number(&c[3]),
                 close: parse_number(&c[4]),
                 volume: parse_number(&c[5]),
+                ..Default::default()
             })
         })
         .collect();
 
     // Reverse to get chronological order (oldest first)
     candles.reverse();
+
+    if source_granularity < granularity {
+        candles = aggregate_candles(&candles, granularity);
+    }
     Ok(candles)
 }
 
+/// Merge chronological candles into buckets of `granularity` seconds
+///
+/// Buckets are aligned to the interval boundary. The first one is dropped when
+/// the history starts partway into it, since its open, high and low would only
+/// cover part of the period.
+fn aggregate_candles(candles: &[Candle], granularity: u32) -> Vec<Candle> {
+    let size = granularity as i64;
+    let offset = candle_open_offset(granularity);
+    let mut merged: Vec<Candle> = Vec::new();
+
+    for candle in candles {
+        let bucket = (candle.time - offset).div_euclid(size) * size + offset;
+        match merged.last_mut() {
+            Some(last) if last.time == bucket => {
+                last.high = last.high.max(candle.high);
+                last.low = last.low.min(candle.low);
+                last.close = candle.close;
+                last.volume += candle.volume;
+            }
+            _ => merged.push(Candle {
+                time: bucket,
+                ..candle.clone()
+            }),
+        }
+    }
+
+    let starts_partway = candles
+        .first()
+        .zip(merged.first())
+        .is_some_and(|(first, bucket)| first.time != bucket.time);
+    if starts_partway {
+        merged.remove(0);
+    }
+    merged
+}
+
 /// Parse a JSON value as f64 (handles both string and number formats)
 fn parse_number(val: &serde_json::Value) -> f64 {
     match val {
@@ -187,3 +251,47 @@ fn parse_number(val: &serde_json::Value) -> f64 {
         _ => 0.0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hourly candles starting at `start`, each closing one higher
+    fn hourly(start: i64, count: usize) -> Vec<Candle> {
+        (0..count)
+            .map(|i| {
+                let price = 100.0 + i as f64;
+                Candle {
+                    time: start + i as i64 * 3600,
+                    open: price,
+                    high: price + 0.5,
+                    low: price - 0.5,
+                    close: price,
+                    volume: 1.0,
+                    ..Candle::default()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn aggregated_buckets_are_aligned_and_partial_first_one_dropped() {
+        const FOUR_HOURS: i64 = 4 * 3600;
+        // Starts at 02:00, halfway into the 00:00-04:00 bucket
+        let merged = aggregate_candles(&hourly(2 * 3600, 10), 14400);
+        let times: Vec<i64> = merged.iter().map(|c| c.time).collect();
+        assert_eq!(times, [FOUR_HOURS, 2 * FOUR_HOURS]);
+
+        let first = &merged[0];
+        assert_eq!((first.open, first.close), (102.0, 105.0));
+        assert_eq!((first.low, first.high), (101.5, 105.5));
+        assert_eq!(first.volume, 4.0);
+    }
+
+    #[test]
+    fn history_starting_on_a_boundary_keeps_its_first_bucket() {
+        let merged = aggregate_candles(&hourly(0, 8), 14400);
+        let times: Vec<i64> = merged.iter().map(|c| c.time).collect();
+        assert_eq!(times, [0, 14400]);
+    }
+}
@@ -32,9 +32,115 @@ pub struct MarginPosition {
 
     // Market data
     pub current_price: f64,
+    /// Mark price when this session first saw the position on its current side
+    /// (session PnL baseline; None until a valid mark is known)
+    pub session_entry: Option<f64>,
     pub total_value_usd: f64,
     pub borrowed_value_usd: f64,
     pub net_value_usd: f64,
+    /// PnL since `session_entry`; Binance reports no entry price, so this is not
+    /// the position's unrealized PnL
+    pub session_pnl: Option<f64>,
+}
+
+/// Direction of a margin position, derived from the net asset amount
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PositionSide {
+    /// Holding more than borrowed - gains when price rises
+    Long,
+    /// Borrowed more than held (sold short) - gains when price falls
+    Short,
+    /// Net zero exposure
+    Flat,
+}
+
+impl PositionSide {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PositionSide::Long => "LONG",
+            PositionSide::Short => "SHORT",
+            PositionSide::Flat => "FLAT",
+        }
+    }
+}
+
+/// Net asset amounts below this are treated as flat (dust)
+const FLAT_EPSILON: f64 = 1e-8;
+
+/// Assets always valued at 1.0 USD (cash, not open exposure)
+const STABLECOINS: [&str; 6] = ["USDT", "BUSD", "USDC", "TUSD", "USDP", "DAI"];
+
+impl MarginPosition {
+    /// Side of the position: short when borrowed exceeds holdings
+    pub fn side(&self) -> PositionSide {
+        if self.net_asset > FLAT_EPSILON {
+            PositionSide::Long
+        } else if self.net_asset < -FLAT_EPSILON {
+            PositionSide::Short
+        } else {
+            PositionSide::Flat
+        }
+    }
+
+    /// Whether the asset is a USD stablecoin (cash rather than a position)
+    pub fn is_stablecoin(&self) -> bool {
+        STABLECOINS.contains(&self.asset.as_str())
+    }
+
+    /// Session PnL at `mark_price` against `session_entry` (None without an entry)
+    ///
+    /// `net_asset` carries the side: a short (negative) gains as the mark falls.
+    pub fn session_pnl_at(&self, mark_price: f64) -> Option<f64> {
+        self.session_entry
+            .map(|entry| self.net_asset * (mark_price - entry))
+    }
+
+    /// Session PnL as a percentage of the entry notional (None without an entry)
+    pub fn session_pnl_percent(&self) -> Option<f64> {
+        let entry_notional = self.net_asset.abs() * self.session_entry?;
+        if entry_notional > 0.0 {
+            Some(finite_or_zero(self.session_pnl? / entry_notional * 100.0))
+        } else {
+            Some(0.0)
+        }
+    }
+
+    /// Revalue the USD fields at a new mark price; the first valid mark becomes
+    /// the session entry
+    fn reprice(&mut self, mark_price: f64) {
+        self.current_price = mark_price;
+        self.total_value_usd = (self.free + self.locked) * mark_price;
+        self.borrowed_value_usd = (self.borrowed + self.interest) * mark_price;
+        self.net_value_usd = self.net_asset * mark_price;
+        if self.session_entry.is_none() && mark_price.is_finite() && mark_price > 0.0 {
+            self.session_entry = Some(mark_price);
+        }
+        self.session_pnl = self.session_pnl_at(mark_price);
+    }
+}
+
+/// How close the account is to liquidation, judged by its margin level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LiquidationRisk {
+    /// Nothing borrowed
+    None,
+    /// Margin level above 2.0
+    Low,
+    /// Margin level above 1.5
+    Elevated,
+    /// Margin level 1.5 or below (Binance margin calls at 1.1)
+    High,
+}
+
+impl LiquidationRisk {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LiquidationRisk::None => "NONE",
+            LiquidationRisk::Low => "LOW",
+            LiquidationRisk::Elevated => "ELEVATED",
+            LiquidationRisk::High => "HIGH",
+        }
+    }
 }
 
 /// Margin account summary
@@ -48,6 +154,101 @@ pub struct MarginAccount {
     pub account_type: String, // "Cross Margin"
 }
 
+impl MarginAccount {
+    /// Session PnL of the non-stablecoin positions (None while any entry is unknown)
+    pub fn total_session_pnl(&self) -> Option<f64> {
+        let total: Option<f64> = self.open_positions().map(|p| p.session_pnl).sum();
+        total.map(finite_or_zero)
+    }
+
+    /// Gross exposure: absolute net value of each non-stablecoin position
+    pub fn total_notional(&self) -> f64 {
+        finite_or_zero(self.open_positions().map(|p| p.net_value_usd.abs()).sum())
+    }
+
+    /// Total assets minus total liabilities (USD)
+    pub fn equity(&self) -> f64 {
+        finite_or_zero(self.total_asset_usd - self.total_liability_usd)
+    }
+
+    /// Liabilities as a fraction of assets (0.0 with no assets)
+    pub fn margin_ratio(&self) -> f64 {
+        if self.total_asset_usd > 0.0 {
+            finite_or_zero(self.total_liability_usd / self.total_asset_usd)
+        } else {
+            0.0
+        }
+    }
+
+    pub fn liquidation_risk(&self) -> LiquidationRisk {
+        if self.total_liability_usd <= 0.0 {
+            LiquidationRisk::None
+        } else if self.margin_level > 2.0 {
+            LiquidationRisk::Low
+        } else if self.margin_level > 1.5 {
+            LiquidationRisk::Elevated
+        } else {
+            LiquidationRisk::High
+        }
+    }
+
+    /// Revalue `asset` at a live ticker price and recompute the account totals
+    ///
+    /// Keeps the view moving between account snapshots; the next
+    /// `PriceUpdate::MarginPositions` replaces these estimates with reported values.
+    /// Returns whether a position matched.
+    pub fn apply_mark_price(&mut self, asset: &str, mark_price: f64) -> bool {
+        if !mark_price.is_finite() || mark_price <= 0.0 {
+            return false;
+        }
+        let Some(position) = self.positions.iter_mut().find(|p| p.asset == asset) else {
+            return false;
+        };
+        position.reprice(mark_price);
+
+        self.total_asset_usd = self.positions.iter().map(|p| p.total_value_usd).sum();
+        self.total_liability_usd = self.positions.iter().map(|p| p.borrowed_value_usd).sum();
+        self.total_net_usd = self.positions.iter().map(|p| p.net_value_usd).sum();
+        // Binance margin level: total assets / (borrowed + interest)
+        if self.total_liability_usd > 0.0 {
+            self.margin_level = self.total_asset_usd / self.total_liability_usd;
+        }
+        true
+    }
+
+    /// Keep the session entries of `previous` for positions still held on the same side
+    ///
+    /// Binance reports balances only, so the entry is the mark at the first snapshot
+    /// showing the position; a new or flipped position starts from the current mark.
+    pub fn carry_session_entries(&mut self, previous: &MarginAccount) {
+        for position in &mut self.positions {
+            if let Some(prev) = previous
+                .positions
+                .iter()
+                .find(|p| p.asset == position.asset && p.side() == position.side())
+            {
+                if prev.session_entry.is_some() {
+                    position.session_entry = prev.session_entry;
+                }
+                position.reprice(position.current_price);
+            }
+        }
+    }
+
+    fn open_positions(&self) -> impl Iterator<Item = &MarginPosition> {
+        self.positions.iter().filter(|p| !p.is_stablecoin())
+    }
+}
+
+/// Replace NaN/infinite totals (e.g. from a malformed balance) with 0.0
+fn finite_or_zero(value: f64) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
 /// Binance API response structures
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -88,9 +289,6 @@ fn parse_f64(s: &str) -> f64 {
 async fn fetch_asset_prices(assets: &[String]) -> Result<std::collections::HashMap<String, f64>> {
     let mut prices = std::collections::HashMap::new();
 
-    // Stablecoins are always 1.0 USD
-    let stablecoins = ["USDT", "BUSD", "USDC", "TUSD", "USDP", "DAI"];
-
     // Fetch ticker prices for all trading pairs
     let url = format!("{}/api/v3/ticker/price", get_binance_api_url());
     let response: Vec<TickerPrice> = reqwest::get(&url).await?.json().await?;
@@ -98,7 +296,7 @@ async fn fetch_asset_prices(assets: &[String]) -> Result<std::collections::HashM
     // Map asset to USDT price
     for asset in assets {
         // Stablecoins are always 1.0
-        if stablecoins.contains(&asset.as_str()) {
+        if STABLECOINS.contains(&asset.as_str()) {
             prices.insert(asset.clone(), 1.0);
             continue;
         }
@@ -132,26 +330,23 @@ fn calculate_position_metrics(
     let locked = parse_f64(&asset.locked);
     let net_asset = parse_f64(&asset.net_asset);
 
-    // Total holdings in this asset
-    let total = free + locked;
-
-    // USD values
-    let total_value_usd = total * current_price;
-    let borrowed_value_usd = (borrowed + interest) * current_price;
-    let net_value_usd = net_asset * current_price;
-
-    MarginPosition {
+    let mut position = MarginPosition {
         asset: asset.asset.clone(),
         borrowed,
         free,
         interest,
         locked,
         net_asset,
-        current_price,
-        total_value_usd,
-        borrowed_value_usd,
-        net_value_usd,
-    }
+        current_price: 0.0,
+        session_entry: None,
+        total_value_usd: 0.0,
+        borrowed_value_usd: 0.0,
+        net_value_usd: 0.0,
+        session_pnl: None,
+    };
+    // USD values
+    position.reprice(current_price);
+    position
 }
 
 /// Fetch margin account data from Binance API
@@ -251,3 +446,102 @@ pub async fn fetch_margin_account(api_key: &str, api_secret: &str) -> Result<Mar
         account_type: "Cross Margin".to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(asset: &str, net_asset: f64, entry: f64) -> MarginPosition {
+        MarginPosition {
+            asset: asset.to_string(),
+            borrowed: 0.0,
+            free: net_asset.max(0.0),
+            interest: 0.0,
+            locked: 0.0,
+            net_asset,
+            current_price: entry,
+            session_entry: Some(entry),
+            total_value_usd: 0.0,
+            borrowed_value_usd: 0.0,
+            net_value_usd: 0.0,
+            session_pnl: Some(0.0),
+        }
+    }
+
+    fn account(positions: Vec<MarginPosition>) -> MarginAccount {
+        MarginAccount {
+            margin_level: 999.0,
+            total_asset_usd: 0.0,
+            total_liability_usd: 0.0,
+            total_net_usd: 0.0,
+            positions,
+            account_type: "Cross Margin".to_string(),
+        }
+    }
+
+    #[test]
+    fn pnl_is_measured_from_entry_and_follows_side() {
+        let long = position("BTC", 2.0, 100.0);
+        assert_eq!(long.session_pnl_at(100.0), Some(0.0));
+        assert_eq!(long.session_pnl_at(110.0), Some(20.0));
+        assert_eq!(long.session_pnl_at(90.0), Some(-20.0));
+
+        let short = position("ETH", -2.0, 100.0);
+        assert_eq!(short.session_pnl_at(90.0), Some(20.0));
+        assert_eq!(short.session_pnl_at(110.0), Some(-20.0));
+    }
+
+    #[test]
+    fn pnl_is_unknown_until_a_valid_mark_sets_the_entry() {
+        let mut account = account(vec![MarginPosition {
+            session_entry: None,
+            session_pnl: None,
+            ..position("BTC", 1.0, 0.0)
+        }]);
+        let btc = &account.positions[0];
+        assert_eq!(btc.session_pnl_at(60_000.0), None);
+        assert_eq!(btc.session_pnl_percent(), None);
+        assert_eq!(account.total_session_pnl(), None);
+
+        assert!(!account.apply_mark_price("BTC", f64::NAN));
+        assert_eq!(account.positions[0].session_entry, None);
+        assert!(account.apply_mark_price("BTC", 60_000.0));
+        assert_eq!(account.positions[0].session_entry, Some(60_000.0));
+        assert_eq!(account.positions[0].session_pnl, Some(0.0));
+    }
+
+    #[test]
+    fn marking_updates_value_and_pnl_but_keeps_entry() {
+        let mut account = account(vec![position("BTC", 1.0, 60_000.0)]);
+        // Holding value is not profit
+        assert_eq!(account.total_session_pnl(), Some(0.0));
+        assert!(account.apply_mark_price("BTC", 61_000.0));
+        let btc = &account.positions[0];
+        assert_eq!(btc.session_entry, Some(60_000.0));
+        assert_eq!(btc.net_value_usd, 61_000.0);
+        assert_eq!(btc.session_pnl, Some(1_000.0));
+        let percent = btc.session_pnl_percent().unwrap();
+        assert!((percent - 1_000.0 / 60_000.0 * 100.0).abs() < 1e-9);
+        assert_eq!(account.total_session_pnl(), Some(1_000.0));
+    }
+
+    #[test]
+    fn snapshots_keep_entries_of_positions_held_on_the_same_side() {
+        let previous = account(vec![
+            position("BTC", 1.0, 60_000.0),
+            position("ETH", 2.0, 3_000.0),
+        ]);
+        let mut next = account(vec![
+            position("BTC", 1.5, 62_000.0),
+            position("ETH", -1.0, 3_100.0),
+            position("SOL", 10.0, 150.0),
+        ]);
+        next.carry_session_entries(&previous);
+
+        assert_eq!(next.positions[0].session_entry, Some(60_000.0));
+        assert_eq!(next.positions[0].session_pnl, Some(1.5 * 2_000.0));
+        // Flipped to short and newly opened positions start at the current mark
+        assert_eq!(next.positions[1].session_entry, Some(3_100.0));
+        assert_eq!(next.positions[2].session_entry, Some(150.0));
+    }
+}
@@ -1,10 +1,20 @@
+pub mod backoff;
 pub mod binance;
+pub mod candle_cache;
 pub mod coinbase;
 pub mod margin;
 pub mod news;
+pub mod provider;
+pub mod rate_limit;
+pub mod replay;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 
 /// OHLC candle data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct Candle {
     pub time: i64,
@@ -13,6 +23,22 @@ pub struct Candle {
     pub low: f64,
     pub close: f64,
     pub volume: f64,
+    /// Volume in the quote asset (Binance REST only, 0.0 otherwise)
+    pub quote_volume: f64,
+    /// Number of trades (Binance REST only, 0 otherwise)
+    pub trades: u64,
+}
+
+/// Request for historical candles from the REST fetcher task
+#[derive(Debug, Clone)]
+pub struct CandleRequest {
+    /// Exchange pair (e.g. "BTCUSDT")
+    pub pair: String,
+    /// Candle interval in seconds
+    pub granularity: u32,
+    /// Some(n): fetch only the latest n candles and merge them (candle-close refresh);
+    /// None: full history replace (startup / window change)
+    pub tail: Option<u32>,
 }
 
 /// Price update message from WebSocket or REST API
@@ -33,20 +59,137 @@ pub enum PriceUpdate {
         symbol: String,
         candles: Vec<Candle>,
     },
+    /// Latest candles to merge into the existing series (deduped by timestamp)
+    CandleTail {
+        symbol: String,
+        candles: Vec<Candle>,
+    },
     /// Real-time kline (candle) update from WebSocket
     Kline {
         symbol: String,
         candle: Candle,
         is_closed: bool,
     },
-    /// Connection status change
-    Connected,
-    Disconnected,
+    /// Connection status change, from the named provider
+    Connected {
+        provider: &'static str,
+    },
+    Disconnected {
+        provider: &'static str,
+    },
+    /// Waiting period over, about to reconnect
+    Reconnecting {
+        provider: &'static str,
+    },
     /// Error message
-    #[allow(dead_code)]
     Error(String),
     /// Margin account positions update
     MarginPositions {
         account: margin::MarginAccount,
     },
 }
+
+/// Take up to `max` pending updates (0 = all) without blocking, leaving the rest
+/// queued for the next frame, with superseded tickers dropped
+pub fn drain_updates(rx: &mut mpsc::Receiver<PriceUpdate>, max: usize) -> Vec<PriceUpdate> {
+    let limit = if max == 0 { usize::MAX } else { max };
+    let mut updates = Vec::new();
+    while updates.len() < limit {
+        match rx.try_recv() {
+            Ok(update) => updates.push(update),
+            Err(_) => break,
+        }
+    }
+    coalesce_tickers(updates)
+}
+
+/// Keep only the newest ticker per symbol; all other updates keep their order
+fn coalesce_tickers(updates: Vec<PriceUpdate>) -> Vec<PriceUpdate> {
+    let latest: HashMap<&str, usize> = updates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, update)| match update {
+            PriceUpdate::Ticker { symbol, .. } => Some((symbol.as_str(), i)),
+            _ => None,
+        })
+        .collect();
+    let keep: Vec<bool> = updates
+        .iter()
+        .enumerate()
+        .map(|(i, update)| match update {
+            PriceUpdate::Ticker { symbol, .. } => latest.get(symbol.as_str()) == Some(&i),
+            _ => true,
+        })
+        .collect();
+
+    updates
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(update, keep)| keep.then_some(update))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(symbol: &str, price: f64) -> PriceUpdate {
+        PriceUpdate::Ticker {
+            symbol: symbol.to_string(),
+            price,
+            change_24h: 0.0,
+            volume_24h_usd: 0.0,
+            volume_24h_base: 0.0,
+            high_24h: 0.0,
+            low_24h: 0.0,
+        }
+    }
+
+    fn summary(updates: &[PriceUpdate]) -> Vec<String> {
+        updates
+            .iter()
+            .map(|update| match update {
+                PriceUpdate::Ticker { symbol, price, .. } => format!("{symbol}@{price}"),
+                PriceUpdate::Candles { symbol, .. } => format!("candles {symbol}"),
+                other => format!("{other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn only_the_newest_ticker_per_symbol_is_kept() {
+        let updates = vec![
+            ticker("BTC", 1.0),
+            ticker("ETH", 10.0),
+            PriceUpdate::Candles {
+                symbol: "BTC".to_string(),
+                candles: Vec::new(),
+            },
+            ticker("BTC", 2.0),
+            PriceUpdate::Connected {
+                provider: "binance",
+            },
+            ticker("BTC", 3.0),
+        ];
+        assert_eq!(
+            summary(&coalesce_tickers(updates)),
+            [
+                "ETH@10",
+                "candles BTC",
+                "Connected { provider: \"binance\" }",
+                "BTC@3"
+            ]
+        );
+    }
+
+    #[test]
+    fn drain_leaves_updates_past_the_cap_queued() {
+        let (tx, mut rx) = mpsc::channel(8);
+        for price in 1..=5 {
+            tx.try_send(ticker("BTC", price as f64)).unwrap();
+        }
+        assert_eq!(summary(&drain_updates(&mut rx, 3)), ["BTC@3"]);
+        assert_eq!(summary(&drain_updates(&mut rx, 0)), ["BTC@5"]);
+        assert!(drain_updates(&mut rx, 0).is_empty());
+    }
+}
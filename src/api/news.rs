@@ -5,6 +5,8 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::Deserialize;
 
+use crate::mock::symbol_to_name;
+
 const NEWSDATA_URL: &str = "https://newsdata.io/api/1/crypto";
 
 /// A news article
@@ -17,6 +19,38 @@ pub struct NewsArticle {
     pub description: String,
 }
 
+impl NewsArticle {
+    /// Whether the title or description names any of `symbols`, by ticker or
+    /// full name (whole words, case-insensitive, so "SOL" doesn't match "solid")
+    pub fn mentions(&self, symbols: &[String]) -> bool {
+        let words: Vec<String> = words(&self.title).chain(words(&self.description)).collect();
+        symbols.iter().any(|symbol| {
+            contains_phrase(&words, symbol) || contains_phrase(&words, &symbol_to_name(symbol))
+        })
+    }
+
+    /// Whether `other` is the same story: matched by link, or by title when either lacks one
+    pub fn is_same_article(&self, other: &NewsArticle) -> bool {
+        match (&self.link, &other.link) {
+            (Some(link), Some(other_link)) => link == other_link,
+            _ => self.title == other.title,
+        }
+    }
+}
+
+/// Lowercased alphanumeric words of `text`
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Whether `phrase` appears as consecutive whole words in `words`
+fn contains_phrase(words: &[String], phrase: &str) -> bool {
+    let phrase: Vec<String> = self::words(phrase).collect();
+    !phrase.is_empty() && words.windows(phrase.len()).any(|window| window == phrase)
+}
+
 // NewsData.io API response structures
 #[derive(Deserialize)]
 struct NewsDataResponse {
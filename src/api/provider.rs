@@ -0,0 +1,279 @@
+//! Common interface over the live price providers
+
+use std::collections::HashMap;
+
+use tokio::sync::mpsc;
+
+use super::binance::{self, granularity_to_interval, BinanceProvider};
+use super::coinbase::{self, CoinbaseProvider};
+use super::{Candle, PriceUpdate};
+use crate::config::ReconnectConfig;
+
+/// A live market data source: a streaming task plus REST candle history
+#[allow(async_fn_in_trait)]
+pub trait PriceProvider {
+    /// Stream updates into `tx` until the channel closes, reconnecting as needed.
+    /// `interval_rx` carries kline interval changes (e.g. "15m") from the UI.
+    async fn run(self, tx: mpsc::Sender<PriceUpdate>, interval_rx: mpsc::Receiver<String>);
+
+    /// Fetch the latest `limit` candles of `granularity` seconds for an exchange pair
+    async fn fetch_candles(
+        &self,
+        pair: &str,
+        granularity: u32,
+        limit: u32,
+    ) -> anyhow::Result<Vec<Candle>>;
+
+    /// Coin symbol for an exchange pair (e.g. "BTCUSDT" -> "BTC")
+    fn symbol(&self, pair: &str) -> String;
+}
+
+impl PriceProvider for BinanceProvider {
+    async fn run(self, tx: mpsc::Sender<PriceUpdate>, interval_rx: mpsc::Receiver<String>) {
+        BinanceProvider::run(self, tx, interval_rx).await;
+    }
+
+    async fn fetch_candles(
+        &self,
+        pair: &str,
+        granularity: u32,
+        limit: u32,
+    ) -> anyhow::Result<Vec<Candle>> {
+        binance::fetch_candles(pair, granularity_to_interval(granularity), limit).await
+    }
+
+    fn symbol(&self, pair: &str) -> String {
+        pair.trim_end_matches("USDT").to_string()
+    }
+}
+
+impl PriceProvider for CoinbaseProvider {
+    /// Coinbase streams tickers only, so interval changes are ignored
+    async fn run(self, tx: mpsc::Sender<PriceUpdate>, _interval_rx: mpsc::Receiver<String>) {
+        CoinbaseProvider::run(self, tx).await;
+    }
+
+    /// Coinbase returns a fixed page of candles; keep only the latest `limit`
+    async fn fetch_candles(
+        &self,
+        pair: &str,
+        granularity: u32,
+        limit: u32,
+    ) -> anyhow::Result<Vec<Candle>> {
+        let mut candles = coinbase::fetch_candles(pair, granularity).await?;
+        let excess = candles.len().saturating_sub(limit as usize);
+        candles.drain(..excess);
+        Ok(candles)
+    }
+
+    fn symbol(&self, pair: &str) -> String {
+        pair.split('-').next().unwrap_or(pair).to_string()
+    }
+}
+
+/// Providers selectable with `api.provider` in config.json
+///
+/// An enum rather than `Box<dyn PriceProvider>`: async trait methods are not
+/// object safe, and a concrete type keeps the spawned futures `Send`.
+pub enum Provider {
+    Binance(BinanceProvider),
+    Coinbase(CoinbaseProvider),
+}
+
+impl Provider {
+    /// Build the provider named in config; None for "mock" or unknown names
+    pub fn from_name(name: &str, pairs: Vec<String>, initial_interval: &str) -> Option<Self> {
+        match name {
+            "binance" => Some(Self::Binance(BinanceProvider::new(pairs, initial_interval))),
+            "coinbase" => Some(Self::Coinbase(CoinbaseProvider::new(pairs))),
+            _ => None,
+        }
+    }
+
+    /// Apply reconnect backoff settings (only the Binance stream uses them)
+    pub fn with_reconnect(self, reconnect: &ReconnectConfig) -> Self {
+        match self {
+            Self::Binance(p) => Self::Binance(p.with_reconnect(reconnect.clone())),
+            other => other,
+        }
+    }
+
+    /// Whether `name` selects a live provider (anything else falls back to mock data)
+    pub fn is_live(name: &str) -> bool {
+        matches!(name, "binance" | "coinbase")
+    }
+
+    /// Whether `pair` is in the named provider's pair format:
+    /// Binance "BTCUSDT" (no separator), Coinbase "BTC-USD" (dash-separated)
+    pub fn accepts_pair(name: &str, pair: &str) -> bool {
+        match name {
+            "binance" => !pair.contains('-'),
+            "coinbase" => pair.contains('-'),
+            _ => false,
+        }
+    }
+}
+
+/// Coin symbol of a pair in either provider format ("BTC-USD" or "BTCUSDT" -> "BTC")
+pub fn pair_symbol(pair: &str) -> &str {
+    if pair.contains('-') {
+        pair.split('-').next().unwrap_or(pair)
+    } else {
+        pair.trim_end_matches("USDT")
+    }
+}
+
+/// Assign `pairs` to the live providers in `names` (duplicates and non-live names skipped)
+///
+/// A single provider takes every pair, as with a one-provider config. With
+/// several, each pair goes to the provider whose format it uses; pairs that
+/// match none are reported and left out. Each coin symbol is owned by one
+/// provider, the first in `names` that takes one of its pairs: the same coin
+/// listed for a later provider is skipped, so its ticks, klines and candle
+/// history never come from two exchanges at once.
+pub fn route_pairs<'a>(names: &[&'a str], pairs: &[String]) -> Vec<(&'a str, Vec<String>)> {
+    let mut live: Vec<&str> = Vec::new();
+    for &name in names {
+        if Provider::is_live(name) && !live.contains(&name) {
+            live.push(name);
+        }
+    }
+
+    let single = live.len() == 1;
+    let accepts = |name: &str, pair: &str| single || Provider::accepts_pair(name, pair);
+    for pair in pairs {
+        if !live.iter().any(|name| accepts(name, pair)) {
+            eprintln!(
+                "Warning: no configured provider accepts pair {}, skipping",
+                pair
+            );
+        }
+    }
+
+    let mut owners: HashMap<&str, &str> = HashMap::new();
+    live.into_iter()
+        .map(|name| {
+            let routed = pairs
+                .iter()
+                .filter(|pair| accepts(name, pair))
+                .filter(|pair| {
+                    let owner = *owners.entry(pair_symbol(pair)).or_insert(name);
+                    if owner != name {
+                        eprintln!(
+                            "Warning: {} already streams from {}, skipping {} on {}",
+                            pair_symbol(pair),
+                            owner,
+                            pair,
+                            name
+                        );
+                    }
+                    owner == name
+                })
+                .cloned()
+                .collect();
+            (name, routed)
+        })
+        .collect()
+}
+
+impl PriceProvider for Provider {
+    async fn run(self, tx: mpsc::Sender<PriceUpdate>, interval_rx: mpsc::Receiver<String>) {
+        match self {
+            Self::Binance(p) => PriceProvider::run(p, tx, interval_rx).await,
+            Self::Coinbase(p) => PriceProvider::run(p, tx, interval_rx).await,
+        }
+    }
+
+    async fn fetch_candles(
+        &self,
+        pair: &str,
+        granularity: u32,
+        limit: u32,
+    ) -> anyhow::Result<Vec<Candle>> {
+        match self {
+            Self::Binance(p) => PriceProvider::fetch_candles(p, pair, granularity, limit).await,
+            Self::Coinbase(p) => PriceProvider::fetch_candles(p, pair, granularity, limit).await,
+        }
+    }
+
+    fn symbol(&self, pair: &str) -> String {
+        match self {
+            Self::Binance(p) => p.symbol(pair),
+            Self::Coinbase(p) => p.symbol(pair),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn live_providers_map_pairs_to_symbols() {
+        let binance = Provider::from_name("binance", Vec::new(), "1h").unwrap();
+        let coinbase = Provider::from_name("coinbase", Vec::new(), "1h").unwrap();
+        assert_eq!(binance.symbol("BTCUSDT"), "BTC");
+        assert_eq!(coinbase.symbol("BTC-USD"), "BTC");
+        assert!(Provider::from_name("mock", Vec::new(), "1h").is_none());
+    }
+
+    #[test]
+    fn each_symbol_routes_to_its_first_provider() {
+        let pairs = [
+            "BTCUSDT", "ETH-USD", "BTC-USD", "SOLUSDT", "SOL-USD", "XRPUSDT",
+        ]
+        .map(String::from);
+        let routed = route_pairs(&["binance", "coinbase"], &pairs);
+        assert_eq!(
+            routed,
+            [
+                (
+                    "binance",
+                    vec!["BTCUSDT".into(), "SOLUSDT".into(), "XRPUSDT".into()]
+                ),
+                ("coinbase", vec!["ETH-USD".into()]),
+            ]
+        );
+
+        // Listing coinbase first hands it the shared symbols
+        let routed = route_pairs(&["coinbase", "binance"], &pairs);
+        assert_eq!(routed[0].1, ["ETH-USD", "BTC-USD", "SOL-USD"]);
+        assert_eq!(routed[1].1, ["XRPUSDT"]);
+    }
+
+    #[test]
+    fn pair_symbol_reads_both_formats() {
+        assert_eq!(pair_symbol("BTCUSDT"), "BTC");
+        assert_eq!(pair_symbol("BTC-USD"), "BTC");
+        assert_eq!(pair_symbol("ETH-EUR"), "ETH");
+        // Only the USDT quote is stripped from Binance pairs
+        assert_eq!(pair_symbol("ETHBTC"), "ETHBTC");
+        assert_eq!(pair_symbol("SOLBUSD"), "SOLBUSD");
+    }
+
+    #[test]
+    fn a_single_provider_takes_every_pair() {
+        let pairs = ["BTCUSDT", "ETH-USD"].map(String::from);
+        let routed = route_pairs(&["coinbase", "mock", "coinbase"], &pairs);
+        assert_eq!(
+            routed,
+            [("coinbase", vec!["BTCUSDT".into(), "ETH-USD".into()])]
+        );
+        assert!(route_pairs(&["mock", "kraken"], &pairs).is_empty());
+    }
+
+    #[test]
+    fn pairs_with_other_quotes_route_by_format_and_keep_their_own_symbol() {
+        let pairs = ["BTC-USD", "BTC-EUR", "ETHBTC", "BTCUSDT"].map(String::from);
+        let routed = route_pairs(&["binance", "coinbase"], &pairs);
+        // Binance owns BTC through BTCUSDT, so both Coinbase BTC quotes are
+        // skipped; ETHBTC is its own symbol and collides with neither
+        assert_eq!(
+            routed,
+            [
+                ("binance", vec!["ETHBTC".into(), "BTCUSDT".into()]),
+                ("coinbase", Vec::<String>::new()),
+            ]
+        );
+    }
+}
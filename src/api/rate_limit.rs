@@ -0,0 +1,53 @@
+//! Request spacing for the REST candle endpoints
+//!
+//! A minimum-interval limiter: callers await `acquire` before each request and
+//! are released at most `per_second` times a second. Requests waiting their
+//! turn simply stay queued in the candle request channel.
+
+use std::time::Duration;
+
+use tokio::time::{sleep_until, Instant};
+
+/// Pause after the exchange answers HTTP 429 before the next request
+const RATE_LIMITED_PAUSE: Duration = Duration::from_secs(10);
+
+pub struct RateLimiter {
+    /// Minimum spacing between requests (zero = unlimited)
+    interval: Duration,
+    /// Earliest time the next request may start
+    next: Instant,
+}
+
+impl RateLimiter {
+    /// Limit to `per_second` requests per second; 0 or less disables limiting
+    pub fn new(per_second: f64) -> Self {
+        let interval = if per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / per_second)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            interval,
+            next: Instant::now(),
+        }
+    }
+
+    /// Wait for the next request slot
+    pub async fn acquire(&mut self) {
+        sleep_until(self.next).await;
+        self.next = Instant::now() + self.interval;
+    }
+
+    /// Hold off further requests after the server reported rate limiting
+    pub fn rate_limited(&mut self) {
+        self.next = self.next.max(Instant::now() + RATE_LIMITED_PAUSE);
+    }
+}
+
+/// Whether a fetch failed with HTTP 429 Too Many Requests
+pub fn is_rate_limited(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .and_then(|e| e.status())
+        .is_some_and(|status| status == reqwest::StatusCode::TOO_MANY_REQUESTS)
+}
@@ -0,0 +1,162 @@
+//! Replay provider - plays recorded candle files back as a live feed
+//!
+//! Reads `<pair>_<granularity>.json` files in the disk cache format (see
+//! `candle_cache`), so a cache directory can be copied as-is. The first half of
+//! each series is startup history; the rest is streamed one candle per step as
+//! closed klines plus a synthesized ticker, looping back to the start once every
+//! series has played out. No network is involved, so runs are deterministic.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use super::candle_cache::CacheEntry;
+use super::{Candle, PriceUpdate};
+
+const DAY_SECS: i64 = 86_400;
+
+pub struct ReplaySeries {
+    /// Exchange pair from the file name (e.g. "BTCUSDT")
+    pub pair: String,
+    pub symbol: String,
+    /// Candle interval in seconds
+    pub granularity: u32,
+    /// Candles loaded at startup
+    pub history: Vec<Candle>,
+    /// Candles streamed one per step afterwards
+    pub pending: Vec<Candle>,
+}
+
+/// Load every recorded series in `dir`, one per symbol (the first file by name
+/// wins); unreadable files are skipped
+pub fn load_dir(dir: &Path) -> Vec<ReplaySeries> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut seen = HashSet::new();
+    paths
+        .iter()
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_str()?;
+            let (pair, granularity) = stem.rsplit_once('_')?;
+            let granularity: u32 = granularity.parse().ok().filter(|g| *g > 0)?;
+            let symbol = pair_symbol(pair);
+            if seen.contains(symbol) {
+                return None;
+            }
+
+            let contents = fs::read_to_string(path).ok()?;
+            let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+            let mut candles = entry.candles;
+            if candles.is_empty() {
+                return None;
+            }
+            candles.sort_by_key(|c| c.time);
+            let pending = candles.split_off(candles.len().div_ceil(2));
+
+            seen.insert(symbol.to_string());
+            Some(ReplaySeries {
+                pair: pair.to_string(),
+                symbol: symbol.to_string(),
+                granularity,
+                history: candles,
+                pending,
+            })
+        })
+        .collect()
+}
+
+/// Stream the pending candles, one per `step`, looping forever
+pub async fn run(series: Vec<ReplaySeries>, tx: mpsc::Sender<PriceUpdate>, step: Duration) {
+    let _ = tx.send(PriceUpdate::Connected { provider: "replay" }).await;
+    let steps = series.iter().map(|s| s.pending.len()).max().unwrap_or(0);
+    if steps == 0 {
+        return;
+    }
+
+    let mut interval = tokio::time::interval(step);
+    loop {
+        for index in 0..steps {
+            interval.tick().await;
+            for s in &series {
+                let Some(candle) = s.pending.get(index) else {
+                    continue;
+                };
+                let _ = tx
+                    .send(PriceUpdate::Kline {
+                        symbol: s.symbol.clone(),
+                        candle: candle.clone(),
+                        is_closed: true,
+                    })
+                    .await;
+                let _ = tx.send(ticker(s, index)).await;
+            }
+        }
+
+        // Start over from the startup history
+        interval.tick().await;
+        for s in &series {
+            let _ = tx
+                .send(PriceUpdate::Candles {
+                    symbol: s.symbol.clone(),
+                    candles: s.history.clone(),
+                })
+                .await;
+        }
+    }
+}
+
+/// Ticker for the candle at `index`, with 24h stats over the candles played so far
+fn ticker(series: &ReplaySeries, index: usize) -> PriceUpdate {
+    let current = &series.pending[index];
+    let since = current.time + series.granularity as i64 - DAY_SECS;
+    let day: Vec<&Candle> = series
+        .history
+        .iter()
+        .chain(&series.pending[..=index])
+        .filter(|c| c.time >= since)
+        .collect();
+
+    let open = day.first().map_or(current.open, |c| c.open);
+    let change_24h = if open > 0.0 {
+        (current.close - open) / open * 100.0
+    } else {
+        0.0
+    };
+    PriceUpdate::Ticker {
+        symbol: series.symbol.clone(),
+        price: current.close,
+        change_24h,
+        volume_24h_usd: day
+            .iter()
+            .map(|c| {
+                if c.quote_volume > 0.0 {
+                    c.quote_volume
+                } else {
+                    c.volume * c.close
+                }
+            })
+            .sum(),
+        volume_24h_base: day.iter().map(|c| c.volume).sum(),
+        high_24h: day.iter().map(|c| c.high).fold(f64::MIN, f64::max),
+        low_24h: day.iter().map(|c| c.low).fold(f64::MAX, f64::min),
+    }
+}
+
+/// Coin symbol for a pair in either format ("BTC-USD" or "BTCUSDT")
+fn pair_symbol(pair: &str) -> &str {
+    if pair.contains('-') {
+        pair.split('-').next().unwrap_or(pair)
+    } else {
+        pair.trim_end_matches("USDT")
+    }
+}
@@ -1,24 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::binance::lookup_interval;
 use crate::api::margin::MarginAccount;
 use crate::api::news::NewsArticle;
 use crate::api::PriceUpdate;
-use crate::mock::CoinData;
-use crate::notifications::NotificationManager;
+use crate::base::scrollable::{ScrollState, ScrollStates};
+use crate::base::view::Breakpoints;
+use crate::config::{BollingerConfig, SparklineStyle, VolumeConfig};
+use crate::connection_log::{ConnectionEventKind, ConnectionLog, DEFAULT_MAX_CONNECTION_EVENTS};
+use crate::keymap::Keymap;
+use crate::mock::{CoinData, DEFAULT_MAX_CANDLES};
+use crate::notifications::audio;
+use crate::notifications::rules::ThresholdDir;
+use crate::notifications::{NotificationManager, NotificationRule, Severity};
+use crate::widgets::chart_renderer::ScaleMode;
+use crate::widgets::chart_utils::{visible_price_range, ChartOptions, GridMode};
+use std::time::{Duration, Instant};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Most coins that can be checked for the details view at once
+pub const MAX_CHECKED_COINS: usize = 3;
+
+/// How long a status toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum View {
     Overview,
     Details,
     Notifications,
     News,
     Positions,
+    Diagnostics,
 }
 
+/// Dialog shown over the current view
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Modal {
+    Help,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ChartType {
     Polygonal,
+    #[default]
     Candlestick,
 }
 
+impl ChartType {
+    /// Parse a config name: "candlestick"/"candle" or "line"/"polygonal"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "candlestick" | "candle" => Ok(Self::Candlestick),
+            "line" | "polygonal" => Ok(Self::Polygonal),
+            _ => Err(format!("unknown chart type '{}'", name)),
+        }
+    }
+}
+
+/// Overview table sort column (cycled with 's')
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CoinSortKey {
+    /// Config order
+    #[default]
+    Listed,
+    Symbol,
+    Price,
+    Change24h,
+    Volume,
+}
+
+impl CoinSortKey {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Listed => Self::Symbol,
+            Self::Symbol => Self::Price,
+            Self::Price => Self::Change24h,
+            Self::Change24h => Self::Volume,
+            Self::Volume => Self::Listed,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Listed => "config order",
+            Self::Symbol => "pair",
+            Self::Price => "price",
+            Self::Change24h => "24h change",
+            Self::Volume => "volume",
+        }
+    }
+
+    /// Direction a key starts in: A-Z for pairs, largest first for numbers
+    fn default_descending(self) -> bool {
+        !matches!(self, Self::Listed | Self::Symbol)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ConnectionStatus {
     Connecting,
@@ -27,65 +106,250 @@ pub enum ConnectionStatus {
     Mock,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TimeWindow {
-    Min15,
-    Hour1,
-    Hour4,
-    Day1,
+/// A selectable candle interval: display label plus Binance kline interval
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeWindow {
+    label: String,
+    interval: &'static str,
+    granularity: u32,
 }
 
+/// Intervals cycled through when none are configured
+const DEFAULT_TIME_WINDOWS: [&str; 4] = ["15m", "1h", "4h", "1d"];
+
+/// Interval selected at startup when present in the list
+const DEFAULT_TIME_WINDOW: &str = "1h";
+
 impl TimeWindow {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            TimeWindow::Min15 => "15m",
-            TimeWindow::Hour1 => "1h",
-            TimeWindow::Hour4 => "4h",
-            TimeWindow::Day1 => "1d",
+    /// Build a window from a Binance interval string (e.g. "5m", "1w").
+    /// Fails if Binance doesn't support the interval or `granularity` disagrees with it.
+    pub fn new(
+        label: Option<&str>,
+        interval: &str,
+        granularity: Option<u32>,
+    ) -> Result<Self, String> {
+        let (interval, secs) = lookup_interval(interval)
+            .ok_or_else(|| format!("unsupported interval '{}'", interval))?;
+        if let Some(granularity) = granularity.filter(|g| *g != secs) {
+            return Err(format!(
+                "interval '{}' is {}s, not {}s",
+                interval, secs, granularity
+            ));
         }
+
+        Ok(Self {
+            label: label.unwrap_or(interval).to_string(),
+            interval,
+            granularity: secs,
+        })
     }
 
-    pub fn next(&self) -> Self {
-        match self {
-            TimeWindow::Min15 => TimeWindow::Hour1,
-            TimeWindow::Hour1 => TimeWindow::Hour4,
-            TimeWindow::Hour4 => TimeWindow::Day1,
-            TimeWindow::Day1 => TimeWindow::Min15,
-        }
+    /// The default 15m/1h/4h/1d list
+    pub fn defaults() -> Vec<Self> {
+        DEFAULT_TIME_WINDOWS
+            .iter()
+            .filter_map(|interval| Self::new(None, interval, None).ok())
+            .collect()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.label
+    }
+
+    /// Binance kline interval string
+    pub fn interval(&self) -> &'static str {
+        self.interval
     }
 
     /// Returns the candle interval in seconds for this time window
     pub fn granularity(&self) -> u32 {
-        match self {
-            TimeWindow::Min15 => 900,   // 15 minutes
-            TimeWindow::Hour1 => 3600,  // 1 hour
-            TimeWindow::Hour4 => 14400, // 4 hours
-            TimeWindow::Day1 => 86400,  // 1 day
-        }
+        self.granularity
+    }
+
+    /// Daily candles, where Binance's rolling 24h stats match the window
+    pub fn is_daily(&self) -> bool {
+        self.granularity == 86400
+    }
+}
+
+/// Middle entry of the zoom presets, used as the startup zoom
+fn median_zoom_level(levels: &[usize]) -> usize {
+    levels[levels.len() / 2]
+}
+
+/// Start on 1h when the list has it, otherwise the first entry
+fn default_window_index(windows: &[TimeWindow]) -> usize {
+    windows
+        .iter()
+        .position(|w| w.interval() == DEFAULT_TIME_WINDOW)
+        .unwrap_or(0)
+}
+
+/// Gamma change per key press
+pub const GAMMA_STEP: f32 = 0.1;
+
+/// Rows shown in the notification log before it scrolls
+pub const NOTIFICATION_VISIBLE_ROWS: usize = 15;
+
+/// Scroll state ids of the smooth-scrolled news article body and notification log
+const NEWS_CONTENT_SCROLL: &str = "news_content";
+const NOTIFICATION_LOG_SCROLL: &str = "notification_log";
+
+/// Share of the remaining distance a smooth scroll covers per 60 Hz frame
+const SMOOTH_SCROLL_FRACTION: f32 = 0.25;
+
+/// Volume change per key press
+pub const VOLUME_STEP: f32 = 0.1;
+
+/// Display gamma limits (config load and runtime adjustment)
+pub const GAMMA_RANGE: (f32, f32) = (0.5, 2.5);
+
+/// Fraction of the visible price range added above and below the chart data
+const CHART_PRICE_MARGIN: f64 = 0.05;
+
+/// Longest price alert entry, in characters
+const MAX_PRICE_ENTRY_LEN: usize = 16;
+
+/// Price alert being typed for a coin (Overview, 'a')
+pub struct PriceEntry {
+    pub symbol: String,
+    /// Typed digits and at most one decimal point
+    pub buffer: String,
+}
+
+/// Longest coin search query
+const MAX_SEARCH_LEN: usize = 24;
+
+/// Coin search overlay state ('/')
+pub struct CoinSearch {
+    /// Typed text, matched case-insensitively against symbol and name
+    pub query: String,
+    /// Highlighted row among the matches
+    pub selected: usize,
+    /// Highlighted coin when the search opened, restored on cancel
+    prior_index: usize,
+}
+
+impl CoinSearch {
+    pub fn matches(&self, coin: &CoinData) -> bool {
+        let query = self.query.trim().to_lowercase();
+        query.is_empty()
+            || coin.symbol.to_lowercase().contains(&query)
+            || coin.name.to_lowercase().contains(&query)
+    }
+}
+
+/// Order two sort values in the given direction; missing (None) or NaN
+/// values go last either way
+fn cmp_sort_values(a: Option<f64>, b: Option<f64>, descending: bool) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    match (a.filter(|v| !v.is_nan()), b.filter(|v| !v.is_nan())) {
+        (Some(a), Some(b)) if descending => b.total_cmp(&a),
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
     }
 }
 
-/// Zoom level presets: fewer candles = zoomed in, more candles = zoomed out
-const ZOOM_LEVELS: [usize; 5] = [20, 35, 50, 80, 120];
+/// Default zoom level presets: fewer candles = zoomed in, more candles = zoomed out
+pub const DEFAULT_ZOOM_LEVELS: [usize; 5] = [20, 35, 50, 80, 120];
 
 pub struct App {
     pub view: View,
     pub coins: Vec<CoinData>,
+    /// Index into `coins` of the highlighted coin (not a display row)
     pub selected_index: usize,
     pub checked: Vec<bool>,
+    /// Overview table sort column and direction
+    pub sort_key: CoinSortKey,
+    pub sort_descending: bool,
     pub running: bool,
+    /// Overall status: Connected once every provider is, Disconnected while any one is
     pub connection_status: ConnectionStatus,
+    /// Last reported status of each streaming provider, in `provider` order
+    pub provider_status: Vec<(String, ConnectionStatus)>,
     pub provider: String,
-    pub time_window: TimeWindow,
+    /// Selectable candle intervals (cycled with 'w')
+    pub time_windows: Vec<TimeWindow>,
+    /// Index of the active entry in `time_windows`
+    pub time_window_index: usize,
     pub needs_candle_refresh: bool,
     pub chart_type: ChartType,
     pub candle_scroll_offset: isize,
     /// Number of visible candles (zoom level)
     pub visible_candles: usize,
+    /// Zoom level presets cycled with j/k, ascending
+    pub zoom_levels: Vec<usize>,
+    /// Candlesticks zoomed out past this many visible candles are drawn as a line (0 = never)
+    pub lod_candles: usize,
+    /// Maximum candles retained per coin (older candles are dropped)
+    pub max_candles: usize,
+    /// Show percentage gridlines (relative to leftmost visible close) instead of price gridlines
+    pub percent_grid: bool,
+    /// Percent step between percentage gridlines
+    pub percent_grid_step: f64,
+    /// Draw EMA 7/25/99 lines over the candlestick chart
+    pub show_ema: bool,
+    /// Show the MACD subpanel below the candlestick chart
+    pub show_macd: bool,
+    /// Price axis scale for charts
+    pub scale_mode: ScaleMode,
+    /// Crosshair slot counted from the left of the visible candles (None = off);
+    /// while set, left/right move the crosshair instead of scrolling
+    pub crosshair_index: Option<usize>,
+    /// Bollinger Bands overlay settings for candlestick charts
+    pub bollinger: BollingerConfig,
+    /// Minimum candle body width in pixels
+    pub min_candle_body_width: f32,
+    /// Duration of the live-price pulse on each ticker update (None = disabled)
+    pub price_pulse: Option<Duration>,
+    /// Crossfade duration between views (None = instant switch)
+    pub view_fade: Option<Duration>,
+    /// When the current view was entered, while its crossfade runs
+    pub view_transition: Option<Instant>,
+    /// Abbreviate the big price display (e.g., "$67.4K")
+    pub compact_price: bool,
+    /// Insert thousands separators in the big price display ("$67,432.10")
+    pub group_thousands: bool,
+    /// Price ticks smaller than this percentage are colored neutral (0.0 = off)
+    pub neutral_zone_pct: f64,
+    /// Glyph set for the overview sparklines
+    pub sparkline_style: SparklineStyle,
+    /// Gamma applied to theme colors (1.0 = unchanged)
+    pub gamma: f32,
+    /// Brightness multiplier applied to theme colors (1.0 = unchanged)
+    pub brightness: f32,
+    /// Show the status header (view tabs, provider, connection) above each view
+    pub show_header: bool,
+    /// Draw the FPS / frame time overlay
+    pub show_fps: bool,
+    /// Size thresholds for responsive view layouts
+    pub breakpoints: Breakpoints,
+    /// Always render indicators as a compact single-line strip
+    pub compact_indicators: bool,
+    /// Trade count sparkline in the details view
+    pub trades_sparkline: bool,
+    /// Volume units, thresholds and precision
+    pub volume_config: VolumeConfig,
+    /// When the last ticker or kline arrived (reset on connect), for staleness
+    pub last_message_at: Option<Instant>,
+    /// Seconds of silence while connected before the header shows stale (0 = off)
+    pub stale_after_secs: u64,
+    /// Recent connection events (Diagnostics view)
+    pub connection_log: ConnectionLog,
+    /// Characters drawn without a font atlas glyph so far (Diagnostics view)
+    pub dropped_glyphs: u64,
+    /// Show the border style samples in the Diagnostics view (renderer checks)
+    pub diagnostics_samples: bool,
     /// Notification manager
     pub notification_manager: NotificationManager,
     /// Scroll offset for notifications view
     pub notification_scroll: usize,
+    /// Selected row in the notification log (position in the filtered, newest-first list)
+    pub notification_selected: usize,
+    /// Minimum severity shown in the notification log and counted in the badge
+    pub notification_filter: Severity,
     /// Whether ticker tones are muted
     pub ticker_muted: bool,
     /// News articles from API
@@ -94,10 +358,24 @@ pub struct App {
     pub news_selected: usize,
     /// Scroll offset for news content (line-based)
     pub news_content_scroll: usize,
+    /// Eased news content and notification log offsets; empty unless smooth scrolling is on
+    pub scroll_states: ScrollStates,
+    /// Largest useful content scroll offset, from the last rendered layout
+    pub news_content_max_scroll: usize,
     /// Whether news is currently being fetched
     pub news_loading: bool,
     /// Flag to trigger news refresh
     pub needs_news_refresh: bool,
+    /// Refresh news whenever the News view is entered
+    pub news_refresh_on_focus: bool,
+    /// Automatic news refresh interval in seconds (0 = off)
+    pub news_refresh_secs: u64,
+    /// When the last news fetch completed (or startup), for the auto-refresh
+    pub news_fetched_at: Instant,
+    /// Only list articles mentioning a checked coin (no effect with none checked)
+    pub news_filter_selected: bool,
+    /// Key -> action bindings (built-in layout plus config.json overrides)
+    pub keymap: Keymap,
     /// Margin account data
     pub margin_account: Option<MarginAccount>,
     /// Selected position index for navigation
@@ -106,10 +384,32 @@ pub struct App {
     pub positions_scroll: usize,
     /// Flag to trigger positions refresh
     pub needs_positions_refresh: bool,
+    /// Refresh positions whenever the Positions view is entered
+    pub positions_refresh_on_focus: bool,
     /// Whether positions are currently loading
     pub positions_loading: bool,
     /// Whether positions API is available (API keys configured)
     pub positions_available: bool,
+    /// Index into the current view's focusable panels (None = nothing focused)
+    pub focus_index: Option<usize>,
+    /// Flag to trigger a snapshot export of the current view
+    pub needs_snapshot_export: bool,
+    /// Flag to re-read config.json on the next frame
+    pub needs_config_reload: bool,
+    /// Flag to copy the focused coin to the clipboard (handled in main loop)
+    pub needs_clipboard_copy: bool,
+    /// Open modal dialog; it captures keyboard input and focus until closed
+    pub modal: Option<Modal>,
+    /// Short status message and when it was shown
+    pub toast: Option<(String, Instant)>,
+    /// Price alert entry in progress (captures digit keys while set)
+    pub price_entry: Option<PriceEntry>,
+    /// Coin search overlay; while open it captures typing and filters the overview
+    pub search: Option<CoinSearch>,
+    /// Flag to persist the rules added from the UI
+    pub needs_rules_save: bool,
+    /// Flag to rewrite the notification log after clearing or dismissing entries
+    pub needs_notifications_save: bool,
 }
 
 impl App {
@@ -124,37 +424,101 @@ impl App {
     ) -> Self {
         let coin_count = coins.len();
         let use_mock = provider == "mock";
+        let time_windows = TimeWindow::defaults();
+        let time_window_index = default_window_index(&time_windows);
         Self {
             view: View::Overview,
             coins,
             selected_index: 0,
             checked: vec![false; coin_count],
+            sort_key: CoinSortKey::default(),
+            sort_descending: false,
             running: true,
             connection_status: if use_mock {
                 ConnectionStatus::Mock
             } else {
                 ConnectionStatus::Connecting
             },
+            provider_status: if use_mock {
+                Vec::new()
+            } else {
+                provider
+                    .split('+')
+                    .map(|name| (name.to_string(), ConnectionStatus::Connecting))
+                    .collect()
+            },
             provider: provider.to_string(),
-            time_window: TimeWindow::Hour1,
+            time_windows,
+            time_window_index,
             needs_candle_refresh: true, // Fetch candles on startup
-            chart_type: ChartType::Candlestick,
+            chart_type: ChartType::default(),
             candle_scroll_offset: 0,
-            visible_candles: 50, // Default zoom level
+            visible_candles: median_zoom_level(&DEFAULT_ZOOM_LEVELS),
+            zoom_levels: DEFAULT_ZOOM_LEVELS.to_vec(),
+            lod_candles: 0,
+            max_candles: DEFAULT_MAX_CANDLES,
+            percent_grid: false,
+            percent_grid_step: 2.0,
+            show_ema: true,
+            show_macd: false,
+            scale_mode: ScaleMode::Linear,
+            crosshair_index: None,
+            bollinger: BollingerConfig::default(),
+            min_candle_body_width: 1.0,
+            price_pulse: None,
+            view_fade: None,
+            view_transition: None,
+            compact_price: false,
+            group_thousands: true,
+            neutral_zone_pct: 0.0,
+            sparkline_style: SparklineStyle::default(),
+            gamma: 1.0,
+            brightness: 1.0,
+            show_header: true,
+            show_fps: false,
+            breakpoints: Breakpoints::default(),
+            compact_indicators: false,
+            trades_sparkline: false,
+            volume_config: VolumeConfig::default(),
+            last_message_at: None,
+            stale_after_secs: 0,
+            connection_log: ConnectionLog::new(DEFAULT_MAX_CONNECTION_EVENTS, None),
+            dropped_glyphs: 0,
+            diagnostics_samples: false,
             notification_manager,
             notification_scroll: 0,
+            notification_selected: 0,
+            notification_filter: Severity::Info,
             ticker_muted: false,
             news_articles: Vec::new(),
             news_selected: 0,
             news_content_scroll: 0,
+            scroll_states: ScrollStates::new(),
+            news_content_max_scroll: 0,
             news_loading: false,
             needs_news_refresh: false,
+            news_refresh_on_focus: false,
+            news_refresh_secs: 0,
+            news_fetched_at: Instant::now(),
+            news_filter_selected: false,
+            keymap: Keymap::default(),
             margin_account: None,
             positions_selected: 0,
             positions_scroll: 0,
             needs_positions_refresh: false,
+            positions_refresh_on_focus: true,
             positions_loading: false,
             positions_available: false,
+            focus_index: None,
+            needs_snapshot_export: false,
+            needs_config_reload: false,
+            needs_clipboard_copy: false,
+            modal: None,
+            toast: None,
+            price_entry: None,
+            search: None,
+            needs_rules_save: false,
+            needs_notifications_save: false,
         }
     }
 
@@ -176,9 +540,132 @@ impl App {
         };
     }
 
+    /// Toggle between price and percentage chart gridlines
+    pub fn toggle_percent_grid(&mut self) {
+        self.percent_grid = !self.percent_grid;
+    }
+
+    /// Show or hide the EMA lines on candlestick charts
+    pub fn toggle_ema(&mut self) {
+        self.show_ema = !self.show_ema;
+    }
+
+    /// Switch chart price axes between linear and log scale
+    pub fn toggle_scale_mode(&mut self) {
+        self.scale_mode = match self.scale_mode {
+            ScaleMode::Linear => ScaleMode::Log,
+            ScaleMode::Log => ScaleMode::Linear,
+        };
+        let name = match self.scale_mode {
+            ScaleMode::Linear => "Linear",
+            ScaleMode::Log => "Log",
+        };
+        self.show_toast(format!("{} price scale", name));
+    }
+
+    /// Toggle the MACD subpanel under candlestick charts
+    pub fn toggle_macd(&mut self) {
+        self.show_macd = !self.show_macd;
+    }
+
+    /// Show the crosshair on the latest slot, or hide it
+    pub fn toggle_crosshair(&mut self) {
+        self.crosshair_index = match self.crosshair_index {
+            Some(_) => None,
+            None => Some(self.visible_candles.saturating_sub(1)),
+        };
+    }
+
+    /// Move the crosshair one candle back, scrolling the chart at the left edge
+    pub fn move_crosshair_left(&mut self) {
+        if let Some(slot) = self.crosshair_slot() {
+            if slot > 0 {
+                self.crosshair_index = Some(slot - 1);
+            } else {
+                let max_offset = self.max_candles.saturating_sub(1) as isize;
+                self.candle_scroll_offset = (self.candle_scroll_offset + 1).min(max_offset);
+            }
+        }
+    }
+
+    /// Move the crosshair one candle forward, scrolling the chart at the right edge
+    pub fn move_crosshair_right(&mut self) {
+        if let Some(slot) = self.crosshair_slot() {
+            if slot + 1 < self.visible_candles {
+                self.crosshair_index = Some(slot + 1);
+            } else if self.candle_scroll_offset > 0 {
+                self.candle_scroll_offset -= 1;
+            }
+        }
+    }
+
+    /// Crosshair slot clamped to the current zoom level
+    fn crosshair_slot(&self) -> Option<usize> {
+        self.crosshair_index
+            .map(|slot| slot.min(self.visible_candles.saturating_sub(1)))
+    }
+
+    /// Chart view options for a coin (zoom, scroll, grid mode, pinned scale)
+    pub fn chart_options(&self, coin: &CoinData) -> ChartOptions {
+        ChartOptions {
+            scroll_offset: self.candle_scroll_offset,
+            visible_candles: self.visible_candles,
+            price_margin: CHART_PRICE_MARGIN,
+            grid: if self.percent_grid {
+                GridMode::Percent(self.percent_grid_step)
+            } else {
+                GridMode::Price
+            },
+            price_range: coin.locked_price_range,
+            volume_unit: self.volume_config.unit,
+            min_body_width: self.min_candle_body_width,
+            show_ema: self.show_ema,
+            bollinger: self.bollinger.enabled.then_some(self.bollinger),
+            crosshair: self
+                .crosshair_slot()
+                .filter(|_| self.rendered_chart_type() == ChartType::Candlestick),
+            show_macd: self.show_macd && self.rendered_chart_type() == ChartType::Candlestick,
+            scale_mode: self.scale_mode,
+        }
+    }
+
+    /// Chart type actually drawn: candlesticks fall back to the line chart when
+    /// zoomed out past `lod_candles`, where bodies and wicks blur together
+    pub fn rendered_chart_type(&self) -> ChartType {
+        match self.chart_type {
+            ChartType::Candlestick
+                if self.lod_candles > 0 && self.visible_candles > self.lod_candles =>
+            {
+                ChartType::Polygonal
+            }
+            chart_type => chart_type,
+        }
+    }
+
+    /// Pin the chart Y-axis of each displayed coin to its current visible range
+    pub fn lock_price_scale(&mut self) {
+        let closes_only = self.rendered_chart_type() == ChartType::Polygonal;
+        let indices: Vec<usize> = self.active_coins().iter().map(|(i, _)| *i).collect();
+        for idx in indices {
+            let options = self.chart_options(&self.coins[idx]);
+            let range = visible_price_range(&self.coins[idx].candles, &options, closes_only);
+            if range.is_some() {
+                self.coins[idx].locked_price_range = range;
+            }
+        }
+    }
+
+    /// Release pinned chart scales so charts autoscale again
+    pub fn unlock_price_scale(&mut self) {
+        for coin in &mut self.coins {
+            coin.locked_price_range = None;
+        }
+    }
+
     /// Scroll candle chart left (back in time)
     pub fn scroll_candles_left(&mut self) {
-        self.candle_scroll_offset += 5;
+        let max_offset = self.max_candles.saturating_sub(1) as isize;
+        self.candle_scroll_offset = (self.candle_scroll_offset + 5).min(max_offset);
     }
 
     /// Scroll candle chart right (forward in time, can go negative to snap to last candles)
@@ -186,6 +673,13 @@ impl App {
         self.candle_scroll_offset -= 5;
     }
 
+    /// Set the candle scroll offset directly (chart dragging), kept between
+    /// the latest candle and the oldest loaded one
+    pub fn set_candle_scroll(&mut self, offset: isize) {
+        let max_offset = self.max_candles.saturating_sub(1) as isize;
+        self.candle_scroll_offset = offset.clamp(0, max_offset);
+    }
+
     /// Reset candle scroll to most recent
     pub fn reset_candle_scroll(&mut self) {
         self.candle_scroll_offset = 0;
@@ -193,42 +687,181 @@ impl App {
 
     /// Zoom in: show fewer candles (each wider)
     pub fn zoom_in(&mut self) {
-        if let Some(pos) = ZOOM_LEVELS.iter().position(|&z| z == self.visible_candles) {
+        if let Some(pos) = self
+            .zoom_levels
+            .iter()
+            .position(|&z| z == self.visible_candles)
+        {
             if pos > 0 {
-                self.visible_candles = ZOOM_LEVELS[pos - 1];
+                self.visible_candles = self.zoom_levels[pos - 1];
             }
         }
     }
 
+    /// Fit the loaded candles: zoom to the smallest level showing all of them (the
+    /// widest level if none does) and snap to the latest candle.
+    /// No-op until a charted coin has candles.
+    pub fn fit_candles(&mut self) {
+        let total = self
+            .active_coins()
+            .iter()
+            .map(|(_, coin)| coin.candles.len())
+            .max()
+            .unwrap_or(0);
+        if total == 0 {
+            return;
+        }
+
+        let widest = self
+            .zoom_levels
+            .last()
+            .copied()
+            .unwrap_or(self.visible_candles);
+        self.visible_candles = self
+            .zoom_levels
+            .iter()
+            .copied()
+            .find(|&level| level >= total)
+            .unwrap_or(widest);
+        self.candle_scroll_offset = 0;
+    }
+
     /// Zoom out: show more candles (each thinner)
     pub fn zoom_out(&mut self) {
-        if let Some(pos) = ZOOM_LEVELS.iter().position(|&z| z == self.visible_candles) {
-            if pos < ZOOM_LEVELS.len() - 1 {
-                self.visible_candles = ZOOM_LEVELS[pos + 1];
+        if let Some(pos) = self
+            .zoom_levels
+            .iter()
+            .position(|&z| z == self.visible_candles)
+        {
+            if pos < self.zoom_levels.len() - 1 {
+                self.visible_candles = self.zoom_levels[pos + 1];
             }
         }
     }
 
+    /// Active candle interval
+    pub fn time_window(&self) -> &TimeWindow {
+        &self.time_windows[self.time_window_index]
+    }
+
+    /// Replace the interval list, starting on 1h when available (ignored if empty)
+    pub fn set_time_windows(&mut self, windows: Vec<TimeWindow>) {
+        if windows.is_empty() {
+            return;
+        }
+        self.time_window_index = default_window_index(&windows);
+        self.time_windows = windows;
+    }
+
+    /// Replace the zoom presets, starting on the median level (ignored if empty)
+    pub fn set_zoom_levels(&mut self, levels: Vec<usize>) {
+        if levels.is_empty() {
+            return;
+        }
+        self.visible_candles = median_zoom_level(&levels);
+        self.zoom_levels = levels;
+    }
+
+    /// Switch to the interval named `interval` (e.g. "1h"), if it is in the list
+    pub fn select_time_window(&mut self, interval: &str) {
+        if let Some(index) = self
+            .time_windows
+            .iter()
+            .position(|w| w.interval() == interval)
+        {
+            self.time_window_index = index;
+        }
+    }
+
     /// Cycle to the next time window. Sets flag to trigger candle refetch.
     pub fn cycle_window(&mut self) {
-        self.time_window = self.time_window.next();
+        self.time_window_index = (self.time_window_index + 1) % self.time_windows.len();
         self.needs_candle_refresh = true;
     }
 
+    /// Move focus to the next focusable panel (wraps back to unfocused after the last one)
+    pub fn cycle_focus(&mut self) {
+        self.focus_index = Some(self.focus_index.map_or(0, |i| i + 1));
+    }
+
     pub fn quit(&mut self) {
         self.running = false;
     }
 
     pub fn move_up(&mut self) {
-        if self.selected_index > 0 {
-            self.selected_index -= 1;
+        let order = self.sorted_coin_indices();
+        if let Some(row) = order.iter().position(|&i| i == self.selected_index) {
+            self.selected_index = order[row.saturating_sub(1)];
         }
     }
 
     pub fn move_down(&mut self) {
-        if self.selected_index < self.coins.len().saturating_sub(1) {
-            self.selected_index += 1;
+        let order = self.sorted_coin_indices();
+        if let Some(row) = order.iter().position(|&i| i == self.selected_index) {
+            self.selected_index = order[(row + 1).min(order.len() - 1)];
+        }
+    }
+
+    /// Indices into `coins` in overview display order; ties fall back to
+    /// symbol order so rows don't shuffle between frames, and coins without a
+    /// value for the key (no price yet, NaN) go last in either direction.
+    /// While searching, only the coins matching the query are listed.
+    pub fn sorted_coin_indices(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.coins.len()).collect();
+        if let Some(search) = &self.search {
+            order.retain(|&i| search.matches(&self.coins[i]));
+        }
+        if self.sort_key == CoinSortKey::Listed {
+            return order;
+        }
+        let descending = self.sort_descending;
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&self.coins[a], &self.coins[b]);
+            let primary = match self.sort_key {
+                CoinSortKey::Listed => std::cmp::Ordering::Equal,
+                CoinSortKey::Symbol if descending => b.symbol.cmp(&a.symbol),
+                CoinSortKey::Symbol => a.symbol.cmp(&b.symbol),
+                // A zero price means no ticker has arrived yet
+                CoinSortKey::Price => cmp_sort_values(
+                    Some(a.price).filter(|p| *p > 0.0),
+                    Some(b.price).filter(|p| *p > 0.0),
+                    descending,
+                ),
+                CoinSortKey::Change24h => {
+                    cmp_sort_values(Some(a.change_24h), Some(b.change_24h), descending)
+                }
+                CoinSortKey::Volume => {
+                    cmp_sort_values(Some(a.volume_usd), Some(b.volume_usd), descending)
+                }
+            };
+            primary.then_with(|| a.symbol.cmp(&b.symbol))
+        });
+        order
+    }
+
+    /// Sort the overview by the next column, in that column's natural direction
+    pub fn cycle_sort_key(&mut self) {
+        self.sort_key = self.sort_key.next();
+        self.sort_descending = self.sort_key.default_descending();
+        self.show_sort_toast();
+    }
+
+    /// Flip the overview sort direction
+    pub fn toggle_sort_direction(&mut self) {
+        if self.sort_key == CoinSortKey::Listed {
+            return;
         }
+        self.sort_descending = !self.sort_descending;
+        self.show_sort_toast();
+    }
+
+    fn show_sort_toast(&mut self) {
+        let arrow = match (self.sort_key, self.sort_descending) {
+            (CoinSortKey::Listed, _) => "",
+            (_, true) => " ▼",
+            (_, false) => " ▲",
+        };
+        self.show_toast(format!("Sorted by {}{}", self.sort_key.label(), arrow));
     }
 
     pub fn toggle_selection(&mut self) {
@@ -237,10 +870,13 @@ impl App {
             self.checked[self.selected_index] = false;
         } else {
             let selected_count = self.checked.iter().filter(|&&c| c).count();
-            if selected_count < 3 {
+            if selected_count < MAX_CHECKED_COINS {
                 self.checked[self.selected_index] = true;
             }
         }
+        if self.news_filter_selected {
+            self.reset_news_selection();
+        }
     }
 
     pub fn switch_view(&mut self) {
@@ -254,15 +890,40 @@ impl App {
             View::Details => View::Notifications,
             View::Notifications => View::News,
             View::News => View::Positions,
-            View::Positions => View::Overview,
+            View::Positions => View::Diagnostics,
+            View::Diagnostics => View::Overview,
         };
 
-        // Trigger positions refresh when entering Positions view
-        if next_view == View::Positions {
-            self.needs_positions_refresh = true;
+        self.enter_view(next_view);
+    }
+
+    /// Show `view`, resetting focus
+    pub fn enter_view(&mut self, view: View) {
+        // Refresh on entering the view when configured; otherwise only 'r' refreshes
+        match view {
+            View::Positions if self.positions_refresh_on_focus => {
+                self.needs_positions_refresh = true;
+            }
+            View::News if self.news_refresh_on_focus => self.needs_news_refresh = true,
+            _ => {}
         }
 
-        self.view = next_view;
+        if view != self.view {
+            self.view_transition = self.view_fade.map(|_| Instant::now());
+        }
+        self.view = view;
+        self.focus_index = None;
+    }
+
+    /// Crossfade progress into the current view (0..1), None when not fading
+    pub fn view_transition_progress(&mut self) -> Option<f32> {
+        let (started, fade) = self.view_transition.zip(self.view_fade)?;
+        let progress = started.elapsed().as_secs_f32() / fade.as_secs_f32().max(f32::EPSILON);
+        if progress >= 1.0 {
+            self.view_transition = None;
+            return None;
+        }
+        Some(progress)
     }
 
     /// Request news refresh
@@ -274,15 +935,15 @@ impl App {
     pub fn scroll_news_up(&mut self) {
         if self.news_selected > 0 {
             self.news_selected -= 1;
-            self.news_content_scroll = 0; // Reset content scroll on selection change
+            self.reset_news_content_scroll();
         }
     }
 
     /// Select next news article
     pub fn scroll_news_down(&mut self) {
-        if !self.news_articles.is_empty() && self.news_selected < self.news_articles.len() - 1 {
+        if self.news_selected + 1 < self.visible_news().len() {
             self.news_selected += 1;
-            self.news_content_scroll = 0; // Reset content scroll on selection change
+            self.reset_news_content_scroll();
         }
     }
 
@@ -293,17 +954,134 @@ impl App {
         }
     }
 
-    /// Scroll content down (for article body)
+    /// Scroll content down (for article body), stopping at the last page
     pub fn scroll_content_down(&mut self) {
-        self.news_content_scroll += 1;
+        if self.news_content_scroll < self.news_content_max_scroll {
+            self.news_content_scroll += 1;
+        }
+    }
+
+    /// Back to the top of a newly selected article, without animating from the old one
+    fn reset_news_content_scroll(&mut self) {
+        self.news_content_scroll = 0;
+        if let Some(state) = self.scroll_states.get_mut(NEWS_CONTENT_SCROLL) {
+            state.jump_to(0.0);
+        }
+    }
+
+    /// Article body scroll in lines as drawn (fractional while a smooth scroll runs)
+    pub fn news_content_offset(&self) -> f32 {
+        self.scroll_states
+            .get(NEWS_CONTENT_SCROLL)
+            .map_or(self.news_content_scroll as f32, ScrollState::offset)
+    }
+
+    /// Notification log scroll in rows as drawn (fractional while a smooth scroll runs)
+    pub fn notification_offset(&self) -> f32 {
+        self.scroll_states
+            .get(NOTIFICATION_LOG_SCROLL)
+            .map_or(self.notification_scroll as f32, ScrollState::offset)
     }
 
-    /// Set news articles from API response
+    /// Ease the news content and notification log scroll instead of jumping
+    /// a line at a time (off: offsets follow input instantly)
+    pub fn set_smooth_scroll(&mut self, enabled: bool) {
+        self.scroll_states = ScrollStates::new();
+        if enabled {
+            for id in [NEWS_CONTENT_SCROLL, NOTIFICATION_LOG_SCROLL] {
+                let state = self.scroll_states.get_or_create(id, 0.0);
+                state.set_line_height(1.0);
+                state.set_smoothing(Some(SMOOTH_SCROLL_FRACTION));
+            }
+        }
+    }
+
+    /// Move the smooth scrolls toward the current offsets; call once per frame
+    ///
+    /// States use a zero viewport, so their content height is the scroll range
+    /// in lines (news content) or rows (notification log).
+    pub fn update_scroll(&mut self, dt: Duration) {
+        let log_rows = self.visible_notification_indices().len().saturating_sub(1);
+        if let Some(state) = self.scroll_states.get_mut(NEWS_CONTENT_SCROLL) {
+            state.set_content_height(self.news_content_max_scroll as f32);
+            state.scroll_to(self.news_content_scroll as f32);
+        }
+        if let Some(state) = self.scroll_states.get_mut(NOTIFICATION_LOG_SCROLL) {
+            state.set_content_height(log_rows as f32);
+            state.scroll_to(self.notification_scroll as f32);
+        }
+        self.scroll_states.update(dt);
+    }
+
+    /// Record the article body's wrapped and visible line counts and clamp
+    /// the scroll to them (the body can shrink on resize or re-wrap)
+    pub fn set_news_content_lines(&mut self, total: usize, visible: usize) {
+        self.news_content_max_scroll = total.saturating_sub(visible);
+        self.news_content_scroll = self.news_content_scroll.min(self.news_content_max_scroll);
+    }
+
+    /// Articles shown in the News view: those mentioning a checked coin when
+    /// the filter is on and any coin is checked, otherwise all of them
+    pub fn visible_news(&self) -> Vec<&NewsArticle> {
+        let symbols = self.selected_symbols();
+        if !self.news_filter_selected || symbols.is_empty() {
+            return self.news_articles.iter().collect();
+        }
+        self.news_articles
+            .iter()
+            .filter(|article| article.mentions(&symbols))
+            .collect()
+    }
+
+    /// Toggle filtering news by the checked coins
+    pub fn toggle_news_filter(&mut self) {
+        self.news_filter_selected = !self.news_filter_selected;
+        self.reset_news_selection();
+        let state = if self.news_filter_selected {
+            "checked coins"
+        } else {
+            "all"
+        };
+        self.show_toast(format!("News: {}", state));
+    }
+
+    /// Back to the first article (the visible list changed under the selection)
+    fn reset_news_selection(&mut self) {
+        self.news_selected = 0;
+        self.reset_news_content_scroll();
+    }
+
+    /// Time left until the automatic news refresh (None when it's off)
+    pub fn news_refresh_in(&self) -> Option<Duration> {
+        (self.news_refresh_secs > 0).then(|| {
+            Duration::from_secs(self.news_refresh_secs)
+                .saturating_sub(self.news_fetched_at.elapsed())
+        })
+    }
+
+    /// Set news articles from API response (restarts the auto-refresh countdown)
     pub fn set_news(&mut self, articles: Vec<NewsArticle>) {
+        let selected = self
+            .visible_news()
+            .get(self.news_selected)
+            .map(|a| (*a).clone());
         self.news_articles = articles;
         self.news_loading = false;
-        self.news_selected = 0;
-        self.news_content_scroll = 0;
+        self.news_fetched_at = Instant::now();
+
+        // A refresh keeps the reader on the same article; reset only when it is gone
+        let kept = selected.and_then(|selected| {
+            self.visible_news()
+                .iter()
+                .position(|article| article.is_same_article(&selected))
+        });
+        match kept {
+            Some(index) => self.news_selected = index,
+            None => {
+                self.news_selected = 0;
+                self.reset_news_content_scroll();
+            }
+        }
     }
 
     /// Request positions refresh
@@ -311,6 +1089,248 @@ impl App {
         self.needs_positions_refresh = true;
     }
 
+    /// Request a JSON snapshot of the current view's data
+    pub fn export_snapshot(&mut self) {
+        self.needs_snapshot_export = true;
+    }
+
+    /// Request a reload of config.json (theme and notification rules)
+    pub fn reload_config(&mut self) {
+        self.needs_config_reload = true;
+    }
+
+    /// Open a modal, or close it if it is already showing
+    pub fn toggle_modal(&mut self, modal: Modal) {
+        if self.modal == Some(modal) {
+            self.close_modal();
+        } else {
+            self.modal = Some(modal);
+            self.focus_index = None;
+        }
+    }
+
+    /// Close the open modal, restoring focus to the view
+    pub fn close_modal(&mut self) {
+        self.modal = None;
+        self.focus_index = None;
+    }
+
+    /// Nudge the display gamma, clamped to a sane range
+    pub fn adjust_gamma(&mut self, delta: f32) {
+        let gamma = (self.gamma + delta).clamp(GAMMA_RANGE.0, GAMMA_RANGE.1);
+        // Round away float drift so repeated steps land on clean values
+        self.gamma = (gamma * 10.0).round() / 10.0;
+        self.show_toast(format!("Gamma {:.1}", self.gamma));
+    }
+
+    /// Change the audio master volume; applies from the next generated sound
+    pub fn adjust_volume(&mut self, delta: f32) {
+        let volume = audio::adjust_volume(delta);
+        self.show_toast(format!("Volume {:.0}%", volume * 100.0));
+    }
+
+    /// Show or hide the status header, giving its space to the view content
+    pub fn toggle_header(&mut self) {
+        self.show_header = !self.show_header;
+        let state = if self.show_header { "shown" } else { "hidden" };
+        self.show_toast(format!("Header {}", state));
+    }
+
+    pub fn toggle_fps(&mut self) {
+        self.show_fps = !self.show_fps;
+    }
+
+    /// Request a clipboard copy of the focused coin
+    pub fn copy_to_clipboard(&mut self) {
+        self.needs_clipboard_copy = true;
+    }
+
+    /// Coin the copy action applies to
+    ///
+    /// In Details this is the coin whose indicator has keyboard focus, else the
+    /// first displayed coin; elsewhere the highlighted row.
+    pub fn focused_coin(&self, focused_symbol: Option<&str>) -> Option<&CoinData> {
+        if self.view == View::Details {
+            let active = self.active_coins();
+            return focused_symbol
+                .and_then(|symbol| active.iter().find(|(_, c)| c.symbol == symbol))
+                .or(active.first())
+                .map(|(_, c)| *c);
+        }
+        self.coins.get(self.selected_index)
+    }
+
+    /// Start typing a price alert for the highlighted coin
+    pub fn start_price_entry(&mut self) {
+        if let Some(coin) = self.coins.get(self.selected_index) {
+            self.price_entry = Some(PriceEntry {
+                symbol: coin.symbol.clone(),
+                buffer: String::new(),
+            });
+        }
+    }
+
+    /// Append a digit or decimal point to the price alert entry
+    pub fn price_entry_push(&mut self, ch: char) {
+        let Some(entry) = self.price_entry.as_mut() else {
+            return;
+        };
+        let valid = ch.is_ascii_digit() || (ch == '.' && !entry.buffer.contains('.'));
+        if valid && entry.buffer.len() < MAX_PRICE_ENTRY_LEN {
+            entry.buffer.push(ch);
+        }
+    }
+
+    pub fn price_entry_backspace(&mut self) {
+        if let Some(entry) = self.price_entry.as_mut() {
+            entry.buffer.pop();
+        }
+    }
+
+    pub fn cancel_price_entry(&mut self) {
+        self.price_entry = None;
+    }
+
+    /// Add a price level rule from the typed price: "above" if the target is
+    /// over the current price, "below" otherwise
+    pub fn confirm_price_entry(&mut self) {
+        let Some(entry) = self.price_entry.take() else {
+            return;
+        };
+        let price = match entry.buffer.parse::<f64>() {
+            Ok(price) if price > 0.0 => price,
+            _ => {
+                self.show_toast(format!("Invalid price: {:?}", entry.buffer));
+                return;
+            }
+        };
+        let current = self
+            .coins
+            .iter()
+            .find(|c| c.symbol == entry.symbol)
+            .map(|c| c.price)
+            .unwrap_or(0.0);
+        let direction = if price > current {
+            ThresholdDir::Above
+        } else {
+            ThresholdDir::Below
+        };
+
+        let rule = NotificationRule::PriceLevel {
+            symbol: entry.symbol,
+            price,
+            direction,
+            enabled: true,
+            sound: None,
+        };
+        let description = rule.description();
+        if self.notification_manager.add_rule(rule) {
+            self.needs_rules_save = true;
+            self.show_toast(format!("Alert added: {}", description));
+        } else {
+            self.show_toast(format!("Alert already exists: {}", description));
+        }
+    }
+
+    /// Prompt text while a price alert is being typed
+    pub fn price_entry_prompt(&self) -> Option<String> {
+        self.price_entry.as_ref().map(|entry| {
+            format!(
+                "Alert {} at $ {}_  [Enter] add  [Esc] cancel",
+                entry.symbol, entry.buffer
+            )
+        })
+    }
+
+    /// Open the coin search overlay
+    pub fn start_search(&mut self) {
+        self.search = Some(CoinSearch {
+            query: String::new(),
+            selected: 0,
+            prior_index: self.selected_index,
+        });
+        self.focus_index = None;
+    }
+
+    pub fn search_push(&mut self, ch: char) {
+        let Some(search) = self.search.as_mut() else {
+            return;
+        };
+        if search.query.chars().count() < MAX_SEARCH_LEN {
+            search.query.push(ch);
+            search.selected = 0;
+            self.sync_search_selection();
+        }
+    }
+
+    pub fn search_backspace(&mut self) {
+        if let Some(search) = self.search.as_mut() {
+            search.query.pop();
+            search.selected = 0;
+            self.sync_search_selection();
+        }
+    }
+
+    /// Move the highlight through the matches
+    pub fn search_move(&mut self, delta: isize) {
+        let count = self.sorted_coin_indices().len();
+        if let Some(search) = self.search.as_mut() {
+            let last = count.saturating_sub(1);
+            search.selected = search.selected.saturating_add_signed(delta).min(last);
+            self.sync_search_selection();
+        }
+    }
+
+    /// Highlight the selected match in the (filtered) coin table behind the overlay
+    fn sync_search_selection(&mut self) {
+        let Some(selected) = self.search.as_ref().map(|s| s.selected) else {
+            return;
+        };
+        if let Some(&index) = self.sorted_coin_indices().get(selected) {
+            self.selected_index = index;
+        }
+    }
+
+    /// Close the search and jump to the highlighted match in the overview
+    pub fn confirm_search(&mut self) {
+        let matches = self.sorted_coin_indices();
+        let Some(search) = self.search.take() else {
+            return;
+        };
+        match matches.get(search.selected) {
+            Some(&index) => {
+                self.selected_index = index;
+                if self.view != View::Overview {
+                    self.enter_view(View::Overview);
+                }
+            }
+            None => {
+                self.selected_index = search.prior_index;
+                self.show_toast(format!("No coin matches {:?}", search.query));
+            }
+        }
+    }
+
+    /// Close the search, restoring the highlighted coin
+    pub fn cancel_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            self.selected_index = search.prior_index;
+        }
+    }
+
+    /// Show a short status message
+    pub fn show_toast(&mut self, message: impl Into<String>) {
+        self.toast = Some((message.into(), Instant::now()));
+    }
+
+    /// Current status message, if it hasn't expired yet
+    pub fn active_toast(&self) -> Option<&str> {
+        self.toast
+            .as_ref()
+            .filter(|(_, shown)| shown.elapsed() < TOAST_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
     /// Navigate to previous position
     pub fn select_prev_position(&mut self) {
         if self.positions_selected > 0 {
@@ -328,7 +1348,10 @@ impl App {
     }
 
     /// Handle margin account update from API
-    pub fn set_margin_account(&mut self, account: MarginAccount) {
+    pub fn set_margin_account(&mut self, mut account: MarginAccount) {
+        if let Some(previous) = &self.margin_account {
+            account.carry_session_entries(previous);
+        }
         self.margin_account = Some(account);
         self.positions_loading = false;
         self.positions_selected = 0;
@@ -359,6 +1382,79 @@ impl App {
         self.notification_manager.select_next();
     }
 
+    /// Cycle the notification log filter: All -> Warning+ -> Critical only
+    pub fn cycle_notification_filter(&mut self) {
+        self.notification_filter = self.notification_filter.next_filter();
+        self.clamp_notification_selection();
+    }
+
+    /// Manager indices of the notifications shown in the log, newest first
+    fn visible_notification_indices(&self) -> Vec<usize> {
+        self.notification_manager
+            .notifications
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, n)| n.severity >= self.notification_filter)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Move notification log selection up (towards newer entries)
+    pub fn select_prev_notification(&mut self) {
+        self.notification_selected = self.notification_selected.saturating_sub(1);
+        self.clamp_notification_selection();
+    }
+
+    /// Move notification log selection down (towards older entries)
+    pub fn select_next_notification(&mut self) {
+        self.notification_selected += 1;
+        self.clamp_notification_selection();
+    }
+
+    /// Remove the selected notification from the log
+    pub fn dismiss_selected_notification(&mut self) {
+        let Some(&index) = self
+            .visible_notification_indices()
+            .get(self.notification_selected)
+        else {
+            return;
+        };
+        if self.notification_manager.remove(index).is_some() {
+            self.needs_notifications_save = true;
+            self.clamp_notification_selection();
+        }
+    }
+
+    /// Remove every notification from the log
+    pub fn clear_notifications(&mut self) {
+        if self.notification_manager.notifications.is_empty() {
+            return;
+        }
+        self.notification_manager.clear_all();
+        self.notification_selected = 0;
+        self.notification_scroll = 0;
+        if let Some(state) = self.scroll_states.get_mut(NOTIFICATION_LOG_SCROLL) {
+            state.jump_to(0.0);
+        }
+        self.needs_notifications_save = true;
+        self.show_toast("Notifications cleared".to_string());
+    }
+
+    /// Keep the selection inside the filtered log and scrolled into view
+    fn clamp_notification_selection(&mut self) {
+        let shown = self.visible_notification_indices().len();
+        let last = shown.saturating_sub(1);
+        self.notification_selected = self.notification_selected.min(last);
+        if self.notification_selected < self.notification_scroll {
+            self.notification_scroll = self.notification_selected;
+        } else if self.notification_selected >= self.notification_scroll + NOTIFICATION_VISIBLE_ROWS
+        {
+            self.notification_scroll = self.notification_selected + 1 - NOTIFICATION_VISIBLE_ROWS;
+        }
+        self.notification_scroll = self.notification_scroll.min(last);
+    }
+
     pub fn selected_count(&self) -> usize {
         self.checked.iter().filter(|&&c| c).count()
     }
@@ -382,6 +1478,37 @@ impl App {
         }
     }
 
+    /// Record one provider's status and derive the overall `connection_status`
+    fn set_provider_status(&mut self, provider: &str, status: ConnectionStatus) {
+        match self
+            .provider_status
+            .iter_mut()
+            .find(|(name, _)| name == provider)
+        {
+            Some(entry) => entry.1 = status,
+            None => self.provider_status.push((provider.to_string(), status)),
+        }
+
+        let any = |status| self.provider_status.iter().any(|(_, s)| *s == status);
+        self.connection_status = if any(ConnectionStatus::Disconnected) {
+            ConnectionStatus::Disconnected
+        } else if any(ConnectionStatus::Connecting) {
+            ConnectionStatus::Connecting
+        } else {
+            ConnectionStatus::Connected
+        };
+    }
+
+    /// Whether the stream reports Connected but nothing has arrived for
+    /// `timeout_secs` (e.g. a half-open WebSocket that never disconnects)
+    pub fn connection_is_stale(&self, timeout_secs: u64) -> bool {
+        timeout_secs > 0
+            && self.connection_status == ConnectionStatus::Connected
+            && self
+                .last_message_at
+                .is_some_and(|at| at.elapsed() >= Duration::from_secs(timeout_secs))
+    }
+
     /// Handle a price update from the WebSocket
     pub fn handle_update(&mut self, update: PriceUpdate) {
         match update {
@@ -397,6 +1524,8 @@ impl App {
                 if let Some(coin) = self.coins.iter_mut().find(|c| c.symbol == symbol) {
                     // Update price, sparkline, and recalculate indicators
                     coin.update_price(price);
+                    coin.last_tick_at = Some(Instant::now());
+                    self.last_message_at = coin.last_tick_at;
 
                     coin.change_24h = change_24h;
                     coin.volume_usd = volume_24h_usd;
@@ -408,16 +1537,36 @@ impl App {
                         coin.low_24h = low_24h;
                     }
                 }
+                // Mark held positions to the live price until the next account snapshot
+                if let Some(account) = self.margin_account.as_mut() {
+                    account.apply_mark_price(&symbol, price);
+                }
+            }
+            PriceUpdate::Connected { provider } => {
+                self.set_provider_status(provider, ConnectionStatus::Connected);
+                // A connection that never delivers data goes stale from here
+                self.last_message_at = Some(Instant::now());
+                self.connection_log
+                    .push(ConnectionEventKind::Connected, provider);
             }
-            PriceUpdate::Connected => {
-                self.connection_status = ConnectionStatus::Connected;
+            PriceUpdate::Disconnected { provider } => {
+                self.set_provider_status(provider, ConnectionStatus::Disconnected);
+                self.connection_log
+                    .push(ConnectionEventKind::Disconnected, provider);
             }
-            PriceUpdate::Disconnected => {
-                self.connection_status = ConnectionStatus::Disconnected;
+            PriceUpdate::Reconnecting { provider } => {
+                self.set_provider_status(provider, ConnectionStatus::Connecting);
+                self.connection_log
+                    .push(ConnectionEventKind::Reconnecting, provider);
             }
             PriceUpdate::Candles { symbol, candles } => {
                 if let Some(coin) = self.coins.iter_mut().find(|c| c.symbol == symbol) {
-                    coin.set_candles(candles);
+                    coin.set_candles(candles, self.max_candles);
+                }
+            }
+            PriceUpdate::CandleTail { symbol, candles } => {
+                if let Some(coin) = self.coins.iter_mut().find(|c| c.symbol == symbol) {
+                    coin.merge_candles(candles, self.max_candles);
                 }
             }
             PriceUpdate::Kline {
@@ -426,11 +1575,13 @@ impl App {
                 is_closed,
             } => {
                 if let Some(coin) = self.coins.iter_mut().find(|c| c.symbol == symbol) {
-                    coin.update_candle(candle, is_closed);
+                    coin.update_candle(candle, is_closed, self.max_candles);
+                    self.last_message_at = Some(Instant::now());
                 }
             }
-            PriceUpdate::Error(_) => {
-                // Could log the error or show it in UI
+            PriceUpdate::Error(message) => {
+                self.connection_log
+                    .push(ConnectionEventKind::Error, &message);
             }
             PriceUpdate::MarginPositions { account } => {
                 self.set_margin_account(account);
@@ -442,6 +1593,307 @@ impl App {
 impl Default for App {
     fn default() -> Self {
         use crate::mock::generate_mock_coins;
-        Self::new(generate_mock_coins(), "mock")
+        Self::new(generate_mock_coins(&Default::default()), "mock")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_at(view: View) -> App {
+        let mut app = App::new(Vec::new(), "mock");
+        app.view = view;
+        app
+    }
+
+    /// App listing (symbol, name, price, 24h change, volume) coins in this order
+    fn app_with_coins(coins: &[(&str, &str, f64, f64, f64)]) -> App {
+        let coins = coins
+            .iter()
+            .map(|&(symbol, name, price, change, volume)| {
+                let mut coin = CoinData::new(symbol, name);
+                coin.price = price;
+                coin.change_24h = change;
+                coin.volume_usd = volume;
+                coin
+            })
+            .collect();
+        App::new(coins, "mock")
+    }
+
+    /// Symbols in overview display order
+    fn listed(app: &App) -> Vec<&str> {
+        app.sorted_coin_indices()
+            .iter()
+            .map(|&i| app.coins[i].symbol.as_str())
+            .collect()
+    }
+
+    fn sorted_by(app: &mut App, key: CoinSortKey, descending: bool) -> Vec<&str> {
+        app.sort_key = key;
+        app.sort_descending = descending;
+        listed(app)
+    }
+
+    #[test]
+    fn sorts_by_each_key_in_both_directions() {
+        let mut app = app_with_coins(&[
+            ("SOL", "Solana", 150.0, 5.0, 2e8),
+            ("BTC", "Bitcoin", 60_000.0, -1.0, 9e9),
+            ("ETH", "Ethereum", 3_000.0, 2.0, 5e9),
+        ]);
+        assert_eq!(listed(&app), ["SOL", "BTC", "ETH"]);
+
+        let cases = [
+            (CoinSortKey::Symbol, ["BTC", "ETH", "SOL"]),
+            (CoinSortKey::Price, ["SOL", "ETH", "BTC"]),
+            (CoinSortKey::Change24h, ["BTC", "ETH", "SOL"]),
+            (CoinSortKey::Volume, ["SOL", "ETH", "BTC"]),
+        ];
+        for (key, ascending) in cases {
+            assert_eq!(sorted_by(&mut app, key, false), ascending, "{:?}", key);
+            let mut descending = ascending;
+            descending.reverse();
+            assert_eq!(sorted_by(&mut app, key, true), descending, "{:?}", key);
+        }
+        assert_eq!(
+            sorted_by(&mut app, CoinSortKey::Listed, true),
+            ["SOL", "BTC", "ETH"]
+        );
+    }
+
+    #[test]
+    fn ties_fall_back_to_symbol_order() {
+        let mut app = app_with_coins(&[
+            ("XRP", "XRP", 1.0, 0.0, 0.0),
+            ("ADA", "Cardano", 1.0, 0.0, 0.0),
+        ]);
+        for descending in [false, true] {
+            assert_eq!(
+                sorted_by(&mut app, CoinSortKey::Price, descending),
+                ["ADA", "XRP"]
+            );
+        }
+    }
+
+    #[test]
+    fn missing_and_nan_values_sort_last_in_both_directions() {
+        let mut app = app_with_coins(&[
+            ("NEW", "No ticker yet", 0.0, f64::NAN, f64::NAN),
+            ("BTC", "Bitcoin", 60_000.0, -1.0, 9e9),
+            ("BAD", "Bad feed", f64::NAN, 1.0, 1e6),
+            ("ETH", "Ethereum", 3_000.0, 2.0, 5e9),
+        ]);
+        for descending in [false, true] {
+            let order = sorted_by(&mut app, CoinSortKey::Price, descending);
+            assert_eq!(order[2..], ["BAD", "NEW"], "descending {}", descending);
+            let order = sorted_by(&mut app, CoinSortKey::Change24h, descending);
+            assert_eq!(order[3], "NEW", "descending {}", descending);
+        }
+        assert_eq!(
+            sorted_by(&mut app, CoinSortKey::Volume, true),
+            ["BTC", "ETH", "BAD", "NEW"]
+        );
+    }
+
+    #[test]
+    fn selection_follows_the_coin_across_re_sorts() {
+        let mut app = app_with_coins(&[
+            ("SOL", "Solana", 150.0, 5.0, 2e8),
+            ("BTC", "Bitcoin", 60_000.0, -1.0, 9e9),
+            ("ETH", "Ethereum", 3_000.0, 2.0, 5e9),
+        ]);
+        app.selected_index = 2;
+
+        app.cycle_sort_key();
+        assert_eq!(app.sort_key, CoinSortKey::Symbol);
+        assert_eq!(app.coins[app.selected_index].symbol, "ETH");
+        app.move_down();
+        assert_eq!(app.coins[app.selected_index].symbol, "SOL");
+
+        // Price, largest first: SOL is the bottom row, BTC above ETH
+        app.cycle_sort_key();
+        assert_eq!(app.coins[app.selected_index].symbol, "SOL");
+        app.move_up();
+        assert_eq!(app.coins[app.selected_index].symbol, "ETH");
+        app.toggle_sort_direction();
+        app.move_up();
+        assert_eq!(app.coins[app.selected_index].symbol, "SOL");
+    }
+
+    fn search_for(app: &mut App, query: &str) {
+        app.start_search();
+        query.chars().for_each(|ch| app.search_push(ch));
+    }
+
+    #[test]
+    fn search_matches_symbol_or_name_ignoring_case() {
+        let mut app = app_with_coins(&[
+            ("BTC", "Bitcoin", 60_000.0, -1.0, 9e9),
+            ("ETH", "Ethereum", 3_000.0, 2.0, 5e9),
+            ("BCH", "Bitcoin Cash", 400.0, 1.0, 3e8),
+            ("SOL", "Solana", 150.0, 5.0, 2e8),
+        ]);
+
+        search_for(&mut app, "eth");
+        assert_eq!(listed(&app), ["ETH"]);
+
+        // Symbol "BCH" and name "Bitcoin Cash" both match
+        search_for(&mut app, "Bc");
+        assert_eq!(listed(&app), ["BCH"]);
+        search_for(&mut app, "COIN");
+        assert_eq!(listed(&app), ["BTC", "BCH"]);
+
+        search_for(&mut app, "doge");
+        assert!(listed(&app).is_empty());
+    }
+
+    #[test]
+    fn empty_search_lists_every_coin_in_the_current_sort() {
+        let mut app = app_with_coins(&[
+            ("SOL", "Solana", 150.0, 5.0, 2e8),
+            ("BTC", "Bitcoin", 60_000.0, -1.0, 9e9),
+        ]);
+        app.sort_key = CoinSortKey::Symbol;
+        search_for(&mut app, "  ");
+        assert_eq!(listed(&app), ["BTC", "SOL"]);
+
+        // Matches keep the table's order, and the highlight starts on the first
+        search_for(&mut app, "o");
+        assert_eq!(listed(&app), ["BTC", "SOL"]);
+        assert_eq!(app.coins[app.selected_index].symbol, "BTC");
+        app.search_backspace();
+        assert_eq!(app.search.as_ref().unwrap().query, "");
+    }
+
+    #[test]
+    fn switch_view_refreshes_on_focus_when_enabled() {
+        let mut app = app_at(View::News);
+        app.positions_refresh_on_focus = true;
+        app.switch_view();
+        assert_eq!(app.view, View::Positions);
+        assert!(app.needs_positions_refresh);
+
+        let mut app = app_at(View::Notifications);
+        app.news_refresh_on_focus = true;
+        app.switch_view();
+        assert_eq!(app.view, View::News);
+        assert!(app.needs_news_refresh);
+    }
+
+    #[test]
+    fn switch_view_leaves_refresh_to_the_user_when_disabled() {
+        let mut app = app_at(View::News);
+        app.positions_refresh_on_focus = false;
+        app.switch_view();
+        assert_eq!(app.view, View::Positions);
+        assert!(!app.needs_positions_refresh);
+
+        let mut app = app_at(View::Notifications);
+        app.news_refresh_on_focus = false;
+        app.switch_view();
+        assert_eq!(app.view, View::News);
+        assert!(!app.needs_news_refresh);
+    }
+
+    #[test]
+    fn one_provider_dropping_out_is_not_masked_by_another() {
+        let mut app = App::new(Vec::new(), "binance+coinbase");
+        let status = |app: &App, provider: &str| {
+            app.provider_status
+                .iter()
+                .find(|(name, _)| name == provider)
+                .map(|(_, status)| *status)
+        };
+
+        app.handle_update(PriceUpdate::Connected {
+            provider: "binance",
+        });
+        assert_eq!(app.connection_status, ConnectionStatus::Connecting);
+        app.handle_update(PriceUpdate::Connected {
+            provider: "coinbase",
+        });
+        assert_eq!(app.connection_status, ConnectionStatus::Connected);
+
+        app.handle_update(PriceUpdate::Disconnected {
+            provider: "coinbase",
+        });
+        assert_eq!(app.connection_status, ConnectionStatus::Disconnected);
+        assert_eq!(status(&app, "binance"), Some(ConnectionStatus::Connected));
+        assert_eq!(
+            status(&app, "coinbase"),
+            Some(ConnectionStatus::Disconnected)
+        );
+    }
+
+    fn article(title: &str, link: Option<&str>) -> NewsArticle {
+        NewsArticle {
+            title: title.to_string(),
+            source: "test".to_string(),
+            published_at: 0,
+            link: link.map(str::to_string),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn news_refresh_keeps_the_selected_article() {
+        let mut app = app_at(View::News);
+        app.set_news(vec![
+            article("A", Some("https://a")),
+            article("B", Some("https://b")),
+        ]);
+        app.news_selected = 1;
+        app.news_content_scroll = 3;
+
+        // A newer article pushes "B" down; its headline was edited but the link matches
+        app.set_news(vec![
+            article("New", Some("https://new")),
+            article("A", Some("https://a")),
+            article("B (updated)", Some("https://b")),
+        ]);
+        assert_eq!(app.news_selected, 2);
+        assert_eq!(app.news_content_scroll, 3);
+
+        // Gone from the feed: back to the top
+        app.set_news(vec![article("New", Some("https://new"))]);
+        assert_eq!(app.news_selected, 0);
+        assert_eq!(app.news_content_scroll, 0);
+    }
+
+    #[test]
+    fn smooth_scroll_eases_news_content_and_snaps_to_a_new_article() {
+        let frame = Duration::from_millis(16);
+        let mut app = app_at(View::News);
+        app.set_news(vec![
+            article("A", Some("https://a")),
+            article("B", Some("https://b")),
+        ]);
+        app.set_smooth_scroll(true);
+        app.set_news_content_lines(20, 5);
+        app.scroll_content_down();
+        app.scroll_content_down();
+
+        app.update_scroll(frame);
+        let offset = app.news_content_offset();
+        assert!(offset > 0.0 && offset < 2.0, "offset {}", offset);
+        for _ in 0..120 {
+            app.update_scroll(frame);
+        }
+        assert_eq!(app.news_content_offset(), 2.0);
+
+        app.scroll_news_down();
+        assert_eq!(app.news_content_offset(), 0.0);
+    }
+
+    #[test]
+    fn scroll_offsets_follow_input_without_smooth_scroll() {
+        let mut app = app_at(View::News);
+        app.set_news_content_lines(20, 5);
+        app.scroll_content_down();
+        assert_eq!(app.news_content_offset(), 1.0);
+        app.notification_scroll = 3;
+        assert_eq!(app.notification_offset(), 3.0);
     }
 }
@@ -4,6 +4,10 @@ use gbm::{AsRaw, BufferObjectFlags, Device as GbmDevice};
 use glow::HasContext;
 use khronos_egl as egl;
 use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd};
+use std::path::{Path, PathBuf};
+
+use super::gl_caps::GlCapabilities;
+use super::png;
 
 pub struct Card(std::fs::File);
 
@@ -22,15 +26,38 @@ impl AsRawFd for Card {
 impl BasicDevice for Card {}
 impl ControlDevice for Card {}
 
+/// Display setup options (from the "display" config section)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayOptions {
+    /// Force safe mode even if the driver looks capable
+    pub safe_mode: bool,
+    /// Requested MSAA samples per pixel (0 = off)
+    pub msaa: u8,
+}
+
 pub struct Display {
     pub gl: glow::Context,
     pub width: u32,
     pub height: u32,
+    /// Driver details detected after context creation
+    pub caps: GlCapabilities,
+    /// Optional GL effects should be skipped (forced or detected)
+    pub safe_mode: bool,
+    /// MSAA samples actually provided by the EGL config (0 = off)
+    pub msaa_samples: u8,
     egl_inst: egl::DynamicInstance<egl::EGL1_4>,
     egl_display: egl::Display,
     egl_surface: egl::Surface,
     #[allow(dead_code)]
     egl_context: egl::Context,
+    /// DRM/GBM page flipping; None for headless (pbuffer) displays
+    scanout: Option<Scanout>,
+    /// PNG path to write the next frame to, captured in `swap_buffers`
+    pending_screenshot: Option<PathBuf>,
+}
+
+/// DRM/GBM state for presenting frames on a real display
+struct Scanout {
     gbm: GbmDevice<Card>,
     gbm_surface: gbm::Surface<()>,
     drm_fd: i32,
@@ -42,8 +69,18 @@ pub struct Display {
     frame_count: u32,
 }
 
+/// EGL surface/context plus the GL state created on top of them
+struct EglSetup {
+    gl: glow::Context,
+    caps: GlCapabilities,
+    safe_mode: bool,
+    msaa_samples: u8,
+    surface: egl::Surface,
+    context: egl::Context,
+}
+
 impl Display {
-    pub fn new() -> Result<Self, String> {
+    pub fn new(options: DisplayOptions) -> Result<Self, String> {
         println!("Initializing DRM/GBM/EGL display...\n");
 
         // Open DRM device
@@ -107,110 +144,170 @@ impl Display {
         let egl_display =
             unsafe { egl_inst.get_display(gbm.as_raw() as _) }.ok_or("No EGL display")?;
 
-        let (maj, min) = egl_inst
-            .initialize(egl_display)
-            .map_err(|e| format!("EGL init failed: {}", e))?;
-        println!("EGL {}.{}", maj, min);
+        let width = mode.size().0 as u32;
+        let height = mode.size().1 as u32;
 
-        let config = egl_inst
-            .choose_first_config(
-                egl_display,
-                &[
-                    egl::RED_SIZE,
-                    8,
-                    egl::GREEN_SIZE,
-                    8,
-                    egl::BLUE_SIZE,
-                    8,
-                    egl::ALPHA_SIZE,
-                    8,
-                    egl::DEPTH_SIZE,
-                    0,
-                    egl::RENDERABLE_TYPE,
-                    egl::OPENGL_ES2_BIT,
-                    egl::SURFACE_TYPE,
-                    egl::WINDOW_BIT,
-                    egl::NONE,
-                ],
-            )
-            .map_err(|e| format!("Config error: {}", e))?
-            .ok_or("No suitable EGL config")?;
-
-        egl_inst
-            .bind_api(egl::OPENGL_ES_API)
-            .map_err(|e| format!("Failed to bind API: {}", e))?;
-
-        let egl_context = egl_inst
-            .create_context(
-                egl_display,
-                config,
-                None,
-                &[egl::CONTEXT_CLIENT_VERSION, 2, egl::NONE],
-            )
-            .map_err(|e| format!("Context failed: {}", e))?;
+        let setup = create_context(
+            &egl_inst,
+            egl_display,
+            options,
+            egl::WINDOW_BIT,
+            (width, height),
+            |config| {
+                unsafe {
+                    egl_inst.create_window_surface(
+                        egl_display,
+                        config,
+                        gbm_surface.as_raw() as _,
+                        None,
+                    )
+                }
+                .map_err(|e| format!("Window surface failed: {}", e))
+            },
+        )?;
 
-        let egl_surface = unsafe {
-            egl_inst.create_window_surface(egl_display, config, gbm_surface.as_raw() as _, None)
-        }
-        .map_err(|e| format!("Window surface failed: {}", e))?;
-
-        egl_inst
-            .make_current(
-                egl_display,
-                Some(egl_surface),
-                Some(egl_surface),
-                Some(egl_context),
-            )
-            .map_err(|e| format!("Make current failed: {}", e))?;
+        Ok(Display {
+            gl: setup.gl,
+            width,
+            height,
+            caps: setup.caps,
+            safe_mode: setup.safe_mode,
+            msaa_samples: setup.msaa_samples,
+            egl_inst,
+            egl_display,
+            egl_surface: setup.surface,
+            egl_context: setup.context,
+            scanout: Some(Scanout {
+                gbm,
+                gbm_surface,
+                drm_fd,
+                crtc_handle,
+                connector_handle,
+                mode,
+                front_bo: None,
+                front_fb: None,
+                frame_count: 0,
+            }),
+            pending_screenshot: None,
+        })
+    }
 
-        // Create OpenGL ES context
-        let gl = unsafe {
-            glow::Context::from_loader_function(|s| {
-                egl_inst
-                    .get_proc_address(s)
-                    .map(|p| p as _)
-                    .unwrap_or(std::ptr::null())
-            })
-        };
+    /// Off-screen display backed by an EGL pbuffer, for machines without DRM
+    /// scanout (CI, screenshots). Frames are rendered but never presented.
+    pub fn new_headless(width: u32, height: u32, options: DisplayOptions) -> Result<Self, String> {
+        println!(
+            "Initializing headless EGL display ({}x{})...\n",
+            width, height
+        );
 
-        println!("Renderer: {}", unsafe {
-            gl.get_parameter_string(glow::RENDERER)
-        });
+        let egl_inst = unsafe { egl::DynamicInstance::<egl::EGL1_4>::load_required() }
+            .map_err(|e| format!("Failed to load EGL: {}", e))?;
 
-        let width = mode.size().0 as u32;
-        let height = mode.size().1 as u32;
+        let egl_display =
+            unsafe { egl_inst.get_display(egl::DEFAULT_DISPLAY) }.ok_or("No EGL display")?;
 
-        unsafe {
-            gl.viewport(0, 0, width as i32, height as i32);
-            gl.enable(glow::BLEND);
-            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
-        }
+        let setup = create_context(
+            &egl_inst,
+            egl_display,
+            options,
+            egl::PBUFFER_BIT,
+            (width, height),
+            |config| {
+                egl_inst
+                    .create_pbuffer_surface(
+                        egl_display,
+                        config,
+                        &[
+                            egl::WIDTH,
+                            width as egl::Int,
+                            egl::HEIGHT,
+                            height as egl::Int,
+                            egl::NONE,
+                        ],
+                    )
+                    .map_err(|e| format!("Pbuffer surface failed: {}", e))
+            },
+        )?;
 
         Ok(Display {
-            gl,
+            gl: setup.gl,
             width,
             height,
+            caps: setup.caps,
+            safe_mode: setup.safe_mode,
+            msaa_samples: setup.msaa_samples,
             egl_inst,
             egl_display,
-            egl_surface,
-            egl_context,
-            gbm,
-            gbm_surface,
-            drm_fd,
-            crtc_handle,
-            connector_handle,
-            mode,
-            front_bo: None,
-            front_fb: None,
-            frame_count: 0,
+            egl_surface: setup.surface,
+            egl_context: setup.context,
+            scanout: None,
+            pending_screenshot: None,
         })
     }
 
+    /// Write the next completed frame to `path` as a PNG
+    pub fn request_screenshot(&mut self, path: PathBuf) {
+        self.pending_screenshot = Some(path);
+    }
+
     pub fn swap_buffers(&mut self) -> Result<(), String> {
+        // Read back before swapping: the back buffer is undefined afterwards
+        if let Some(path) = self.pending_screenshot.take() {
+            self.write_screenshot(&path)?;
+        }
+
         self.egl_inst
             .swap_buffers(self.egl_display, self.egl_surface)
             .map_err(|e| format!("Swap failed: {}", e))?;
 
+        match self.scanout.as_mut() {
+            Some(scanout) => scanout.present(),
+            None => Ok(()),
+        }
+    }
+
+    fn write_screenshot(&self, path: &Path) -> Result<(), String> {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut pixels = vec![0u8; width * height * 4];
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+        }
+
+        // GL rows run bottom-up; PNG rows run top-down
+        let stride = width * 4;
+        let flipped: Vec<u8> = pixels
+            .chunks_exact(stride)
+            .rev()
+            .flatten()
+            .copied()
+            .collect();
+
+        png::write_rgba(path, self.width, self.height, &flipped)
+            .map_err(|e| format!("Failed to write screenshot {}: {}", path.display(), e))?;
+        println!("Screenshot written to {}", path.display());
+        Ok(())
+    }
+
+    pub fn clear(&self, r: f32, g: f32, b: f32, a: f32) {
+        unsafe {
+            self.gl.clear_color(r, g, b, a);
+            self.gl.clear(glow::COLOR_BUFFER_BIT);
+        }
+    }
+}
+
+impl Scanout {
+    /// Put the just-swapped GBM buffer on screen (modeset on the first frame,
+    /// then page flips waiting for the flip event)
+    fn present(&mut self) -> Result<(), String> {
         let bo = unsafe {
             self.gbm_surface
                 .lock_front_buffer()
@@ -266,10 +363,144 @@ impl Display {
         Ok(())
     }
 
-    pub fn clear(&self, r: f32, g: f32, b: f32, a: f32) {
-        unsafe {
-            self.gl.clear_color(r, g, b, a);
-            self.gl.clear(glow::COLOR_BUFFER_BIT);
+    /// Release the on-screen framebuffer (before the EGL surface goes away)
+    fn release(&mut self) {
+        if let Some(fb) = self.front_fb.take() {
+            let _ = self.gbm.destroy_framebuffer(fb);
+        }
+        drop(self.front_bo.take());
+    }
+}
+
+/// Initialize EGL on `egl_display`, create a context and a surface of
+/// `surface_type` (window or pbuffer), make it current and set up GL
+fn create_context(
+    egl_inst: &egl::DynamicInstance<egl::EGL1_4>,
+    egl_display: egl::Display,
+    options: DisplayOptions,
+    surface_type: egl::Int,
+    (width, height): (u32, u32),
+    create_surface: impl FnOnce(egl::Config) -> Result<egl::Surface, String>,
+) -> Result<EglSetup, String> {
+    let (maj, min) = egl_inst
+        .initialize(egl_display)
+        .map_err(|e| format!("EGL init failed: {}", e))?;
+    println!("EGL {}.{}", maj, min);
+
+    // Forced safe mode skips multisampling entirely
+    let requested_samples = if options.safe_mode { 0 } else { options.msaa };
+    let (config, msaa_samples) =
+        choose_config(egl_inst, egl_display, surface_type, requested_samples)?;
+    if msaa_samples > 0 {
+        println!("MSAA: {}x", msaa_samples);
+    }
+
+    egl_inst
+        .bind_api(egl::OPENGL_ES_API)
+        .map_err(|e| format!("Failed to bind API: {}", e))?;
+
+    let context = egl_inst
+        .create_context(
+            egl_display,
+            config,
+            None,
+            &[egl::CONTEXT_CLIENT_VERSION, 2, egl::NONE],
+        )
+        .map_err(|e| format!("Context failed: {}", e))?;
+
+    let surface = create_surface(config)?;
+
+    egl_inst
+        .make_current(egl_display, Some(surface), Some(surface), Some(context))
+        .map_err(|e| format!("Make current failed: {}", e))?;
+
+    // Create OpenGL ES context
+    let gl = unsafe {
+        glow::Context::from_loader_function(|s| {
+            egl_inst
+                .get_proc_address(s)
+                .map(|p| p as _)
+                .unwrap_or(std::ptr::null())
+        })
+    };
+
+    let caps = GlCapabilities::detect(&gl);
+    caps.log();
+    caps.verify(&gl)?;
+
+    let safe_mode = match caps.safe_mode_reason() {
+        Some(reason) => {
+            println!("Safe mode: {}", reason);
+            true
+        }
+        None if options.safe_mode => {
+            println!("Safe mode: forced by config");
+            true
+        }
+        None => false,
+    };
+
+    unsafe {
+        gl.viewport(0, 0, width as i32, height as i32);
+        // No glEnable(MULTISAMPLE) here: ES2 has no such cap and always
+        // resolves multisampled surfaces when the config has sample buffers
+        gl.enable(glow::BLEND);
+        gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+    }
+
+    Ok(EglSetup {
+        gl,
+        caps,
+        safe_mode,
+        msaa_samples,
+        surface,
+        context,
+    })
+}
+
+/// Choose an RGBA8 ES2 config for `surface_type`, asking for `samples` MSAA
+/// samples and halving the count down to none when the driver can't provide it
+fn choose_config(
+    egl_inst: &egl::DynamicInstance<egl::EGL1_4>,
+    egl_display: egl::Display,
+    surface_type: egl::Int,
+    samples: u8,
+) -> Result<(egl::Config, u8), String> {
+    let mut samples = samples;
+    loop {
+        let attributes = [
+            egl::RED_SIZE,
+            8,
+            egl::GREEN_SIZE,
+            8,
+            egl::BLUE_SIZE,
+            8,
+            egl::ALPHA_SIZE,
+            8,
+            egl::DEPTH_SIZE,
+            0,
+            egl::RENDERABLE_TYPE,
+            egl::OPENGL_ES2_BIT,
+            egl::SURFACE_TYPE,
+            surface_type,
+            egl::SAMPLE_BUFFERS,
+            (samples > 0) as egl::Int,
+            egl::SAMPLES,
+            samples as egl::Int,
+            egl::NONE,
+        ];
+
+        let config = egl_inst
+            .choose_first_config(egl_display, &attributes)
+            .map_err(|e| format!("Config error: {}", e))?;
+        match config {
+            Some(config) => return Ok((config, samples)),
+            None if samples == 0 => return Err("No suitable EGL config".to_string()),
+            None => {
+                let fallback = if samples > 2 { samples / 2 } else { 0 };
+                println!("MSAA {}x unavailable, trying {}x", samples, fallback);
+                samples = fallback;
+            }
         }
     }
 }
@@ -277,10 +508,9 @@ impl Display {
 impl Drop for Display {
     fn drop(&mut self) {
         // Cleanup must happen in correct order to avoid segfault
-        if let Some(fb) = self.front_fb.take() {
-            let _ = self.gbm.destroy_framebuffer(fb);
+        if let Some(scanout) = self.scanout.as_mut() {
+            scanout.release();
         }
-        drop(self.front_bo.take());
 
         let _ = self
             .egl_inst
@@ -1,9 +1,13 @@
+use crate::base::renderer::rect_renderer::Rect;
+
 /// Manages keyboard focus across focusable panels
 pub struct FocusManager {
     /// List of focusable panel IDs in navigation order
     focus_order: Vec<String>,
     /// Current focus index
     current_index: usize,
+    /// Resolved bounds of the focusable panels (navigation order), for hit-testing
+    rects: Vec<(String, Rect)>,
 }
 
 impl FocusManager {
@@ -11,6 +15,7 @@ impl FocusManager {
         Self {
             focus_order: Vec::new(),
             current_index: 0,
+            rects: Vec::new(),
         }
     }
 
@@ -71,6 +76,25 @@ impl FocusManager {
         }
     }
 
+    /// Set the on-screen bounds of the focusable panels (call after layout)
+    pub fn set_focus_rects(&mut self, rects: Vec<(String, Rect)>) {
+        self.rects = rects;
+    }
+
+    /// Focus the panel under a screen point and return its navigation index;
+    /// the innermost panel wins where they nest (None if nothing is hit)
+    pub fn focus_at(&mut self, x: f32, y: f32) -> Option<usize> {
+        let (index, id) = self
+            .rects
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, (_, rect))| rect.contains(x, y))
+            .map(|(index, (id, _))| (index, id.clone()))?;
+        self.set_focus(&id);
+        Some(index)
+    }
+
     /// Get the index of the currently focused panel
     pub fn current_index(&self) -> usize {
         self.current_index
@@ -87,3 +111,47 @@ impl Default for FocusManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A manager with an outer panel and a nested inner one, focus on "outer"
+    fn nested() -> FocusManager {
+        let mut focus = FocusManager::new();
+        focus.set_focus_order(vec!["outer".into(), "inner".into()]);
+        focus.set_focus_rects(vec![
+            ("outer".into(), Rect::new(0.0, 0.0, 100.0, 100.0)),
+            ("inner".into(), Rect::new(20.0, 20.0, 30.0, 30.0)),
+        ]);
+        focus
+    }
+
+    #[test]
+    fn innermost_panel_wins_where_they_nest() {
+        let mut focus = nested();
+        assert_eq!(focus.focus_at(25.0, 25.0), Some(1));
+        assert_eq!(focus.current(), Some("inner"));
+        assert_eq!(focus.focus_at(5.0, 5.0), Some(0));
+        assert_eq!(focus.current(), Some("outer"));
+    }
+
+    #[test]
+    fn miss_leaves_focus_unchanged() {
+        let mut focus = nested();
+        focus.set_focus("inner");
+        assert_eq!(focus.focus_at(150.0, 50.0), None);
+        assert_eq!(focus.focus_at(-1.0, 50.0), None);
+        assert_eq!(focus.current(), Some("inner"));
+    }
+
+    #[test]
+    fn left_and_top_edges_hit_right_and_bottom_edges_miss() {
+        let mut focus = nested();
+        assert_eq!(focus.focus_at(20.0, 20.0), Some(1));
+        assert_eq!(focus.focus_at(50.0, 30.0), Some(0));
+        assert_eq!(focus.focus_at(30.0, 50.0), Some(0));
+        assert_eq!(focus.focus_at(100.0, 50.0), None);
+        assert_eq!(focus.focus_at(50.0, 100.0), None);
+    }
+}
@@ -11,8 +11,15 @@ const EXTRA_CHARS: &[char] = &[
     '▼', // price down
     '←', // left arrow (scroll hint)
     '→', // right arrow (scroll hint)
+    '…', // ellipsis (truncated text)
+    '—', // em dash (unknown values)
+    '×', // multiplication sign (repeat counts)
+    '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█', // sparkline bars
 ];
 
+/// Braille patterns (sparkline line and dot styles)
+const BRAILLE_RANGE: std::ops::RangeInclusive<char> = '\u{2800}'..='\u{28FF}';
+
 #[derive(Clone, Copy, Debug)]
 pub struct GlyphInfo {
     pub uv_min: (f32, f32),
@@ -27,6 +34,8 @@ pub struct FontAtlas {
     pub glyphs: HashMap<char, GlyphInfo>,
     pub atlas_size: u32,
     pub line_height: f32,
+    /// Non-zero kerning offsets (pixels at atlas size) between ASCII pairs
+    kerning: HashMap<(char, char), f32>,
 }
 
 impl FontAtlas {
@@ -34,9 +43,10 @@ impl FontAtlas {
         let font = Font::from_bytes(font_data, FontSettings::default())
             .map_err(|e| format!("Failed to load font: {}", e))?;
 
-        // ASCII printable characters (32-126) plus explicit extras
+        // ASCII printable characters (32-126) plus explicit extras and braille
         let mut chars: Vec<char> = (32u8..=126u8).map(|c| c as char).collect();
         chars.extend(EXTRA_CHARS.iter().copied());
+        chars.extend(BRAILLE_RANGE);
         chars.sort_unstable();
         chars.dedup();
 
@@ -165,11 +175,14 @@ impl FontAtlas {
             tex
         };
 
+        let kerning = kerning_pairs(&font, font_size);
+
         println!(
-            "Font atlas created: {}x{} ({} glyphs)",
+            "Font atlas created: {}x{} ({} glyphs, {} kerning pairs)",
             atlas_size,
             atlas_size,
-            glyphs.len()
+            glyphs.len(),
+            kerning.len()
         );
 
         Ok(FontAtlas {
@@ -177,21 +190,66 @@ impl FontAtlas {
             glyphs,
             atlas_size,
             line_height,
+            kerning,
         })
     }
 
+    /// Atlas of fixed-advance ASCII glyphs with the given kerning, for layout
+    /// tests without a GL context (the placeholder texture is never bound)
+    #[cfg(test)]
+    pub fn for_tests(advance: f32, kerning: HashMap<(char, char), f32>) -> Self {
+        let glyph = GlyphInfo {
+            uv_min: (0.0, 0.0),
+            uv_max: (0.0, 0.0),
+            size: (advance, 10.0),
+            bearing: (0.0, 0.0),
+            advance,
+        };
+        FontAtlas {
+            texture: glow::NativeTexture(std::num::NonZeroU32::MIN),
+            glyphs: (32u8..=126u8).map(|c| (c as char, glyph)).collect(),
+            atlas_size: 256,
+            line_height: 12.0,
+            kerning,
+        }
+    }
+
     pub fn get_glyph(&self, c: char) -> Option<&GlyphInfo> {
         self.glyphs.get(&c).or_else(|| self.glyphs.get(&'?'))
     }
 
+    /// Horizontal offset between two consecutive glyphs, in pixels at atlas size
+    /// (0.0 when the font has no kern data for the pair, e.g. monospace fonts)
+    pub fn kerning(&self, left: char, right: char) -> f32 {
+        self.kerning.get(&(left, right)).copied().unwrap_or(0.0)
+    }
+
     /// Measure text dimensions without needing a TextRenderer
-    /// Returns (width, height) in pixels at the given scale
+    /// Returns (width, height) in pixels at the given scale; embedded newlines
+    /// add one `line_height` each, and width is the widest line
     pub fn measure_text(&self, text: &str, scale: f32) -> (f32, f32) {
+        let mut lines = text.split('\n');
+        let (mut width, mut height) = self.measure_line(lines.next().unwrap_or(""), scale);
+
+        for line in lines {
+            width = width.max(self.measure_line(line, scale).0);
+            height += self.line_height * scale;
+        }
+
+        (width, height)
+    }
+
+    fn measure_line(&self, text: &str, scale: f32) -> (f32, f32) {
         let mut width = 0.0f32;
         let mut height = 0.0f32;
+        let mut prev: Option<char> = None;
 
         for c in text.chars() {
             if let Some(glyph) = self.get_glyph(c) {
+                if let Some(p) = prev {
+                    width += self.kerning(p, c) * scale;
+                }
+                prev = Some(c);
                 width += glyph.advance * scale;
                 // Include bearing to match draw_text positioning
                 let glyph_height = (glyph.size.1 + glyph.bearing.1) * scale;
@@ -205,3 +263,24 @@ impl FontAtlas {
         (width, height)
     }
 }
+
+/// Kerning for every printable ASCII pair the font's kern table covers
+///
+/// Extras (block elements, braille) are left out: they are drawn in
+/// fixed-width runs where kerning would misalign them.
+fn kerning_pairs(font: &Font, font_size: f32) -> HashMap<(char, char), f32> {
+    let ascii: Vec<char> = (32u8..=126u8).map(|c| c as char).collect();
+    let mut pairs = HashMap::new();
+
+    for &left in &ascii {
+        for &right in &ascii {
+            if let Some(kern) = font.horizontal_kern(left, right, font_size) {
+                if kern != 0.0 {
+                    pairs.insert((left, right), kern);
+                }
+            }
+        }
+    }
+
+    pairs
+}
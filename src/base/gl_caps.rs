@@ -0,0 +1,133 @@
+//! GL capability detection run right after context creation
+//!
+//! Catches drivers that create an ES2 context but can't actually compile our
+//! shaders, and flags setups where optional effects should be turned off.
+
+use glow::HasContext;
+
+const PROBE_VERTEX_SHADER: &str = r#"
+    attribute vec2 a_pos;
+    void main() {
+        gl_Position = vec4(a_pos, 0.0, 1.0);
+    }
+"#;
+
+const PROBE_FRAGMENT_SHADER: &str = r#"
+    precision mediump float;
+    void main() {
+        gl_FragColor = vec4(1.0);
+    }
+"#;
+
+/// Renderer substrings of software rasterizers, too slow for full-screen effects
+const SOFTWARE_RENDERERS: [&str; 3] = ["llvmpipe", "softpipe", "swrast"];
+
+/// What the current GL context reports about itself
+#[derive(Debug, Clone)]
+pub struct GlCapabilities {
+    pub vendor: String,
+    pub renderer: String,
+    pub version: String,
+    pub shading_language: String,
+    pub extensions: Vec<String>,
+    pub max_texture_size: i32,
+}
+
+impl GlCapabilities {
+    /// Query the current context
+    pub fn detect(gl: &glow::Context) -> Self {
+        unsafe {
+            let extensions = gl
+                .get_parameter_string(glow::EXTENSIONS)
+                .split_whitespace()
+                .map(str::to_string)
+                .collect();
+            Self {
+                vendor: gl.get_parameter_string(glow::VENDOR),
+                renderer: gl.get_parameter_string(glow::RENDERER),
+                version: gl.get_parameter_string(glow::VERSION),
+                shading_language: gl.get_parameter_string(glow::SHADING_LANGUAGE_VERSION),
+                extensions,
+                max_texture_size: gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE),
+            }
+        }
+    }
+
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|e| e == name)
+    }
+
+    /// Print the detected driver details
+    pub fn log(&self) {
+        println!("Renderer: {} ({})", self.renderer, self.vendor);
+        println!("GL: {}, GLSL: {}", self.version, self.shading_language);
+        println!(
+            "Max texture size: {}, {} extensions",
+            self.max_texture_size,
+            self.extensions.len()
+        );
+    }
+
+    /// Fail with a readable diagnostic if the context can't run our renderers
+    pub fn verify(&self, gl: &glow::Context) -> Result<(), String> {
+        if !self.version.starts_with("OpenGL ES 2") && !self.version.starts_with("OpenGL ES 3") {
+            return Err(format!(
+                "{} reports '{}', but OpenGL ES 2.0 or newer is required",
+                self.renderer, self.version
+            ));
+        }
+
+        probe_shaders(gl).map_err(|e| {
+            format!(
+                "{} ({}) failed to build a trivial shader: {}",
+                self.renderer, self.version, e
+            )
+        })
+    }
+
+    /// Reason optional GL effects should be disabled on this driver, if any
+    pub fn safe_mode_reason(&self) -> Option<&'static str> {
+        let renderer = self.renderer.to_lowercase();
+        if SOFTWARE_RENDERERS.iter().any(|r| renderer.contains(r)) {
+            return Some("software renderer");
+        }
+        None
+    }
+}
+
+/// Compile and link a minimal program, cleaning up afterwards
+fn probe_shaders(gl: &glow::Context) -> Result<(), String> {
+    unsafe {
+        let program = gl.create_program()?;
+        let mut shaders = Vec::new();
+        let mut result = Ok(());
+
+        for (kind, source) in [
+            (glow::VERTEX_SHADER, PROBE_VERTEX_SHADER),
+            (glow::FRAGMENT_SHADER, PROBE_FRAGMENT_SHADER),
+        ] {
+            let shader = gl.create_shader(kind)?;
+            gl.shader_source(shader, source);
+            gl.compile_shader(shader);
+            if !gl.get_shader_compile_status(shader) {
+                result = Err(format!("compile error: {}", gl.get_shader_info_log(shader)));
+            }
+            gl.attach_shader(program, shader);
+            shaders.push(shader);
+        }
+
+        if result.is_ok() {
+            gl.link_program(program);
+            if !gl.get_program_link_status(program) {
+                result = Err(format!("link error: {}", gl.get_program_info_log(program)));
+            }
+        }
+
+        for shader in shaders {
+            gl.detach_shader(program, shader);
+            gl.delete_shader(shader);
+        }
+        gl.delete_program(program);
+        result
+    }
+}
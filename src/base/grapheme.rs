@@ -0,0 +1,164 @@
+//! Minimal grapheme cluster segmentation for UI text
+//!
+//! Covers the cases that show up in news titles without pulling in a full
+//! Unicode segmentation table: combining marks, variation selectors, emoji
+//! skin-tone modifiers, zero-width-joiner sequences, and regional indicator
+//! pairs (flags). Anything else is one cluster per char.
+
+/// Whether `ch` extends the preceding cluster instead of starting a new one
+fn is_extender(ch: char) -> bool {
+    matches!(ch as u32,
+        0x0300..=0x036F     // Combining Diacritical Marks
+        | 0x1AB0..=0x1AFF   // Combining Diacritical Marks Extended
+        | 0x1DC0..=0x1DFF   // Combining Diacritical Marks Supplement
+        | 0x20D0..=0x20FF   // Combining Diacritical Marks for Symbols
+        | 0xFE20..=0xFE2F   // Combining Half Marks
+        | 0xFE00..=0xFE0F   // Variation Selectors
+        | 0x1F3FB..=0x1F3FF // Emoji skin tone modifiers
+        | 0xE0020..=0xE007F // Tag characters (subdivision flags)
+        | 0x200D            // Zero width joiner
+    )
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch as u32, 0x1F1E6..=0x1F1FF)
+}
+
+/// Split text into grapheme clusters (as string slices, in order)
+pub fn graphemes(text: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut start = 0;
+    let mut prev: Option<char> = None;
+    // Regional indicators pair up: the second of a pair joins the first
+    let mut pending_regional = false;
+
+    for (idx, ch) in text.char_indices() {
+        let joins = match prev {
+            None => false,
+            Some(p) => {
+                is_extender(ch)
+                    || p == '\u{200D}'
+                    || (pending_regional && is_regional_indicator(ch))
+            }
+        };
+
+        if !joins && idx > start {
+            clusters.push(&text[start..idx]);
+            start = idx;
+        }
+
+        pending_regional = is_regional_indicator(ch) && !(joins && pending_regional);
+        prev = Some(ch);
+    }
+
+    if start < text.len() {
+        clusters.push(&text[start..]);
+    }
+
+    clusters
+}
+
+/// Truncate to at most `max` grapheme clusters, appending "..." when cut
+pub fn truncate_graphemes(text: &str, max: usize) -> String {
+    let clusters = graphemes(text);
+    if clusters.len() <= max {
+        return text.to_string();
+    }
+    if max < 4 {
+        return clusters[..max].concat();
+    }
+    format!("{}...", clusters[..max - 3].concat())
+}
+
+/// Word-wrap `text` into lines no wider than `max_width`
+///
+/// `width_of` measures a run of text in the caller's unit (pixels, monospace
+/// cells, ...) and is always handed whole candidate lines, so kerning between
+/// neighbouring glyphs is counted the same way it is drawn. Words wider than a
+/// whole line (URLs, contract addresses) are hard-broken at cluster
+/// boundaries, so emoji sequences and combining marks never straddle a line
+/// break.
+pub fn wrap_by_width(text: &str, max_width: f32, width_of: impl Fn(&str) -> f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if current_line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", current_line, word)
+        };
+
+        if width_of(&candidate) <= max_width {
+            current_line = candidate;
+            continue;
+        }
+
+        if !current_line.is_empty() {
+            lines.push(std::mem::take(&mut current_line));
+        }
+
+        // Hard break: fill each line cluster by cluster
+        for cluster in graphemes(word) {
+            let kept = current_line.len();
+            current_line.push_str(cluster);
+            if kept > 0 && width_of(&current_line) > max_width {
+                current_line.truncate(kept);
+                lines.push(std::mem::replace(&mut current_line, cluster.to_string()));
+            }
+        }
+    }
+
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combining_marks_stay_with_their_base() {
+        // "e" + combining acute, "n" + combining tilde
+        assert_eq!(
+            graphemes("cafe\u{301} n\u{303}"),
+            vec!["c", "a", "f", "e\u{301}", " ", "n\u{303}"]
+        );
+    }
+
+    #[test]
+    fn emoji_sequences_are_single_clusters() {
+        // Thumbs up + skin tone, family ZWJ sequence, flag pair, heart + VS16
+        let thumbs = "\u{1F44D}\u{1F3FD}";
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let flags = "\u{1F1FA}\u{1F1F8}\u{1F1EF}\u{1F1F5}";
+        let heart = "\u{2764}\u{FE0F}";
+        let text = format!("{}{}{}{}", thumbs, family, flags, heart);
+        assert_eq!(
+            graphemes(&text),
+            vec![
+                thumbs,
+                family,
+                "\u{1F1FA}\u{1F1F8}",
+                "\u{1F1EF}\u{1F1F5}",
+                heart
+            ]
+        );
+    }
+
+    #[test]
+    fn truncate_never_splits_a_cluster() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let title = format!("BTC {} rally e\u{301}te\u{301}", family);
+        // 15 clusters: cut to 8 keeps 5 + "..."
+        assert_eq!(truncate_graphemes(&title, 8), format!("BTC {}...", family));
+        assert_eq!(truncate_graphemes(&title, 15), title);
+        assert_eq!(
+            truncate_graphemes("e\u{301}e\u{301}e\u{301}", 2),
+            "e\u{301}e\u{301}"
+        );
+    }
+}
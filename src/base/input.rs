@@ -1,7 +1,8 @@
 use std::ffi::CString;
+use std::time::{Duration, Instant};
 
 /// Keyboard events for navigation
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum KeyEvent {
     Up,
     Down,
@@ -21,6 +22,7 @@ pub enum KeyEvent {
     Num4,
     Num5,
     Space,
+    Backspace,
     Char(char),
 }
 
@@ -32,15 +34,42 @@ mod keycodes {
     pub const KEY_3: u16 = 4;
     pub const KEY_4: u16 = 5;
     pub const KEY_5: u16 = 6;
+    pub const KEY_6: u16 = 7;
+    pub const KEY_7: u16 = 8;
+    pub const KEY_8: u16 = 9;
+    pub const KEY_9: u16 = 10;
+    pub const KEY_0: u16 = 11;
+    pub const KEY_MINUS: u16 = 12;
+    pub const KEY_EQUAL: u16 = 13;
+    pub const KEY_BACKSPACE: u16 = 14;
     pub const KEY_Q: u16 = 16;
     pub const KEY_W: u16 = 17;
+    pub const KEY_E: u16 = 18;
     pub const KEY_R: u16 = 19;
+    pub const KEY_T: u16 = 20;
+    pub const KEY_Y: u16 = 21;
+    pub const KEY_U: u16 = 22;
+    pub const KEY_I: u16 = 23;
+    pub const KEY_O: u16 = 24;
+    pub const KEY_P: u16 = 25;
+    pub const KEY_LEFTBRACE: u16 = 26;
+    pub const KEY_RIGHTBRACE: u16 = 27;
+    pub const KEY_A: u16 = 30;
+    pub const KEY_S: u16 = 31;
+    pub const KEY_D: u16 = 32;
+    pub const KEY_F: u16 = 33;
+    pub const KEY_G: u16 = 34;
     pub const KEY_H: u16 = 35;
     pub const KEY_J: u16 = 36;
     pub const KEY_K: u16 = 37;
     pub const KEY_L: u16 = 38;
+    pub const KEY_Z: u16 = 44;
+    pub const KEY_X: u16 = 45;
     pub const KEY_C: u16 = 46;
+    pub const KEY_V: u16 = 47;
     pub const KEY_M: u16 = 50;
+    pub const KEY_DOT: u16 = 52;
+    pub const KEY_SLASH: u16 = 53;
     pub const KEY_SPACE: u16 = 57;
     pub const KEY_TAB: u16 = 15;
     pub const KEY_ENTER: u16 = 28;
@@ -54,27 +83,78 @@ mod keycodes {
     pub const KEY_END: u16 = 107;
     pub const KEY_DOWN: u16 = 108;
     pub const KEY_PAGEDOWN: u16 = 109;
+
+    /// Letter keys in code order (rows of a QWERTY keyboard)
+    const LETTER_ROWS: [(u16, &str); 3] = [(16, "qwertyuiop"), (30, "asdfghjkl"), (44, "zxcvbnm")];
+
+    /// Lowercase letter for a key code
+    pub fn letter(code: u16) -> Option<char> {
+        LETTER_ROWS.iter().find_map(|&(first, letters)| {
+            code.checked_sub(first)
+                .and_then(|offset| letters.chars().nth(offset as usize))
+        })
+    }
 }
 
 /// Input event structure from Linux evdev
 #[repr(C)]
-struct InputEvent {
-    tv_sec: libc::time_t,
-    tv_usec: libc::suseconds_t,
-    type_: u16,
-    code: u16,
-    value: i32,
+pub(super) struct InputEvent {
+    pub(super) tv_sec: libc::time_t,
+    pub(super) tv_usec: libc::suseconds_t,
+    pub(super) type_: u16,
+    pub(super) code: u16,
+    pub(super) value: i32,
 }
 
-const EV_KEY: u16 = 1;
+pub(super) const EV_KEY: u16 = 1;
 const KEY_PRESS: i32 = 1;
-// const KEY_RELEASE: i32 = 0;
+const KEY_RELEASE: i32 = 0;
 // const KEY_REPEAT: i32 = 2;
 
+/// Software key repeat timing for held navigation keys
+#[derive(Clone, Copy, Debug)]
+pub struct KeyRepeat {
+    /// Hold time before the first repeat
+    pub delay: Duration,
+    /// Time between repeats after that
+    pub interval: Duration,
+}
+
+/// A held repeatable key and when it next repeats
+struct HeldKey {
+    code: u16,
+    event: KeyEvent,
+    next_repeat: Instant,
+}
+
+impl HeldKey {
+    /// Start holding a key pressed at `now`; it first repeats after the delay
+    fn pressed(code: u16, event: KeyEvent, repeat: KeyRepeat, now: Instant) -> Self {
+        Self {
+            code,
+            event,
+            next_repeat: now + repeat.delay,
+        }
+    }
+
+    /// The repeat due at `now`, if any. At most one per call, so a slow
+    /// frame doesn't burst; a late schedule resumes from `now`
+    fn repeat_due(&mut self, repeat: KeyRepeat, now: Instant) -> Option<KeyEvent> {
+        if now < self.next_repeat {
+            return None;
+        }
+        self.next_repeat = (self.next_repeat + repeat.interval).max(now);
+        Some(self.event)
+    }
+}
+
 /// Keyboard input handler using Linux evdev
 pub struct KeyboardInput {
     device_fd: Option<i32>,
     shift_held: bool,
+    /// Repeat held navigation keys (None = one event per physical press)
+    repeat: Option<KeyRepeat>,
+    held: Option<HeldKey>,
 }
 
 // EVIOCGBIT ioctl to get event bits
@@ -85,11 +165,11 @@ pub struct KeyboardInput {
 
 // EVIOCGBIT for EV (event types)
 // = (2 << 30) | (32 << 16) | (0x45 << 8) | 0x20 = 0x80204520
-const EVIOCGBIT_EV: libc::c_ulong = 0x80204520;
+pub(super) const EVIOCGBIT_EV: libc::c_ulong = 0x80204520;
 
 // EVIOCGBIT for EV_KEY (key capabilities) - 96 bytes for key bitmap
 // = (2 << 30) | (96 << 16) | (0x45 << 8) | 0x21 = 0x80604521
-const EVIOCGBIT_KEY: libc::c_ulong = 0x80604521;
+pub(super) const EVIOCGBIT_KEY: libc::c_ulong = 0x80604521;
 
 // Key codes for detecting real keyboards (vs HDMI CEC, etc)
 const KEY_A: u16 = 30;
@@ -97,7 +177,7 @@ const KEY_Z: u16 = 44;
 const KEY_SPACE: u16 = 57;
 
 /// Check if a bit is set in a byte array
-fn has_key(bits: &[u8], key: u16) -> bool {
+pub(super) fn has_key(bits: &[u8], key: u16) -> bool {
     let byte_idx = (key / 8) as usize;
     let bit_idx = key % 8;
     if byte_idx < bits.len() {
@@ -140,6 +220,8 @@ impl KeyboardInput {
                             return KeyboardInput {
                                 device_fd: Some(fd),
                                 shift_held: false,
+                                repeat: None,
+                                held: None,
                             };
                         } else {
                             println!("Skipping {}: has EV_KEY but not a keyboard", path);
@@ -156,9 +238,18 @@ impl KeyboardInput {
         KeyboardInput {
             device_fd: None,
             shift_held: false,
+            repeat: None,
+            held: None,
         }
     }
 
+    /// Enable software repeat for held navigation keys (arrows, h/j/k/l,
+    /// PgUp/PgDn), timed here rather than by the kernel's autorepeat
+    pub fn set_key_repeat(&mut self, repeat: Option<KeyRepeat>) {
+        self.repeat = repeat;
+        self.held = None;
+    }
+
     /// Poll for keyboard events (non-blocking)
     pub fn poll_events(&mut self) -> Vec<KeyEvent> {
         let mut events = Vec::new();
@@ -189,7 +280,20 @@ impl KeyboardInput {
                 continue;
             }
 
-            // Only handle key press events (not release or repeat)
+            // Releasing the held key stops its software repeat
+            if event.value == KEY_RELEASE {
+                if self
+                    .held
+                    .as_ref()
+                    .is_some_and(|held| held.code == event.code)
+                {
+                    self.held = None;
+                }
+                continue;
+            }
+
+            // Only handle key press events (kernel autorepeat is ignored;
+            // held keys repeat on our own timing below)
             if event.value != KEY_PRESS {
                 continue;
             }
@@ -221,23 +325,52 @@ impl KeyboardInput {
                 keycodes::KEY_3 => Some(KeyEvent::Num3),
                 keycodes::KEY_4 => Some(KeyEvent::Num4),
                 keycodes::KEY_5 => Some(KeyEvent::Num5),
+                keycodes::KEY_6 => Some(KeyEvent::Char('6')),
+                keycodes::KEY_7 => Some(KeyEvent::Char('7')),
+                keycodes::KEY_8 => Some(KeyEvent::Char('8')),
+                keycodes::KEY_9 => Some(KeyEvent::Char('9')),
+                keycodes::KEY_0 => Some(KeyEvent::Char('0')),
+                keycodes::KEY_BACKSPACE => Some(KeyEvent::Backspace),
                 keycodes::KEY_SPACE => Some(KeyEvent::Space),
                 // Character keys
-                keycodes::KEY_Q => Some(KeyEvent::Char('q')),
-                keycodes::KEY_W => Some(KeyEvent::Char('w')),
-                keycodes::KEY_R => Some(KeyEvent::Char('r')),
-                keycodes::KEY_H => Some(KeyEvent::Char('h')),
-                keycodes::KEY_J => Some(KeyEvent::Char('j')),
-                keycodes::KEY_K => Some(KeyEvent::Char('k')),
-                keycodes::KEY_L => Some(KeyEvent::Char('l')),
-                keycodes::KEY_C => Some(KeyEvent::Char('c')),
-                keycodes::KEY_M => Some(KeyEvent::Char('m')),
-                _ => None,
+                keycodes::KEY_SLASH => {
+                    if self.shift_held {
+                        Some(KeyEvent::Char('?'))
+                    } else {
+                        Some(KeyEvent::Char('/'))
+                    }
+                }
+                keycodes::KEY_MINUS => Some(KeyEvent::Char('-')),
+                keycodes::KEY_EQUAL => Some(KeyEvent::Char('=')),
+                keycodes::KEY_DOT => Some(KeyEvent::Char('.')),
+                keycodes::KEY_LEFTBRACE => Some(KeyEvent::Char('[')),
+                keycodes::KEY_RIGHTBRACE => Some(KeyEvent::Char(']')),
+                // Letters, uppercase with shift (any letter can be bound in config.json;
+                // an unbound uppercase letter falls back to the lowercase binding)
+                code => keycodes::letter(code).map(|ch| {
+                    if self.shift_held {
+                        KeyEvent::Char(ch.to_ascii_uppercase())
+                    } else {
+                        KeyEvent::Char(ch)
+                    }
+                }),
             };
 
             if let Some(e) = key_event {
                 events.push(e);
             }
+
+            // A new press replaces the held key (or ends repeating it)
+            self.held = match (self.repeat, key_event) {
+                (Some(repeat), Some(e)) if is_repeatable(e) => {
+                    Some(HeldKey::pressed(event.code, e, repeat, Instant::now()))
+                }
+                _ => None,
+            };
+        }
+
+        if let (Some(repeat), Some(held)) = (self.repeat, self.held.as_mut()) {
+            events.extend(held.repeat_due(repeat, Instant::now()));
         }
 
         events
@@ -249,6 +382,20 @@ impl KeyboardInput {
     }
 }
 
+/// Keys that repeat while held: scrolling and list navigation, never toggles
+fn is_repeatable(event: KeyEvent) -> bool {
+    matches!(
+        event,
+        KeyEvent::Up
+            | KeyEvent::Down
+            | KeyEvent::Left
+            | KeyEvent::Right
+            | KeyEvent::PageUp
+            | KeyEvent::PageDown
+            | KeyEvent::Char('h' | 'j' | 'k' | 'l')
+    )
+}
+
 impl Drop for KeyboardInput {
     fn drop(&mut self) {
         if let Some(fd) = self.device_fd {
@@ -264,3 +411,52 @@ impl Default for KeyboardInput {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPEAT: KeyRepeat = KeyRepeat {
+        delay: Duration::from_millis(300),
+        interval: Duration::from_millis(50),
+    };
+
+    /// Hold a key pressed at 0 ms and poll it at each of the given times
+    fn repeats_at(key: KeyEvent, polls: &[u64]) -> Vec<Option<KeyEvent>> {
+        let start = Instant::now();
+        let mut held = HeldKey::pressed(0, key, REPEAT, start);
+        polls
+            .iter()
+            .map(|&ms| held.repeat_due(REPEAT, start + Duration::from_millis(ms)))
+            .collect()
+    }
+
+    #[test]
+    fn repeats_after_the_delay_then_every_interval() {
+        let down = Some(KeyEvent::Down);
+        assert_eq!(
+            repeats_at(KeyEvent::Down, &[0, 299, 300, 320, 350, 400]),
+            [None, None, down, None, down, down]
+        );
+    }
+
+    #[test]
+    fn slow_frame_yields_one_repeat_not_a_burst() {
+        // A second-long stall covers many intervals but repeats once per poll,
+        // then settles back to one per interval
+        let up = Some(KeyEvent::Up);
+        assert_eq!(
+            repeats_at(KeyEvent::Up, &[1_300, 1_316, 1_332, 1_366]),
+            [up, up, None, up]
+        );
+    }
+
+    #[test]
+    fn only_navigation_keys_repeat() {
+        assert!(is_repeatable(KeyEvent::PageDown));
+        assert!(is_repeatable(KeyEvent::Char('j')));
+        assert!(!is_repeatable(KeyEvent::Enter));
+        assert!(!is_repeatable(KeyEvent::Space));
+        assert!(!is_repeatable(KeyEvent::Char('q')));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::base::layout::style::{Border, Content, HAlign, PanelStyle, VAlign};
+use crate::base::layout::style::{Border, Content, HAlign, PanelStyle, Shadow, VAlign};
 use crate::base::layout::tree::LayoutTree;
+use crate::base::scrollable::{ScrollState, Scrollbar};
 use taffy::prelude::*;
 use taffy::{Overflow, Point as TaffyPoint};
 
@@ -286,6 +287,29 @@ impl PanelBuilder {
         self
     }
 
+    /// Draw a soft drop shadow offset by (`offset_x`, `offset_y`) behind the panel
+    pub fn shadow(mut self, offset_x: f32, offset_y: f32, color: [f32; 4]) -> Self {
+        self.panel_style.shadow = Some(Shadow {
+            offset_x,
+            offset_y,
+            color,
+        });
+        self
+    }
+
+    /// Set a vertical background gradient from `top` to `bottom`
+    pub fn background_gradient(mut self, top: [f32; 4], bottom: [f32; 4]) -> Self {
+        self.panel_style.background_gradient = Some((top, bottom));
+        self
+    }
+
+    /// Round the corners of the background and solid border (clamped to
+    /// half the smaller side when drawn)
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.panel_style.corner_radius = radius.max(0.0);
+        self
+    }
+
     /// Set border
     #[allow(dead_code)]
     pub fn border(mut self, border: Border) -> Self {
@@ -335,6 +359,12 @@ impl PanelBuilder {
         self
     }
 
+    /// Truncate text that overflows the panel width with a trailing "…"
+    pub fn ellipsize(mut self, ellipsize: bool) -> Self {
+        self.panel_style.ellipsize = ellipsize;
+        self
+    }
+
     /// Set text horizontal alignment
     #[allow(dead_code)]
     pub fn text_align_h(mut self, align: HAlign) -> Self {
@@ -378,6 +408,12 @@ impl PanelBuilder {
         self
     }
 
+    /// Set tooltip text (shown near the panel while it is focused)
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.panel_style.tooltip = Some(text.into());
+        self
+    }
+
     /// Make this panel scrollable
     pub fn scrollable(mut self) -> Self {
         self.panel_style.scrollable = true;
@@ -391,6 +427,21 @@ impl PanelBuilder {
         self
     }
 
+    /// Draw a scrollbar for `state` along the right edge
+    pub fn scrollbar(
+        mut self,
+        state: ScrollState,
+        track_color: [f32; 4],
+        thumb_color: [f32; 4],
+    ) -> Self {
+        self.panel_style.scrollbar = Some(Scrollbar {
+            state,
+            track_color,
+            thumb_color,
+        });
+        self
+    }
+
     // === Children ===
 
     /// Add a child panel
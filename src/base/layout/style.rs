@@ -1,3 +1,5 @@
+use crate::base::scrollable::Scrollbar;
+
 /// Horizontal text alignment
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum HAlign {
@@ -64,6 +66,14 @@ impl Border {
     }
 }
 
+/// Drop shadow drawn behind a panel, offset from it
+#[derive(Clone, Copy, Debug)]
+pub struct Shadow {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub color: [f32; 4],
+}
+
 /// Content that can be rendered inside a panel
 #[derive(Clone, Debug, Default)]
 pub enum Content {
@@ -78,7 +88,8 @@ pub enum Content {
         text: String,
         color: [f32; 4],
         scale: f32,
-        scroll_offset: usize,
+        /// First visible line; fractional while a smooth scroll animates
+        scroll_offset: f32,
         line_gap: f32,
         indicator_color: [f32; 4],
         indicator_scale: f32,
@@ -89,9 +100,18 @@ pub enum Content {
 #[derive(Clone, Debug, Default)]
 pub struct PanelStyle {
     pub background_color: Option<[f32; 4]>,
+    /// Drop shadow behind the background (None = no shadow; not drawn in safe mode)
+    pub shadow: Option<Shadow>,
+    /// Vertical (top, bottom) background fade, drawn instead of `background_color`
+    /// (rounded by `corner_radius`; a solid fill in safe mode)
+    pub background_gradient: Option<([f32; 4], [f32; 4])>,
+    /// Rounds the background and solid borders (0 = square corners)
+    pub corner_radius: f32,
     pub border: Border,
     pub content: Content,
     pub clip_overflow: bool,
+    /// Replace the overflowing tail of text lines with "…" at render time
+    pub ellipsize: bool,
     pub text_align_h: HAlign,
     pub text_align_v: VAlign,
     /// Whether this panel can receive focus
@@ -104,6 +124,10 @@ pub struct PanelStyle {
     pub scroll_offset: f32,
     /// Whether this panel is scrollable
     pub scrollable: bool,
+    /// Scrollbar along the right edge (hidden while the content fits)
+    pub scrollbar: Option<Scrollbar>,
+    /// Tooltip text shown in an overlay while the panel is focused
+    pub tooltip: Option<String>,
 }
 
 impl PanelStyle {
@@ -1,5 +1,6 @@
 use crate::base::font_atlas::FontAtlas;
 use crate::base::layout::style::{Content, PanelStyle};
+use crate::base::renderer::rect_renderer::Rect as PixelBounds;
 use taffy::prelude::*;
 
 /// Wrapper around TaffyTree that associates PanelStyle with each node
@@ -163,6 +164,120 @@ impl LayoutTree {
             self.find_panels_recursive(child, abs_x, abs_y, prefix, results);
         }
     }
+
+    /// Find the node with exactly the given panel_id and return its absolute bounds
+    ///
+    /// Positions are resolved the same way as the renderer: parent scroll offsets
+    /// are applied to children, so the returned rect matches what is on screen.
+    pub fn find_panel_by_id(&self, root: NodeId, id: &str) -> Option<PixelBounds> {
+        self.find_panel_by_id_recursive(root, 0.0, 0.0, id)
+    }
+
+    /// Find the node with exactly the given panel_id
+    pub fn find_node_by_id(&self, node: NodeId, id: &str) -> Option<NodeId> {
+        let style = self.get_panel_style(node);
+        if style.and_then(|s| s.panel_id.as_deref()) == Some(id) {
+            return Some(node);
+        }
+
+        self.children(node)
+            .into_iter()
+            .find_map(|child| self.find_node_by_id(child, id))
+    }
+
+    /// Collect the ids and on-screen bounds of all focusable panels in tree
+    /// (navigation) order, for pointer hit-testing
+    pub fn focusable_rects(&self, root: NodeId) -> Vec<(String, PixelBounds)> {
+        let mut rects = Vec::new();
+        self.collect_focusable_rects(root, 0.0, 0.0, &mut rects);
+        rects
+    }
+
+    fn collect_focusable_rects(
+        &self,
+        node: NodeId,
+        parent_x: f32,
+        parent_y: f32,
+        rects: &mut Vec<(String, PixelBounds)>,
+    ) {
+        let layout = self.get_layout(node);
+        let abs_x = parent_x + layout.location.x;
+        let abs_y = parent_y + layout.location.y;
+
+        let style = self.get_panel_style(node);
+        if let Some(style) = style.filter(|s| s.focusable) {
+            if let Some(ref id) = style.panel_id {
+                let bounds = PixelBounds::new(abs_x, abs_y, layout.size.width, layout.size.height);
+                rects.push((id.clone(), bounds));
+            }
+        }
+
+        let scroll_offset = style.map(|s| s.scroll_offset).unwrap_or(0.0);
+        for child in self.children(node) {
+            self.collect_focusable_rects(child, abs_x, abs_y - scroll_offset, rects);
+        }
+    }
+
+    /// Collect the ids of all focusable panels in tree (navigation) order
+    pub fn focusable_ids(&self, root: NodeId) -> Vec<String> {
+        let mut ids = Vec::new();
+        self.collect_focusable_ids(root, &mut ids);
+        ids
+    }
+
+    fn collect_focusable_ids(&self, node: NodeId, ids: &mut Vec<String>) {
+        if let Some(style) = self.get_panel_style(node) {
+            if style.focusable {
+                if let Some(ref id) = style.panel_id {
+                    ids.push(id.clone());
+                }
+            }
+        }
+
+        for child in self.children(node) {
+            self.collect_focusable_ids(child, ids);
+        }
+    }
+
+    /// Find the tooltip text of the panel with the given panel_id
+    pub fn find_tooltip(&self, node: NodeId, id: &str) -> Option<String> {
+        if let Some(style) = self.get_panel_style(node) {
+            if style.panel_id.as_deref() == Some(id) {
+                return style.tooltip.clone();
+            }
+        }
+
+        self.children(node)
+            .into_iter()
+            .find_map(|child| self.find_tooltip(child, id))
+    }
+
+    fn find_panel_by_id_recursive(
+        &self,
+        node: NodeId,
+        parent_x: f32,
+        parent_y: f32,
+        id: &str,
+    ) -> Option<PixelBounds> {
+        let layout = self.get_layout(node);
+        let abs_x = parent_x + layout.location.x;
+        let abs_y = parent_y + layout.location.y;
+
+        let style = self.get_panel_style(node);
+        if style.and_then(|s| s.panel_id.as_deref()) == Some(id) {
+            return Some(PixelBounds::new(
+                abs_x,
+                abs_y,
+                layout.size.width,
+                layout.size.height,
+            ));
+        }
+
+        let scroll_offset = style.map(|s| s.scroll_offset).unwrap_or(0.0);
+        self.children(node).into_iter().find_map(|child| {
+            self.find_panel_by_id_recursive(child, abs_x, abs_y - scroll_offset, id)
+        })
+    }
 }
 
 impl Default for LayoutTree {
@@ -170,3 +285,51 @@ impl Default for LayoutTree {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::layout::panel;
+
+    #[test]
+    fn find_panel_by_id_returns_on_screen_bounds() {
+        let mut tree = LayoutTree::new();
+        let root = panel()
+            .width(length(200.0))
+            .height(length(100.0))
+            .flex_direction(FlexDirection::Column)
+            .padding_all(10.0)
+            .child(panel().marker_id("header").height(length(20.0)))
+            .child(
+                panel()
+                    .marker_id("list")
+                    .height(length(50.0))
+                    .scroll_offset(15.0)
+                    .child(panel().marker_id("row").height(length(30.0))),
+            )
+            .child(
+                panel()
+                    .marker_id("overlay")
+                    .absolute(40.0, 60.0)
+                    .width(length(30.0))
+                    .height(length(10.0)),
+            )
+            .build(&mut tree);
+        tree.compute(root, 200.0, 100.0);
+
+        let header = tree.find_panel_by_id(root, "header").unwrap();
+        assert_eq!(
+            (header.x, header.y, header.width, header.height),
+            (10.0, 10.0, 180.0, 20.0)
+        );
+
+        // Children of a scrolled panel move up by its scroll offset
+        let row = tree.find_panel_by_id(root, "row").unwrap();
+        assert_eq!((row.x, row.y, row.height), (10.0, 15.0, 30.0));
+
+        let overlay = tree.find_panel_by_id(root, "overlay").unwrap();
+        assert_eq!((overlay.x, overlay.y), (40.0, 60.0));
+
+        assert!(tree.find_panel_by_id(root, "head").is_none());
+    }
+}
@@ -3,18 +3,26 @@
 pub mod drm_display;
 pub mod focus;
 pub mod font_atlas;
+pub mod gl_caps;
+pub mod grapheme;
 pub mod input;
 pub mod layout;
+mod png;
+pub mod pointer;
 pub mod renderer;
+pub mod scrollable;
 pub mod text_renderer;
 pub mod view;
 
-pub use drm_display::Display;
+pub use drm_display::{Display, DisplayOptions};
 pub use focus::FocusManager;
 pub use font_atlas::FontAtlas;
-pub use input::{KeyEvent, KeyboardInput};
+pub use input::{KeyEvent, KeyRepeat, KeyboardInput};
 pub use layout::{panel, LayoutTree, PanelBuilder};
-pub use renderer::{render, RectRenderer, ScissorStack};
+pub use pointer::{PointerEvent, PointerInput};
+pub use renderer::{
+    render, render_tooltip, text_box_line_counts, RectRenderer, ScissorStack, Tooltip,
+};
 pub use text_renderer::TextRenderer;
 
 pub use glow;
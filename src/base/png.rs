@@ -0,0 +1,167 @@
+//! Minimal PNG writer for screenshots
+//!
+//! Emits 8-bit RGBA with stored (uncompressed) deflate blocks, so no
+//! compression crate is needed. Files are larger than a real encoder's
+//! output, which is fine for occasional debug captures.
+
+use std::path::Path;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+/// Largest payload of a single stored deflate block
+const MAX_STORED_BLOCK: usize = 65_535;
+
+/// Write top-to-bottom RGBA pixels (`width * height * 4` bytes) as a PNG file
+pub fn write_rgba(path: &Path, width: u32, height: u32, pixels: &[u8]) -> std::io::Result<()> {
+    std::fs::write(path, encode_rgba(width, height, pixels))
+}
+
+fn encode_rgba(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    let mut out = SIGNATURE.to_vec();
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // Bit depth 8, color type 6 (RGBA), default compression/filter, no interlace
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    write_chunk(&mut out, b"IHDR", &header);
+
+    // Each scanline is prefixed with filter type 0 (none)
+    let stride = width as usize * 4;
+    let mut raw = Vec::with_capacity((stride + 1) * height as usize);
+    for row in pixels.chunks_exact(stride).take(height as usize) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+    write_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    write_chunk(&mut out, b"IEND", &[]);
+
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wrap `data` in a zlib stream of stored deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // Empty input still needs one final block
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(is_final as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65_521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_reference_vectors() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+        // Long enough that the sums must be reduced between chunks
+        assert_eq!(adler32(&[0xFF; 100_000]), 0x149A_302C);
+    }
+
+    #[test]
+    fn encodes_a_2x2_image_byte_for_byte() {
+        // Red, green / blue, transparent white
+        let pixels = [
+            255, 0, 0, 255, 0, 255, 0, 255, //
+            0, 0, 255, 255, 255, 255, 255, 0,
+        ];
+        // Reference built with Python's zlib (level 0 also writes stored blocks)
+        let expected: Vec<u8> = [
+            &SIGNATURE[..],
+            // IHDR: 2x2, 8-bit RGBA
+            &[0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52],
+            &[0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02],
+            &[0x08, 0x06, 0x00, 0x00, 0x00],
+            &[0x72, 0xB6, 0x0D, 0x24],
+            // IDAT: zlib header, one final stored block of 18 bytes, adler32
+            &[0x00, 0x00, 0x00, 0x1D, 0x49, 0x44, 0x41, 0x54],
+            &[0x78, 0x01, 0x01, 0x12, 0x00, 0xED, 0xFF],
+            &[0x00, 0xFF, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF],
+            &[0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00],
+            &[0x48, 0xC9, 0x08, 0xF8],
+            &[0x57, 0x15, 0xB0, 0x15],
+            // IEND
+            &[0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44],
+            &[0xAE, 0x42, 0x60, 0x82],
+        ]
+        .concat();
+        assert_eq!(encode_rgba(2, 2, &pixels), expected);
+    }
+
+    #[test]
+    fn splits_long_data_into_stored_blocks() {
+        let data: Vec<u8> = (0..MAX_STORED_BLOCK + 10).map(|i| i as u8).collect();
+        let stream = zlib_stored(&data);
+
+        // Walk the blocks: final flag only on the last, NLEN = !LEN
+        let mut pos = 2;
+        let mut inflated = Vec::new();
+        let mut finals = Vec::new();
+        while inflated.len() < data.len() {
+            finals.push(stream[pos]);
+            let len = u16::from_le_bytes([stream[pos + 1], stream[pos + 2]]);
+            let nlen = u16::from_le_bytes([stream[pos + 3], stream[pos + 4]]);
+            assert_eq!(nlen, !len);
+            pos += 5;
+            inflated.extend_from_slice(&stream[pos..pos + len as usize]);
+            pos += len as usize;
+        }
+        assert_eq!(finals, [0, 1]);
+        assert_eq!(inflated, data);
+        assert_eq!(stream[pos..], adler32(&data).to_be_bytes());
+
+        // Empty input is a single empty final block
+        assert_eq!(zlib_stored(&[])[2..7], [1, 0, 0, 0xFF, 0xFF]);
+    }
+}
@@ -0,0 +1,240 @@
+//! Pointer input (touchscreen or mouse) via Linux evdev
+//!
+//! Touchscreens report absolute axes (EV_ABS), scaled from the device range
+//! to the screen; mice report relative motion (EV_REL), accumulated into a
+//! cursor clamped to the screen. BTN_TOUCH / BTN_LEFT become Down/Up. Events
+//! are emitted on SYN_REPORT so a press always carries the position reported
+//! with it (touchscreens send the button before the coordinates).
+
+use std::ffi::CString;
+
+use super::input::{has_key, InputEvent, EVIOCGBIT_EV, EVIOCGBIT_KEY, EV_KEY};
+
+/// Pointer events in screen pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointerEvent {
+    Move { x: f32, y: f32 },
+    Down { x: f32, y: f32 },
+    Up { x: f32, y: f32 },
+}
+
+const EV_SYN: u16 = 0;
+const EV_REL: u16 = 2;
+const EV_ABS: u16 = 3;
+const SYN_REPORT: u16 = 0;
+const REL_X: u16 = 0;
+const REL_Y: u16 = 1;
+const ABS_X: u16 = 0;
+const ABS_Y: u16 = 1;
+const BTN_LEFT: u16 = 0x110;
+const BTN_TOUCH: u16 = 0x14a;
+
+// EVIOCGBIT for EV_REL / EV_ABS - 8 bytes covers every axis code
+// = (2 << 30) | (8 << 16) | (0x45 << 8) | (0x20 + ev_type)
+const EVIOCGBIT_REL: libc::c_ulong = 0x80084522;
+const EVIOCGBIT_ABS: libc::c_ulong = 0x80084523;
+
+// EVIOCGABS(axis) = _IOR('E', 0x40 + axis, struct input_absinfo), 24 bytes
+// = (2 << 30) | (24 << 16) | (0x45 << 8) | (0x40 + axis)
+const EVIOCGABS_X: libc::c_ulong = 0x80184540;
+const EVIOCGABS_Y: libc::c_ulong = 0x80184541;
+
+/// struct input_absinfo
+#[repr(C)]
+#[derive(Default)]
+struct AbsInfo {
+    value: i32,
+    minimum: i32,
+    maximum: i32,
+    fuzz: i32,
+    flat: i32,
+    resolution: i32,
+}
+
+/// How the device reports position
+enum Axes {
+    /// Touchscreen or tablet: device (min, max) per axis
+    Absolute { x: (i32, i32), y: (i32, i32) },
+    /// Mouse: relative motion
+    Relative,
+}
+
+/// Touchscreen / mouse input handler using Linux evdev
+pub struct PointerInput {
+    device_fd: Option<i32>,
+    axes: Axes,
+    screen_width: f32,
+    screen_height: f32,
+    /// Current position in screen pixels
+    x: f32,
+    y: f32,
+    /// Position changed since the last SYN_REPORT
+    moved: bool,
+    /// Button change since the last SYN_REPORT (true = pressed)
+    button: Option<bool>,
+}
+
+impl PointerInput {
+    pub fn new(screen_width: u32, screen_height: u32) -> Self {
+        let mut input = PointerInput {
+            device_fd: None,
+            axes: Axes::Relative,
+            screen_width: screen_width as f32,
+            screen_height: screen_height as f32,
+            x: screen_width as f32 / 2.0,
+            y: screen_height as f32 / 2.0,
+            moved: false,
+            button: None,
+        };
+
+        for i in 0..10 {
+            let path = format!("/dev/input/event{}", i);
+            let c_path = CString::new(path.clone()).unwrap();
+            let fd = unsafe { libc::open(c_path.as_ptr(), libc::O_RDONLY | libc::O_NONBLOCK) };
+            if fd < 0 {
+                continue;
+            }
+
+            if let Some(axes) = probe_device(fd) {
+                let kind = match axes {
+                    Axes::Absolute { .. } => "touch",
+                    Axes::Relative => "mouse",
+                };
+                println!("Using pointer: {} ({})", path, kind);
+                input.device_fd = Some(fd);
+                input.axes = axes;
+                return input;
+            }
+            unsafe {
+                libc::close(fd);
+            }
+        }
+
+        println!("No touchscreen or mouse found, pointer input disabled");
+        input
+    }
+
+    /// Poll for pointer events (non-blocking)
+    pub fn poll_events(&mut self) -> Vec<PointerEvent> {
+        let mut events = Vec::new();
+
+        let Some(fd) = self.device_fd else {
+            return events;
+        };
+
+        let event_size = std::mem::size_of::<InputEvent>();
+        let mut event: InputEvent = unsafe { std::mem::zeroed() };
+
+        loop {
+            let ret =
+                unsafe { libc::read(fd, &mut event as *mut _ as *mut libc::c_void, event_size) };
+            if ret != event_size as isize {
+                break;
+            }
+
+            match (event.type_, event.code) {
+                (EV_ABS, ABS_X) => {
+                    if let Axes::Absolute { x, .. } = self.axes {
+                        self.x = scale_axis(event.value, x, self.screen_width);
+                        self.moved = true;
+                    }
+                }
+                (EV_ABS, ABS_Y) => {
+                    if let Axes::Absolute { y, .. } = self.axes {
+                        self.y = scale_axis(event.value, y, self.screen_height);
+                        self.moved = true;
+                    }
+                }
+                (EV_REL, REL_X) => {
+                    self.x = (self.x + event.value as f32).clamp(0.0, self.screen_width - 1.0);
+                    self.moved = true;
+                }
+                (EV_REL, REL_Y) => {
+                    self.y = (self.y + event.value as f32).clamp(0.0, self.screen_height - 1.0);
+                    self.moved = true;
+                }
+                (EV_KEY, BTN_LEFT | BTN_TOUCH) if event.value != 2 => {
+                    // 1 = pressed, 0 = released (2 = autorepeat, ignored)
+                    self.button = Some(event.value == 1);
+                }
+                (EV_SYN, SYN_REPORT) => self.flush(&mut events),
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    /// Emit the motion and button change collected since the last report
+    fn flush(&mut self, events: &mut Vec<PointerEvent>) {
+        let (x, y) = (self.x, self.y);
+        if std::mem::take(&mut self.moved) {
+            events.push(PointerEvent::Move { x, y });
+        }
+        match self.button.take() {
+            Some(true) => events.push(PointerEvent::Down { x, y }),
+            Some(false) => events.push(PointerEvent::Up { x, y }),
+            None => {}
+        }
+    }
+}
+
+impl Drop for PointerInput {
+    fn drop(&mut self) {
+        if let Some(fd) = self.device_fd {
+            unsafe {
+                libc::close(fd);
+            }
+        }
+    }
+}
+
+/// Pointer axes of a device with a touch or left button (None if it has neither)
+fn probe_device(fd: i32) -> Option<Axes> {
+    let mut ev_bits = [0u8; 32];
+    if unsafe { libc::ioctl(fd, EVIOCGBIT_EV, ev_bits.as_mut_ptr()) } < 0
+        || !has_key(&ev_bits, EV_KEY)
+    {
+        return None;
+    }
+
+    let mut key_bits = [0u8; 96];
+    if unsafe { libc::ioctl(fd, EVIOCGBIT_KEY, key_bits.as_mut_ptr()) } < 0 {
+        return None;
+    }
+    let touch = has_key(&key_bits, BTN_TOUCH);
+    let left = has_key(&key_bits, BTN_LEFT);
+
+    if has_key(&ev_bits, EV_ABS) && (touch || left) {
+        let mut abs_bits = [0u8; 8];
+        let ret = unsafe { libc::ioctl(fd, EVIOCGBIT_ABS, abs_bits.as_mut_ptr()) };
+        if ret >= 0 && has_key(&abs_bits, ABS_X) && has_key(&abs_bits, ABS_Y) {
+            if let (Some(x), Some(y)) = (abs_range(fd, EVIOCGABS_X), abs_range(fd, EVIOCGABS_Y)) {
+                return Some(Axes::Absolute { x, y });
+            }
+        }
+    }
+
+    if has_key(&ev_bits, EV_REL) && left {
+        let mut rel_bits = [0u8; 8];
+        let ret = unsafe { libc::ioctl(fd, EVIOCGBIT_REL, rel_bits.as_mut_ptr()) };
+        if ret >= 0 && has_key(&rel_bits, REL_X) && has_key(&rel_bits, REL_Y) {
+            return Some(Axes::Relative);
+        }
+    }
+
+    None
+}
+
+/// (min, max) of an absolute axis, None if unreadable or empty
+fn abs_range(fd: i32, request: libc::c_ulong) -> Option<(i32, i32)> {
+    let mut info = AbsInfo::default();
+    let ret = unsafe { libc::ioctl(fd, request, &mut info as *mut AbsInfo) };
+    (ret >= 0 && info.maximum > info.minimum).then_some((info.minimum, info.maximum))
+}
+
+/// Map a device axis value onto 0..screen pixels
+fn scale_axis(value: i32, (min, max): (i32, i32), screen: f32) -> f32 {
+    let t = (value - min) as f32 / (max - min) as f32;
+    (t * screen).clamp(0.0, screen - 1.0)
+}
@@ -2,12 +2,16 @@ use taffy::NodeId;
 
 use crate::base::focus::FocusManager;
 use crate::base::font_atlas::FontAtlas;
+use crate::base::grapheme::{graphemes, wrap_by_width};
 use crate::base::layout::{BorderStyle, Content, HAlign, LayoutTree, VAlign};
-use crate::base::renderer::rect_renderer::{Rect, RectRenderer};
+use crate::base::renderer::rect_renderer::{lerp_color, Rect, RectRenderer};
 use crate::base::renderer::scissor_stack::ScissorStack;
+use crate::base::scrollable::{draw_scrollbar, ScrollState, SCROLLBAR_GAP, SCROLLBAR_WIDTH};
 use crate::base::text_renderer::TextRenderer;
 
 /// Renders the layout tree to the screen
+///
+/// In safe mode panels are drawn with square corners (plain rects and borders).
 pub fn render(
     gl: &glow::Context,
     tree: &LayoutTree,
@@ -17,6 +21,7 @@ pub fn render(
     font_atlas: &FontAtlas,
     scissor_stack: &mut ScissorStack,
     focus_manager: &FocusManager,
+    safe_mode: bool,
     screen_width: u32,
     screen_height: u32,
 ) {
@@ -36,6 +41,7 @@ pub fn render(
         font_atlas,
         scissor_stack,
         focus_manager,
+        safe_mode,
         screen_width,
         screen_height,
     );
@@ -48,6 +54,81 @@ pub fn render(
     scissor_stack.clear(gl);
 }
 
+/// Tooltip overlay anchored to a resolved panel rect
+pub struct Tooltip<'a> {
+    pub text: &'a str,
+    pub anchor: Rect,
+    pub background: [f32; 4],
+    pub border: [f32; 4],
+    pub color: [f32; 4],
+    pub scale: f32,
+}
+
+/// Render a tooltip on top of everything drawn so far
+///
+/// Placed below the anchor panel (above it if there is no room) and
+/// clamped to the screen edges.
+pub fn render_tooltip(
+    gl: &glow::Context,
+    tooltip: &Tooltip,
+    rect_renderer: &mut RectRenderer,
+    text_renderer: &mut TextRenderer,
+    font_atlas: &FontAtlas,
+    screen_width: u32,
+    screen_height: u32,
+) {
+    const PADDING: f32 = 6.0;
+    const OFFSET: f32 = 4.0;
+
+    let (text_width, text_height) =
+        text_renderer.measure_text(font_atlas, tooltip.text, tooltip.scale);
+    let box_width = text_width + PADDING * 2.0;
+    let box_height = text_height + PADDING * 2.0;
+    let (screen_w, screen_h) = (screen_width as f32, screen_height as f32);
+
+    let below = tooltip.anchor.bottom() + OFFSET;
+    let y = if below + box_height <= screen_h {
+        below
+    } else {
+        tooltip.anchor.y - OFFSET - box_height
+    };
+    let x = tooltip.anchor.x.min(screen_w - box_width).max(0.0);
+    let y = y.min(screen_h - box_height).max(0.0);
+
+    let bounds = Rect::new(x, y, box_width, box_height);
+
+    rect_renderer.begin();
+    text_renderer.begin();
+    rect_renderer.draw_rect(&bounds, tooltip.background);
+    rect_renderer.draw_border_solid(&bounds, 1.0, tooltip.border);
+    text_renderer.draw_text(
+        font_atlas,
+        tooltip.text,
+        x + PADDING,
+        y + PADDING + text_height,
+        tooltip.scale,
+        tooltip.color,
+    );
+    rect_renderer.end(gl, screen_width, screen_height);
+    text_renderer.end(gl, font_atlas, screen_width, screen_height);
+}
+
+/// Solid border with rounded corners, or a square one in safe mode
+fn draw_solid_border(
+    rect_renderer: &mut RectRenderer,
+    bounds: &Rect,
+    radius: f32,
+    width: f32,
+    color: [f32; 4],
+    safe_mode: bool,
+) {
+    if safe_mode {
+        rect_renderer.draw_border_solid(bounds, width, color);
+    } else {
+        rect_renderer.draw_rounded_border(bounds, radius, width, color);
+    }
+}
+
 fn render_node(
     gl: &glow::Context,
     tree: &LayoutTree,
@@ -59,6 +140,7 @@ fn render_node(
     font_atlas: &FontAtlas,
     scissor_stack: &mut ScissorStack,
     focus_manager: &FocusManager,
+    safe_mode: bool,
     screen_width: u32,
     screen_height: u32,
 ) {
@@ -74,9 +156,43 @@ fn render_node(
     let bounds = Rect::new(abs_x, abs_y, width, height);
 
     if let Some(style) = panel_style {
-        // 1. Draw background
-        if let Some(bg_color) = style.background_color {
-            rect_renderer.draw_rect(&bounds, bg_color);
+        // 0. Draw the drop shadow: stacked translucent rects, each a little larger,
+        // so the edge fades out (drawn under the parent's scissor like the panel;
+        // skipped in safe mode)
+        if let Some(shadow) = style.shadow.as_ref().filter(|_| !safe_mode) {
+            let layer_alpha = shadow.color[3] / SHADOW_LAYERS as f32;
+            let color = [
+                shadow.color[0],
+                shadow.color[1],
+                shadow.color[2],
+                layer_alpha,
+            ];
+            for layer in 0..SHADOW_LAYERS {
+                let spread = layer as f32 * SHADOW_SPREAD;
+                let rect = Rect::new(
+                    abs_x + shadow.offset_x - spread,
+                    abs_y + shadow.offset_y - spread,
+                    width + spread * 2.0,
+                    height + spread * 2.0,
+                );
+                rect_renderer.draw_rounded_rect(&rect, style.corner_radius + spread, color);
+            }
+        }
+
+        // 1. Draw background (a gradient replaces the solid color; safe mode
+        // fills it with the color halfway down instead)
+        if let Some((top, bottom)) = style.background_gradient {
+            if safe_mode {
+                rect_renderer.draw_rect(&bounds, lerp_color(top, bottom, 0.5));
+            } else {
+                rect_renderer.draw_rounded_rect_gradient(&bounds, style.corner_radius, top, bottom);
+            }
+        } else if let Some(bg_color) = style.background_color {
+            if safe_mode {
+                rect_renderer.draw_rect(&bounds, bg_color);
+            } else {
+                rect_renderer.draw_rounded_rect(&bounds, style.corner_radius, bg_color);
+            }
         }
 
         // 2. Draw border (with focus highlight if focused)
@@ -102,12 +218,38 @@ fn render_node(
             BorderStyle::None => {
                 // If focused but no border, draw focus indicator anyway
                 if is_focused {
-                    rect_renderer.draw_border_solid(&bounds, 2.0, border_color);
+                    draw_solid_border(
+                        rect_renderer,
+                        &bounds,
+                        style.corner_radius,
+                        2.0,
+                        border_color,
+                        safe_mode,
+                    );
                 }
             }
+            // The focus highlight is always solid so it reads the same on every panel
+            _ if is_focused => {
+                draw_solid_border(
+                    rect_renderer,
+                    &bounds,
+                    style.corner_radius,
+                    border_width,
+                    border_color,
+                    safe_mode,
+                );
+            }
             BorderStyle::Solid => {
-                rect_renderer.draw_border_solid(&bounds, border_width, border_color);
+                draw_solid_border(
+                    rect_renderer,
+                    &bounds,
+                    style.corner_radius,
+                    border_width,
+                    border_color,
+                    safe_mode,
+                );
             }
+            // Dashed and dotted borders keep square corners
             BorderStyle::Dashed => {
                 rect_renderer.draw_border_dashed(&bounds, border_width, border_color);
             }
@@ -149,33 +291,46 @@ fn render_node(
             let content_width = width - padding.left - padding.right;
             let content_height = height - padding.top - padding.bottom;
 
-            // Measure text for alignment
-            let (text_width, text_height) = text_renderer.measure_text(font_atlas, text, scale);
+            // Measure the whole block (all lines) and the first line for alignment
+            let (_, block_height) = text_renderer.measure_text(font_atlas, text, scale);
+            let first_line = text.split('\n').next().unwrap_or("");
+            let (_, text_height) = text_renderer.measure_line(font_atlas, first_line, scale);
+            let line_step = line_height_with_atlas(font_atlas, scale);
 
-            // Calculate X position based on horizontal alignment
-            let text_x = match style.text_align_h {
-                HAlign::Left => content_x,
-                HAlign::Center => content_x + (content_width - text_width) / 2.0,
-                HAlign::Right => content_x + content_width - text_width,
-            };
-
-            // Calculate Y position based on vertical alignment
+            // Calculate Y position of the first baseline based on vertical alignment
             // base_y is the baseline position - glyphs extend upward by text_height
             // text_height = ascent (height above baseline)
             // For most text, descent is minimal, so we center based on ascent
             let base_y = match style.text_align_v {
                 VAlign::Top => content_y + text_height,
                 VAlign::Center => {
-                    // Center the visual text in the content area
-                    // baseline = content_center + text_height/2 positions text visually centered
-                    content_y + (content_height / 2.0) + (text_height / 2.0)
+                    // Center the visual block in the content area
+                    // (single line: baseline = content_center + text_height/2)
+                    content_y + (content_height - block_height) / 2.0 + text_height
                 }
-                VAlign::Bottom => content_y + content_height,
+                // Last baseline sits on the bottom edge
+                VAlign::Bottom => content_y + content_height - (block_height - text_height),
             };
 
-            let text_y = base_y - style.scroll_offset;
-
-            text_renderer.draw_text(font_atlas, text, text_x, text_y, scale, color);
+            // Each line is aligned horizontally on its own
+            for (i, line) in text.split('\n').enumerate() {
+                let fitted;
+                let line = if style.ellipsize {
+                    fitted = ellipsize_line(font_atlas, line, content_width, scale);
+                    fitted.as_str()
+                } else {
+                    line
+                };
+                let (line_width, _) = text_renderer.measure_line(font_atlas, line, scale);
+                let text_x = match style.text_align_h {
+                    HAlign::Left => content_x,
+                    HAlign::Center => content_x + (content_width - line_width) / 2.0,
+                    HAlign::Right => content_x + content_width - line_width,
+                };
+                let text_y = base_y + i as f32 * line_step - style.scroll_offset;
+
+                text_renderer.draw_text(font_atlas, line, text_x, text_y, scale, color);
+            }
         } else if let Content::WrappedTextBox {
             ref text,
             color,
@@ -193,34 +348,58 @@ fn render_node(
             let content_width = width - padding.left - padding.right;
             let content_height = height - padding.top - padding.bottom;
 
-            // Wrap text to available width
-            let wrapped = wrap_text_with_atlas(font_atlas, text, content_width, scale);
+            // Wrap text to available width (less the scrollbar if it overflows)
+            let text_box = layout_text_box(
+                font_atlas,
+                text,
+                content_width,
+                content_height,
+                scale,
+                line_gap,
+            );
+            let wrapped = &text_box.wrapped;
+            let visible_lines = text_box.visible_lines;
             let line_height = line_height_with_atlas(font_atlas, scale);
-
-            // Determine how many lines fit
             let line_step = line_height + line_gap;
-            let visible_lines =
-                ((content_height / line_step).floor() as usize).max(1);
 
-            let start = scroll_offset.min(wrapped.lines.len().saturating_sub(1));
+            let offset = scroll_offset.clamp(0.0, text_box.max_scroll() as f32);
+            let start = offset.floor() as usize;
             let end = (start + visible_lines).min(wrapped.lines.len());
+            // Mid-scroll the lines sit part of a line higher, with one more coming in below
+            let shift = (offset - start as f32) * line_step;
+            let drawn = (end + usize::from(shift > 0.0)).min(wrapped.lines.len());
 
             // Render visible lines top-aligned
-            let mut cursor_y = content_y + line_height;
-            for line in wrapped.lines.iter().skip(start).take(end - start) {
+            let mut cursor_y = content_y + line_height - shift;
+            for line in wrapped.lines.iter().skip(start).take(drawn - start) {
                 text_renderer.draw_text(font_atlas, line, content_x, cursor_y, scale, color);
                 cursor_y += line_step;
             }
 
+            // Scrollbar on the right edge (only drawn when the text overflows)
+            let track = Rect::new(
+                content_x + content_width - SCROLLBAR_WIDTH,
+                content_y,
+                SCROLLBAR_WIDTH,
+                content_height,
+            );
+            let mut track_color = indicator_color;
+            track_color[3] *= 0.3;
+            let mut scroll = ScrollState::rows(start, visible_lines, wrapped.lines.len());
+            scroll.scroll_to(offset);
+            draw_scrollbar(rect_renderer, &scroll, &track, track_color, indicator_color);
+
             // Scroll indicator if more content
             if wrapped.lines.len() > end {
                 let remaining = wrapped.lines.len().saturating_sub(end);
                 let indicator = format!("... {} more lines [PgDn]", remaining);
+                // Below the last full line, still while a smooth scroll moves the text
+                let indicator_y = content_y + line_height + (end - start) as f32 * line_step;
                 text_renderer.draw_text(
                     font_atlas,
                     &indicator,
                     content_x,
-                    cursor_y,
+                    indicator_y,
                     indicator_scale,
                     indicator_color,
                 );
@@ -244,6 +423,7 @@ fn render_node(
             font_atlas,
             scissor_stack,
             focus_manager,
+            safe_mode,
             screen_width,
             screen_height,
         );
@@ -260,6 +440,23 @@ fn render_node(
 
             scissor_stack.pop(gl);
         }
+
+        // 7. Scrollbar along the right edge, inside the border
+        if let Some(ref scrollbar) = style.scrollbar {
+            let track = Rect::new(
+                abs_x + width - style.border.width - SCROLLBAR_WIDTH,
+                abs_y + layout.padding.top,
+                SCROLLBAR_WIDTH,
+                height - layout.padding.top - layout.padding.bottom,
+            );
+            draw_scrollbar(
+                rect_renderer,
+                &scrollbar.state,
+                &track,
+                scrollbar.track_color,
+                scrollbar.thumb_color,
+            );
+        }
     }
 }
 
@@ -268,73 +465,143 @@ struct WrappedLines {
     lines: Vec<String>,
 }
 
-fn line_height_with_atlas(atlas: &FontAtlas, scale: f32) -> f32 {
-    atlas.line_height * scale
+/// Translucent layers in a drop shadow, and how much each grows past the last
+const SHADOW_LAYERS: usize = 3;
+const SHADOW_SPREAD: f32 = 1.5;
+
+/// Wrapped lines of a text box and how many of them fit its height
+struct TextBoxLayout {
+    wrapped: WrappedLines,
+    visible_lines: usize,
 }
 
-fn char_advance(atlas: &FontAtlas, ch: char, scale: f32) -> f32 {
-    atlas
-        .get_glyph(ch)
-        .map(|g| g.advance * scale)
-        .unwrap_or(scale * 8.0)
+impl TextBoxLayout {
+    fn overflows(&self) -> bool {
+        self.wrapped.lines.len() > self.visible_lines
+    }
+
+    /// Largest scroll offset that still fills the box
+    fn max_scroll(&self) -> usize {
+        self.wrapped.lines.len().saturating_sub(self.visible_lines)
+    }
 }
 
-fn wrap_text_with_atlas(
+/// Wrap a text box's text to its content area, narrowed to make room for the
+/// scrollbar when the text doesn't fit
+fn layout_text_box(
     atlas: &FontAtlas,
     text: &str,
-    max_width: f32,
+    content_width: f32,
+    content_height: f32,
     scale: f32,
-) -> WrappedLines {
-    let space_width = char_advance(atlas, ' ', scale);
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-    let mut current_width = 0.0f32;
-
-    for word in text.split_whitespace() {
-        let word_width: f32 = word.chars().map(|c| char_advance(atlas, c, scale)).sum();
-        let needed = if current_line.is_empty() {
-            word_width
-        } else {
-            current_width + space_width + word_width
-        };
+    line_gap: f32,
+) -> TextBoxLayout {
+    let line_step = line_height_with_atlas(atlas, scale) + line_gap;
+    let visible_lines = ((content_height / line_step).floor() as usize).max(1);
+
+    let mut layout = TextBoxLayout {
+        wrapped: wrap_text_with_atlas(atlas, text, content_width, scale),
+        visible_lines,
+    };
+    if layout.overflows() {
+        let narrowed = content_width - SCROLLBAR_WIDTH - SCROLLBAR_GAP;
+        layout.wrapped = wrap_text_with_atlas(atlas, text, narrowed, scale);
+    }
+    layout
+}
 
-        if needed <= max_width {
-            if !current_line.is_empty() {
-                current_line.push(' ');
-                current_width += space_width;
-            }
-            current_line.push_str(word);
-            current_width += word_width;
+/// Total wrapped and visible line counts of the text box panel `node`
+/// (None if it isn't one), as laid out by the renderer
+pub fn text_box_line_counts(
+    tree: &LayoutTree,
+    node: NodeId,
+    font_atlas: &FontAtlas,
+) -> Option<(usize, usize)> {
+    let Content::WrappedTextBox {
+        ref text,
+        scale,
+        line_gap,
+        ..
+    } = tree.get_panel_style(node)?.content
+    else {
+        return None;
+    };
+
+    let layout = tree.get_layout(node);
+    let padding = &layout.padding;
+    let text_box = layout_text_box(
+        font_atlas,
+        text,
+        layout.size.width - padding.left - padding.right,
+        layout.size.height - padding.top - padding.bottom,
+        scale,
+        line_gap,
+    );
+    Some((text_box.wrapped.lines.len(), text_box.visible_lines))
+}
+
+fn line_height_with_atlas(atlas: &FontAtlas, scale: f32) -> f32 {
+    atlas.line_height * scale
+}
+
+const ELLIPSIS: &str = "…";
+
+/// Cut `line` to the longest prefix of whole grapheme clusters that fits
+/// `max_width` together with a trailing ellipsis (unchanged if it already fits)
+fn ellipsize_line(atlas: &FontAtlas, line: &str, max_width: f32, scale: f32) -> String {
+    if atlas.measure_text(line, scale).0 <= max_width {
+        return line.to_string();
+    }
+
+    let clusters = graphemes(line);
+    let fits = |count: usize| {
+        let candidate = format!("{}{}", clusters[..count].concat(), ELLIPSIS);
+        atlas.measure_text(&candidate, scale).0 <= max_width
+    };
+
+    // Widest prefix length whose ellipsized form still fits (0 = ellipsis only)
+    let (mut lo, mut hi) = (0, clusters.len());
+    while lo < hi {
+        let mid = (lo + hi).div_ceil(2);
+        if fits(mid) {
+            lo = mid;
         } else {
-            if !current_line.is_empty() {
-                lines.push(current_line);
-            }
-            // If single word is longer than line, hard wrap by char
-            if word_width > max_width {
-                let mut chunk = String::new();
-                let mut chunk_width = 0.0f32;
-                for ch in word.chars() {
-                    let w = char_advance(atlas, ch, scale);
-                    if chunk_width + w > max_width && !chunk.is_empty() {
-                        lines.push(chunk);
-                        chunk = String::new();
-                        chunk_width = 0.0;
-                    }
-                    chunk.push(ch);
-                    chunk_width += w;
-                }
-                current_line = chunk;
-                current_width = chunk_width;
-            } else {
-                current_line = word.to_string();
-                current_width = word_width;
-            }
+            hi = mid - 1;
         }
     }
 
-    if !current_line.is_empty() {
-        lines.push(current_line);
+    if lo == 0 && !fits(0) {
+        return String::new();
     }
+    format!("{}{}", clusters[..lo].concat(), ELLIPSIS)
+}
+
+fn wrap_text_with_atlas(
+    atlas: &FontAtlas,
+    text: &str,
+    max_width: f32,
+    scale: f32,
+) -> WrappedLines {
+    let lines = wrap_by_width(text, max_width, |line| atlas.measure_text(line, scale).0);
 
     WrappedLines { lines }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn wrapping_counts_kerning_like_measure_text() {
+        // "AB" kerns 3px apart: "AB AB" is 56px, past a 50px line
+        let atlas = FontAtlas::for_tests(10.0, HashMap::from([(('A', 'B'), 3.0)]));
+        let wrapped = wrap_text_with_atlas(&atlas, "AB AB", 50.0, 1.0);
+        assert_eq!(wrapped.lines, ["AB", "AB"]);
+
+        // Negative kerning lets "VA VA VA" (72px) fit where advances alone (80px) would not
+        let atlas = FontAtlas::for_tests(10.0, HashMap::from([(('V', 'A'), -4.0)]));
+        let wrapped = wrap_text_with_atlas(&atlas, "VA VA VA", 75.0, 1.0);
+        assert_eq!(wrapped.lines, ["VA VA VA"]);
+    }
+}
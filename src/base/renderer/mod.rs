@@ -2,6 +2,6 @@ pub mod layout_renderer;
 pub mod rect_renderer;
 pub mod scissor_stack;
 
-pub use layout_renderer::render;
+pub use layout_renderer::{render, render_tooltip, text_box_line_counts, Tooltip};
 pub use rect_renderer::RectRenderer;
 pub use scissor_stack::ScissorStack;
@@ -30,6 +30,13 @@ const MAX_QUADS: usize = 10000;
 const MAX_VERTICES: usize = MAX_QUADS * VERTICES_PER_QUAD;
 const MAX_FLOATS: usize = MAX_VERTICES * FLOATS_PER_VERTEX;
 
+// Triangles per rounded corner (a quarter circle)
+const CORNER_SEGMENTS: usize = 6;
+
+// Dashed borders: dash length per unit of border width, and its floor
+const DASH_LENGTH_PER_WIDTH: f32 = 5.0;
+const MIN_DASH_LENGTH: f32 = 10.0;
+
 /// A simple rectangle
 #[derive(Clone, Copy, Debug)]
 pub struct Rect {
@@ -57,6 +64,11 @@ impl Rect {
         self.y + self.height
     }
 
+    /// Whether a point lies inside (left/top edges inclusive)
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
     /// Intersect this rect with another, returning the overlapping region
     pub fn intersect(&self, other: &Rect) -> Option<Rect> {
         let x = self.x.max(other.x);
@@ -87,6 +99,8 @@ pub struct RectRenderer {
     color_loc: u32,
     vertex_data: Vec<f32>,
     vertex_count: usize,
+    /// Multiplier applied to the alpha of every pushed color (view crossfades)
+    alpha: f32,
 }
 
 impl RectRenderer {
@@ -159,9 +173,15 @@ impl RectRenderer {
             color_loc,
             vertex_data: Vec::with_capacity(MAX_FLOATS),
             vertex_count: 0,
+            alpha: 1.0,
         })
     }
 
+    /// Scale the alpha of everything drawn from now on (1.0 = as given)
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha.clamp(0.0, 1.0);
+    }
+
     pub fn begin(&mut self) {
         self.vertex_data.clear();
         self.vertex_count = 0;
@@ -187,6 +207,156 @@ impl RectRenderer {
         self.vertex_count += 6;
     }
 
+    /// Draw a rectangle fading vertically from `top` to `bottom` (colors are
+    /// interpolated between the top and bottom vertices)
+    pub fn draw_rect_gradient(&mut self, rect: &Rect, top: [f32; 4], bottom: [f32; 4]) {
+        let x0 = rect.x;
+        let y0 = rect.y;
+        let x1 = rect.right();
+        let y1 = rect.bottom();
+
+        // Triangle 1
+        self.push_vertex(x0, y0, &top);
+        self.push_vertex(x1, y0, &top);
+        self.push_vertex(x0, y1, &bottom);
+
+        // Triangle 2
+        self.push_vertex(x1, y0, &top);
+        self.push_vertex(x1, y1, &bottom);
+        self.push_vertex(x0, y1, &bottom);
+
+        self.vertex_count += 6;
+    }
+
+    /// Draw a filled rectangle with rounded corners: a center column and two
+    /// side strips, plus a triangle fan per corner. The radius is clamped to
+    /// half the smaller side; 0 draws a plain rectangle.
+    pub fn draw_rounded_rect(&mut self, rect: &Rect, radius: f32, color: [f32; 4]) {
+        let r = clamp_radius(rect, radius);
+        if r <= 0.0 {
+            self.draw_rect(rect, color);
+            return;
+        }
+
+        let side_height = rect.height - 2.0 * r;
+        self.draw_rect(
+            &Rect::new(rect.x + r, rect.y, rect.width - 2.0 * r, rect.height),
+            color,
+        );
+        self.draw_rect(&Rect::new(rect.x, rect.y + r, r, side_height), color);
+        self.draw_rect(
+            &Rect::new(rect.right() - r, rect.y + r, r, side_height),
+            color,
+        );
+
+        for (cx, cy, start) in corner_arcs(rect, r) {
+            for i in 0..CORNER_SEGMENTS {
+                let (x1, y1) = arc_point(cx, cy, r, start, i);
+                let (x2, y2) = arc_point(cx, cy, r, start, i + 1);
+                self.push_vertex(cx, cy, &color);
+                self.push_vertex(x1, y1, &color);
+                self.push_vertex(x2, y2, &color);
+                self.vertex_count += 3;
+            }
+        }
+    }
+
+    /// Draw a rounded rectangle with a vertical gradient from `top` to
+    /// `bottom`; every vertex takes the color at its height. Equal colors
+    /// take the solid path.
+    pub fn draw_rounded_rect_gradient(
+        &mut self,
+        rect: &Rect,
+        radius: f32,
+        top: [f32; 4],
+        bottom: [f32; 4],
+    ) {
+        if top == bottom {
+            self.draw_rounded_rect(rect, radius, top);
+            return;
+        }
+        let r = clamp_radius(rect, radius);
+        if r <= 0.0 {
+            self.draw_rect_gradient(rect, top, bottom);
+            return;
+        }
+
+        let color_at = |y: f32| lerp_color(top, bottom, (y - rect.y) / rect.height);
+        let (side_top, side_bottom) = (color_at(rect.y + r), color_at(rect.bottom() - r));
+        let side_height = rect.height - 2.0 * r;
+        self.draw_rect_gradient(
+            &Rect::new(rect.x + r, rect.y, rect.width - 2.0 * r, rect.height),
+            top,
+            bottom,
+        );
+        self.draw_rect_gradient(
+            &Rect::new(rect.x, rect.y + r, r, side_height),
+            side_top,
+            side_bottom,
+        );
+        self.draw_rect_gradient(
+            &Rect::new(rect.right() - r, rect.y + r, r, side_height),
+            side_top,
+            side_bottom,
+        );
+
+        for (cx, cy, start) in corner_arcs(rect, r) {
+            let center = color_at(cy);
+            for i in 0..CORNER_SEGMENTS {
+                let (x1, y1) = arc_point(cx, cy, r, start, i);
+                let (x2, y2) = arc_point(cx, cy, r, start, i + 1);
+                self.push_vertex(cx, cy, &center);
+                self.push_vertex(x1, y1, &color_at(y1));
+                self.push_vertex(x2, y2, &color_at(y2));
+                self.vertex_count += 3;
+            }
+        }
+    }
+
+    /// Draw a solid border with rounded corners (arcs between the outer
+    /// radius and the radius less the border width); 0 draws a square border
+    pub fn draw_rounded_border(&mut self, rect: &Rect, radius: f32, width: f32, color: [f32; 4]) {
+        let r = clamp_radius(rect, radius);
+        if r <= 0.0 {
+            self.draw_border_solid(rect, width, color);
+            return;
+        }
+
+        // Straight edges between the corners
+        let edge_width = rect.width - 2.0 * r;
+        let edge_height = rect.height - 2.0 * r;
+        self.draw_rect(&Rect::new(rect.x + r, rect.y, edge_width, width), color);
+        self.draw_rect(
+            &Rect::new(rect.x + r, rect.bottom() - width, edge_width, width),
+            color,
+        );
+        self.draw_rect(&Rect::new(rect.x, rect.y + r, width, edge_height), color);
+        self.draw_rect(
+            &Rect::new(rect.right() - width, rect.y + r, width, edge_height),
+            color,
+        );
+
+        let inner = (r - width).max(0.0);
+        for (cx, cy, start) in corner_arcs(rect, r) {
+            for i in 0..CORNER_SEGMENTS {
+                let (ox1, oy1) = arc_point(cx, cy, r, start, i);
+                let (ox2, oy2) = arc_point(cx, cy, r, start, i + 1);
+                let (ix1, iy1) = arc_point(cx, cy, inner, start, i);
+                let (ix2, iy2) = arc_point(cx, cy, inner, start, i + 1);
+
+                self.push_vertex(ox1, oy1, &color);
+                self.push_vertex(ox2, oy2, &color);
+                self.push_vertex(ix1, iy1, &color);
+
+                self.push_vertex(ox2, oy2, &color);
+                self.push_vertex(ix2, iy2, &color);
+                self.push_vertex(ix1, iy1, &color);
+
+                self.vertex_count += 6;
+            }
+        }
+    }
+
     /// Draw a solid border (4 edge rectangles)
     pub fn draw_border_solid(&mut self, rect: &Rect, width: f32, color: [f32; 4]) {
         // Top edge
@@ -213,11 +383,11 @@ impl RectRenderer {
         );
     }
 
-    /// Draw a dashed border
-    #[allow(dead_code)]
+    /// Draw a dashed border; dashes lengthen with the border width so thick
+    /// borders don't turn into a row of squares
     pub fn draw_border_dashed(&mut self, rect: &Rect, width: f32, color: [f32; 4]) {
-        let dash_len = 10.0;
-        let gap_len = 5.0;
+        let dash_len = (width * DASH_LENGTH_PER_WIDTH).max(MIN_DASH_LENGTH);
+        let gap_len = dash_len / 2.0;
 
         // Top edge
         self.draw_dashed_line_h(rect.x, rect.y, rect.width, width, dash_len, gap_len, color);
@@ -246,7 +416,6 @@ impl RectRenderer {
     }
 
     /// Draw a dotted border
-    #[allow(dead_code)]
     pub fn draw_border_dotted(&mut self, rect: &Rect, width: f32, color: [f32; 4]) {
         let dot_size = width;
         let gap = width;
@@ -349,7 +518,7 @@ impl RectRenderer {
         self.vertex_data.push(color[0]);
         self.vertex_data.push(color[1]);
         self.vertex_data.push(color[2]);
-        self.vertex_data.push(color[3]);
+        self.vertex_data.push(color[3] * self.alpha);
     }
 
     pub fn end(&mut self, gl: &glow::Context, screen_width: u32, screen_height: u32) {
@@ -390,6 +559,34 @@ impl RectRenderer {
     }
 }
 
+/// Corner radius that fits the rect (at most half the smaller side)
+fn clamp_radius(rect: &Rect, radius: f32) -> f32 {
+    radius.min(rect.width / 2.0).min(rect.height / 2.0).max(0.0)
+}
+
+/// Blend from `a` (t = 0) to `b` (t = 1)
+pub fn lerp_color(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+/// (center x, center y, start angle) of each corner arc, clockwise from
+/// top-left; y points down, so angles run clockwise on screen
+fn corner_arcs(rect: &Rect, r: f32) -> [(f32, f32, f32); 4] {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    [
+        (rect.x + r, rect.y + r, PI),
+        (rect.right() - r, rect.y + r, PI + FRAC_PI_2),
+        (rect.right() - r, rect.bottom() - r, 0.0),
+        (rect.x + r, rect.bottom() - r, FRAC_PI_2),
+    ]
+}
+
+/// Point `step` of CORNER_SEGMENTS along a quarter arc
+fn arc_point(cx: f32, cy: f32, r: f32, start: f32, step: usize) -> (f32, f32) {
+    let angle = start + std::f32::consts::FRAC_PI_2 * step as f32 / CORNER_SEGMENTS as f32;
+    (cx + angle.cos() * r, cy + angle.sin() * r)
+}
+
 fn ortho_projection(left: f32, right: f32, bottom: f32, top: f32) -> [f32; 16] {
     let near = -1.0f32;
     let far = 1.0f32;
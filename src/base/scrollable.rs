@@ -1,8 +1,32 @@
+use std::time::Duration;
+
+use crate::base::renderer::rect_renderer::{Rect, RectRenderer};
+
+/// Width of a scrollbar track
+pub const SCROLLBAR_WIDTH: f32 = 4.0;
+
+/// Gap kept between scrolled content and its scrollbar
+pub const SCROLLBAR_GAP: f32 = 4.0;
+
+/// Shortest scrollbar thumb, so very long content still shows a visible mark
+const MIN_THUMB_HEIGHT: f32 = 8.0;
+
+/// Distance (in lines) under which a smooth scroll snaps onto its target, so
+/// row- and line-based states settle like pixel ones (0.6 px at the default line height)
+const SNAP_DISTANCE: f32 = 0.02;
+
+/// Frame rate the smoothing fraction is defined against
+const SMOOTHING_FPS: f32 = 60.0;
+
 /// Scroll state for a scrollable panel
 #[derive(Clone, Debug)]
 pub struct ScrollState {
-    /// Current scroll offset (pixels from top)
-    pub offset: f32,
+    /// Rendered scroll offset (pixels from top), eases toward `target` in smooth mode
+    offset: f32,
+    /// Requested scroll offset, always clamped to the content
+    target: f32,
+    /// Fraction of the remaining distance covered per 60 Hz frame (None = instant)
+    smoothing: Option<f32>,
     /// Total height of content
     pub content_height: f32,
     /// Height of the visible viewport
@@ -15,12 +39,75 @@ impl ScrollState {
     pub fn new(viewport_height: f32) -> Self {
         Self {
             offset: 0.0,
+            target: 0.0,
+            smoothing: None,
             content_height: 0.0,
             viewport_height,
             line_height: 30.0, // Default line height
         }
     }
 
+    /// Row-based state for windowed lists: `visible` of `total` rows shown,
+    /// starting at row `offset`
+    pub fn rows(offset: usize, visible: usize, total: usize) -> Self {
+        let mut state = Self::new(visible as f32);
+        state.set_line_height(1.0);
+        state.set_content_height(total as f32);
+        state.scroll_to(offset as f32);
+        state
+    }
+
+    /// Enable smooth scrolling with the given per-frame fraction (0.0-1.0],
+    /// or jump instantly with None (any running animation completes at once)
+    pub fn set_smoothing(&mut self, smoothing: Option<f32>) {
+        self.smoothing = smoothing.map(|fraction| fraction.clamp(0.01, 1.0));
+        if self.smoothing.is_none() {
+            self.offset = self.target;
+        }
+    }
+
+    /// Ease the rendered offset toward the target; call once per frame
+    pub fn update(&mut self, dt: Duration) {
+        let Some(fraction) = self.smoothing else {
+            return;
+        };
+        let remaining = self.target - self.offset;
+        if remaining.abs() < SNAP_DISTANCE * self.line_height {
+            self.offset = self.target;
+            return;
+        }
+        // Frame-rate independent: the same curve at 30 or 144 fps
+        let keep = (1.0 - fraction).powf(dt.as_secs_f32() * SMOOTHING_FPS);
+        self.offset = self.target - remaining * keep;
+    }
+
+    /// Rendered scroll offset (mid-animation in smooth mode)
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Offset the scroll is heading to
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Whether a smooth scroll is still moving
+    pub fn is_animating(&self) -> bool {
+        self.offset != self.target
+    }
+
+    /// Scroll to an offset (clamped); animated in smooth mode
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.target = offset;
+        self.clamp_offset();
+    }
+
+    /// Scroll to an offset (clamped) without animating, e.g. when the content is replaced
+    pub fn jump_to(&mut self, offset: f32) {
+        self.scroll_to(offset);
+        self.offset = self.target;
+    }
+
     /// Set the content height
     pub fn set_content_height(&mut self, height: f32) {
         self.content_height = height;
@@ -41,36 +128,32 @@ impl ScrollState {
 
     /// Scroll up by a number of lines
     pub fn scroll_up(&mut self, lines: f32) {
-        self.offset -= lines * self.line_height;
-        self.clamp_offset();
+        self.scroll_to(self.target - lines * self.line_height);
     }
 
     /// Scroll down by a number of lines
     pub fn scroll_down(&mut self, lines: f32) {
-        self.offset += lines * self.line_height;
-        self.clamp_offset();
+        self.scroll_to(self.target + lines * self.line_height);
     }
 
     /// Scroll up by one page
     pub fn page_up(&mut self) {
-        self.offset -= self.viewport_height * 0.9;
-        self.clamp_offset();
+        self.scroll_to(self.target - self.viewport_height * 0.9);
     }
 
     /// Scroll down by one page
     pub fn page_down(&mut self) {
-        self.offset += self.viewport_height * 0.9;
-        self.clamp_offset();
+        self.scroll_to(self.target + self.viewport_height * 0.9);
     }
 
-    /// Scroll to the top
+    /// Scroll to the top (instant unless smooth mode is on)
     pub fn scroll_to_top(&mut self) {
-        self.offset = 0.0;
+        self.scroll_to(0.0);
     }
 
-    /// Scroll to the bottom
+    /// Scroll to the bottom (instant unless smooth mode is on)
     pub fn scroll_to_bottom(&mut self) {
-        self.offset = self.max_offset();
+        self.scroll_to(self.max_offset());
     }
 
     /// Get the maximum scroll offset
@@ -78,9 +161,13 @@ impl ScrollState {
         (self.content_height - self.viewport_height).max(0.0)
     }
 
-    /// Clamp offset to valid range
+    /// Clamp the target to the valid range; the rendered offset follows it
+    /// immediately unless a smooth scroll is animating toward it
     fn clamp_offset(&mut self) {
-        self.offset = self.offset.clamp(0.0, self.max_offset());
+        self.target = self.target.clamp(0.0, self.max_offset());
+        if self.smoothing.is_none() {
+            self.offset = self.target;
+        }
     }
 
     /// Get the visible range (start, end) in content coordinates
@@ -98,7 +185,8 @@ impl ScrollState {
         if self.max_offset() <= 0.0 {
             0.0
         } else {
-            self.offset / self.max_offset()
+            // A smooth scroll can briefly sit past a shrunken content end
+            (self.offset / self.max_offset()).clamp(0.0, 1.0)
         }
     }
 
@@ -121,6 +209,44 @@ impl Default for ScrollState {
     }
 }
 
+/// Scrollbar drawn along the right edge of a panel
+#[derive(Clone, Debug)]
+pub struct Scrollbar {
+    pub state: ScrollState,
+    pub track_color: [f32; 4],
+    pub thumb_color: [f32; 4],
+}
+
+/// Draw a track filling `rect` and a thumb sized by the visible share of the
+/// content and placed by the scroll progress; nothing when the content fits
+pub fn draw_scrollbar(
+    rect_renderer: &mut RectRenderer,
+    state: &ScrollState,
+    rect: &Rect,
+    track_color: [f32; 4],
+    thumb_color: [f32; 4],
+) {
+    let Some(thumb) = scrollbar_thumb_rect(state, rect) else {
+        return;
+    };
+    rect_renderer.draw_rect(rect, track_color);
+    rect_renderer.draw_rect(&thumb, thumb_color);
+}
+
+/// Thumb of a scrollbar whose track fills `rect` (None when the content fits)
+fn scrollbar_thumb_rect(state: &ScrollState, rect: &Rect) -> Option<Rect> {
+    if !state.can_scroll() || rect.height <= 0.0 {
+        return None;
+    }
+
+    let (_, thumb_size) = state.scrollbar_thumb();
+    let thumb_height = (rect.height * thumb_size)
+        .max(MIN_THUMB_HEIGHT)
+        .min(rect.height);
+    let thumb_y = rect.y + (rect.height - thumb_height) * state.scroll_progress();
+    Some(Rect::new(rect.x, thumb_y, rect.width, thumb_height))
+}
+
 /// Collection of scroll states indexed by panel ID
 pub struct ScrollStates {
     states: std::collections::HashMap<String, ScrollState>,
@@ -152,7 +278,14 @@ impl ScrollStates {
 
     /// Get the scroll offset for a panel (0.0 if not scrollable)
     pub fn offset(&self, id: &str) -> f32 {
-        self.states.get(id).map(|s| s.offset).unwrap_or(0.0)
+        self.states.get(id).map(|s| s.offset()).unwrap_or(0.0)
+    }
+
+    /// Advance every smooth scroll by one frame
+    pub fn update(&mut self, dt: Duration) {
+        for state in self.states.values_mut() {
+            state.update(dt);
+        }
     }
 }
 
@@ -161,3 +294,125 @@ impl Default for ScrollStates {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Instant state showing `viewport` of `content` pixels, scrolled to `offset`
+    fn scrolled(viewport: f32, content: f32, offset: f32) -> ScrollState {
+        let mut state = ScrollState::new(viewport);
+        state.set_content_height(content);
+        state.scroll_to(offset);
+        state
+    }
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    #[test]
+    fn targets_are_clamped_to_the_content() {
+        let mut state = scrolled(100.0, 400.0, -50.0);
+        assert_eq!(state.offset(), 0.0);
+        state.scroll_to(1_000.0);
+        assert_eq!((state.target(), state.offset()), (300.0, 300.0));
+
+        // Shrinking the content pulls the offset back in range
+        state.set_content_height(250.0);
+        assert_eq!(state.offset(), 150.0);
+        state.set_content_height(50.0);
+        assert_eq!(state.offset(), 0.0);
+        assert_eq!(state.scroll_progress(), 0.0);
+    }
+
+    #[test]
+    fn smooth_scroll_converges_on_the_target_and_stops() {
+        let mut state = scrolled(100.0, 1_000.0, 0.0);
+        state.set_smoothing(Some(0.25));
+        state.scroll_down(3.0);
+        assert_eq!((state.offset(), state.target()), (0.0, 90.0));
+
+        let mut previous = 0.0;
+        let mut frames = 0;
+        while state.is_animating() {
+            state.update(FRAME);
+            assert!(state.offset() > previous && state.offset() <= 90.0);
+            previous = state.offset();
+            frames += 1;
+            assert!(frames < 100, "never settled");
+        }
+        assert_eq!(state.offset(), 90.0);
+
+        // Settled: further frames leave it in place
+        state.update(FRAME);
+        assert_eq!(state.offset(), 90.0);
+    }
+
+    #[test]
+    fn smooth_scroll_is_frame_rate_independent() {
+        let mut fast = scrolled(100.0, 1_000.0, 0.0);
+        fast.set_smoothing(Some(0.25));
+        fast.scroll_to(300.0);
+        let mut slow = fast.clone();
+
+        for _ in 0..4 {
+            fast.update(FRAME / 2);
+        }
+        slow.update(FRAME);
+        slow.update(FRAME);
+        assert!((fast.offset() - slow.offset()).abs() < 0.01);
+    }
+
+    #[test]
+    fn smooth_scroll_clamps_mid_animation_and_can_be_cut_short() {
+        let mut state = scrolled(100.0, 1_000.0, 0.0);
+        state.set_smoothing(Some(0.25));
+        state.scroll_to(900.0);
+        state.update(FRAME);
+        assert!(state.offset() > 0.0 && state.offset() < 900.0);
+
+        // The content shrinks under a running scroll: the target clamps at once,
+        // the offset eases toward it and progress never leaves 0..1
+        state.set_content_height(400.0);
+        assert_eq!(state.target(), 300.0);
+        assert!((0.0..=1.0).contains(&state.scroll_progress()));
+
+        // Jumps and switching smoothing off land immediately
+        state.jump_to(0.0);
+        assert_eq!(state.offset(), 0.0);
+        state.scroll_to(200.0);
+        state.set_smoothing(None);
+        assert_eq!(state.offset(), 200.0);
+    }
+
+    #[test]
+    fn thumb_is_sized_by_the_visible_share_and_placed_by_progress() {
+        let track = Rect::new(10.0, 20.0, SCROLLBAR_WIDTH, 200.0);
+
+        let top = scrollbar_thumb_rect(&scrolled(100.0, 400.0, 0.0), &track).unwrap();
+        assert_eq!(
+            (top.x, top.y, top.width, top.height),
+            (10.0, 20.0, 4.0, 50.0)
+        );
+
+        let middle = scrollbar_thumb_rect(&scrolled(100.0, 400.0, 150.0), &track).unwrap();
+        assert_eq!((middle.y, middle.height), (20.0 + 75.0, 50.0));
+
+        // Scrolled past the end clamps to the bottom of the track
+        let bottom = scrollbar_thumb_rect(&scrolled(100.0, 400.0, 1_000.0), &track).unwrap();
+        assert_eq!(bottom.bottom(), track.bottom());
+    }
+
+    #[test]
+    fn thumb_keeps_a_minimum_height_and_hides_when_content_fits() {
+        let track = Rect::new(0.0, 0.0, SCROLLBAR_WIDTH, 100.0);
+
+        let long = scrolled(100.0, 100_000.0, 99_900.0);
+        let thumb = scrollbar_thumb_rect(&long, &track).unwrap();
+        assert_eq!(thumb.height, MIN_THUMB_HEIGHT);
+        assert_eq!(thumb.bottom(), track.bottom());
+
+        assert!(scrollbar_thumb_rect(&scrolled(100.0, 100.0, 0.0), &track).is_none());
+        let flat = Rect::new(0.0, 0.0, SCROLLBAR_WIDTH, 0.0);
+        assert!(scrollbar_thumb_rect(&scrolled(100.0, 400.0, 0.0), &flat).is_none());
+    }
+}
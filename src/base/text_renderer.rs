@@ -32,9 +32,10 @@ const FRAGMENT_SHADER: &str = r#"
 const FLOATS_PER_VERTEX: usize = 8;
 const VERTICES_PER_QUAD: usize = 6;
 
-// Pre-allocate for max text (320 elements * ~50 chars * 6 vertices)
-const MAX_VERTICES: usize = 320 * 50 * VERTICES_PER_QUAD;
-const MAX_FLOATS: usize = MAX_VERTICES * FLOATS_PER_VERTEX;
+// Initial allocation for typical text (320 elements * ~50 chars * 6 vertices);
+// the VBO grows in `end` when a frame needs more
+const INITIAL_VERTICES: usize = 320 * 50 * VERTICES_PER_QUAD;
+const INITIAL_FLOATS: usize = INITIAL_VERTICES * FLOATS_PER_VERTEX;
 
 pub struct TextRenderer {
     program: glow::Program,
@@ -46,6 +47,12 @@ pub struct TextRenderer {
     color_loc: u32,
     vertex_data: Vec<f32>,
     vertex_count: usize,
+    /// Floats the VBO currently has room for
+    vbo_capacity: usize,
+    /// Characters skipped because the font atlas has no glyph for them (debug counter)
+    dropped_glyphs: u64,
+    /// Multiplier applied to the alpha of every pushed color (view crossfades)
+    alpha: f32,
 }
 
 impl TextRenderer {
@@ -101,7 +108,7 @@ impl TextRenderer {
             // Pre-allocate buffer
             gl.buffer_data_size(
                 glow::ARRAY_BUFFER,
-                (MAX_FLOATS * std::mem::size_of::<f32>()) as i32,
+                (INITIAL_FLOATS * std::mem::size_of::<f32>()) as i32,
                 glow::DYNAMIC_DRAW,
             );
             vbo
@@ -122,11 +129,19 @@ impl TextRenderer {
             pos_loc,
             uv_loc,
             color_loc,
-            vertex_data: Vec::with_capacity(MAX_FLOATS),
+            vertex_data: Vec::with_capacity(INITIAL_FLOATS),
             vertex_count: 0,
+            vbo_capacity: INITIAL_FLOATS,
+            dropped_glyphs: 0,
+            alpha: 1.0,
         })
     }
 
+    /// Scale the alpha of everything drawn from now on (1.0 = as given)
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha.clamp(0.0, 1.0);
+    }
+
     pub fn begin(&mut self) {
         self.vertex_data.clear();
         self.vertex_count = 0;
@@ -141,8 +156,15 @@ impl TextRenderer {
         scale: f32,
         color: [f32; 4],
     ) {
+        let mut prev: Option<char> = None;
+
         for c in text.chars() {
             if let Some(glyph) = atlas.get_glyph(c) {
+                if let Some(p) = prev {
+                    x += atlas.kerning(p, c) * scale;
+                }
+                prev = Some(c);
+
                 let x0 = x + glyph.bearing.0 * scale;
                 let y0 = y - (glyph.size.1 + glyph.bearing.1) * scale;
                 let x1 = x0 + glyph.size.0 * scale;
@@ -163,10 +185,17 @@ impl TextRenderer {
 
                 self.vertex_count += 6;
                 x += glyph.advance * scale;
+            } else {
+                self.dropped_glyphs += 1;
             }
         }
     }
 
+    /// Total characters skipped so far because the atlas had no glyph for them
+    pub fn dropped_glyphs(&self) -> u64 {
+        self.dropped_glyphs
+    }
+
     /// Draw text with vertical offset for scrolling
     pub fn draw_text_offset(
         &mut self,
@@ -189,7 +218,7 @@ impl TextRenderer {
         self.vertex_data.push(color[0]);
         self.vertex_data.push(color[1]);
         self.vertex_data.push(color[2]);
-        self.vertex_data.push(color[3]);
+        self.vertex_data.push(color[3] * self.alpha);
     }
 
     pub fn end(
@@ -215,8 +244,22 @@ impl TextRenderer {
             gl.bind_texture(glow::TEXTURE_2D, Some(atlas.texture));
             gl.uniform_1_i32(self.atlas_loc.as_ref(), 0);
 
-            // Upload vertex data
+            // Upload vertex data, growing the VBO first if this batch outgrew it
+            // (uploading past the allocation would fail and drop the whole batch)
             gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vbo));
+            let capacity = grown_capacity(self.vertex_data.len(), self.vbo_capacity);
+            if capacity != self.vbo_capacity {
+                self.vbo_capacity = capacity;
+                gl.buffer_data_size(
+                    glow::ARRAY_BUFFER,
+                    (self.vbo_capacity * std::mem::size_of::<f32>()) as i32,
+                    glow::DYNAMIC_DRAW,
+                );
+                eprintln!(
+                    "Text VBO grown to {} vertices",
+                    self.vbo_capacity / FLOATS_PER_VERTEX
+                );
+            }
             gl.buffer_sub_data_u8_slice(
                 glow::ARRAY_BUFFER,
                 0,
@@ -245,12 +288,32 @@ impl TextRenderer {
         }
     }
 
+    /// Bounding box of (possibly multi-line) text: the widest line, and the first
+    /// line's ascent plus one `line_height` per extra line
     pub fn measure_text(&self, atlas: &FontAtlas, text: &str, scale: f32) -> (f32, f32) {
+        let mut lines = text.split('\n');
+        let (mut width, mut height) = self.measure_line(atlas, lines.next().unwrap_or(""), scale);
+
+        for line in lines {
+            width = width.max(self.measure_line(atlas, line, scale).0);
+            height += atlas.line_height * scale;
+        }
+
+        (width, height)
+    }
+
+    /// Measure a single line of text (ascent above the baseline as height)
+    pub fn measure_line(&self, atlas: &FontAtlas, text: &str, scale: f32) -> (f32, f32) {
         let mut width = 0.0f32;
         let mut height = 0.0f32;
+        let mut prev: Option<char> = None;
 
         for c in text.chars() {
             if let Some(glyph) = atlas.get_glyph(c) {
+                if let Some(p) = prev {
+                    width += atlas.kerning(p, c) * scale;
+                }
+                prev = Some(c);
                 width += glyph.advance * scale;
                 // Include bearing to match draw_text positioning
                 let glyph_height = (glyph.size.1 + glyph.bearing.1) * scale;
@@ -264,6 +327,16 @@ impl TextRenderer {
     }
 }
 
+/// VBO capacity (in floats) able to hold `needed`: unchanged when it fits,
+/// otherwise the next power of two
+fn grown_capacity(needed: usize, capacity: usize) -> usize {
+    if needed > capacity {
+        needed.next_power_of_two()
+    } else {
+        capacity
+    }
+}
+
 fn ortho_projection(left: f32, right: f32, bottom: f32, top: f32) -> [f32; 16] {
     let near = -1.0f32;
     let far = 1.0f32;
@@ -291,3 +364,65 @@ fn ortho_projection(left: f32, right: f32, bottom: f32, top: f32) -> [f32; 16] {
         1.0,
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::num::NonZeroU32;
+
+    /// Renderer with placeholder GL handles; only the CPU-side batching is exercised
+    fn renderer() -> TextRenderer {
+        TextRenderer {
+            program: glow::NativeProgram(NonZeroU32::MIN),
+            vbo: glow::NativeBuffer(NonZeroU32::MIN),
+            projection_loc: None,
+            atlas_loc: None,
+            pos_loc: 0,
+            uv_loc: 1,
+            color_loc: 2,
+            vertex_data: Vec::new(),
+            vertex_count: 0,
+            vbo_capacity: INITIAL_FLOATS,
+            dropped_glyphs: 0,
+            alpha: 1.0,
+        }
+    }
+
+    #[test]
+    fn text_past_the_initial_vbo_is_kept_whole() {
+        let atlas = FontAtlas::for_tests(8.0, HashMap::new());
+        let mut text_renderer = renderer();
+        let chars = INITIAL_VERTICES / VERTICES_PER_QUAD + 500;
+        let text = "x".repeat(chars);
+
+        text_renderer.begin();
+        text_renderer.draw_text(&atlas, &text, 0.0, 20.0, 1.0, [1.0; 4]);
+
+        assert_eq!(text_renderer.vertex_count, chars * VERTICES_PER_QUAD);
+        assert_eq!(
+            text_renderer.vertex_data.len(),
+            text_renderer.vertex_count * FLOATS_PER_VERTEX
+        );
+        // The last glyph sits where the layout put it
+        let last_x = text_renderer.vertex_data[text_renderer.vertex_data.len() - FLOATS_PER_VERTEX];
+        assert_eq!(last_x, (chars - 1) as f32 * 8.0);
+        assert_eq!(text_renderer.dropped_glyphs(), 0);
+
+        // The upload grows the VBO to hold the whole batch
+        let capacity = grown_capacity(text_renderer.vertex_data.len(), INITIAL_FLOATS);
+        assert!(capacity >= text_renderer.vertex_data.len());
+        assert_eq!(grown_capacity(100, INITIAL_FLOATS), INITIAL_FLOATS);
+    }
+
+    #[test]
+    fn glyphs_missing_from_the_atlas_are_counted() {
+        let mut atlas = FontAtlas::for_tests(8.0, HashMap::new());
+        atlas.glyphs.remove(&'?');
+        let mut text_renderer = renderer();
+
+        text_renderer.draw_text(&atlas, "BTC \u{1F680}\u{1F680}", 0.0, 20.0, 1.0, [1.0; 4]);
+        assert_eq!(text_renderer.vertex_count, 4 * VERTICES_PER_QUAD);
+        assert_eq!(text_renderer.dropped_glyphs(), 2);
+    }
+}
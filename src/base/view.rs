@@ -25,6 +25,40 @@ impl ViewSpacing {
     }
 }
 
+/// Size thresholds below which views collapse to a condensed layout.
+#[derive(Clone, Copy, Debug)]
+pub struct Breakpoints {
+    /// Views narrower than this stack columns and drop secondary columns
+    pub narrow_width: f32,
+    /// Views shorter than this hide non-essential panels (indicators, footer)
+    pub short_height: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            narrow_width: 800.0,
+            short_height: 480.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    pub fn resolve(&self, width: f32, height: f32) -> Responsive {
+        Responsive {
+            narrow: width < self.narrow_width,
+            short: height < self.short_height,
+        }
+    }
+}
+
+/// Breakpoints resolved against the current view size.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Responsive {
+    pub narrow: bool,
+    pub short: bool,
+}
+
 /// Precomputed dimensions for a view, derived from the theme and spacing.
 #[derive(Clone, Copy)]
 pub struct ViewMetrics {
@@ -35,8 +69,19 @@ pub struct ViewMetrics {
 }
 
 impl ViewMetrics {
-    pub fn new(width: f32, height: f32, spacing: &ViewSpacing, theme: &GlTheme) -> Self {
-        let header_height = header_height(theme);
+    /// `show_header` = false reclaims the status header and its gap for content.
+    pub fn new(
+        width: f32,
+        height: f32,
+        show_header: bool,
+        spacing: &ViewSpacing,
+        theme: &GlTheme,
+    ) -> Self {
+        let (header_height, header_gap) = if show_header {
+            (header_height(theme), spacing.section_gap)
+        } else {
+            (0.0, 0.0)
+        };
         let footer_height = footer_height(theme);
         let inner_width = inner_width(width, spacing.outer_padding);
 
@@ -44,8 +89,9 @@ impl ViewMetrics {
         let content_height = (height
             - spacing.outer_padding * 2.0
             - header_height
+            - header_gap
             - footer_height
-            - spacing.section_gap * 2.0
+            - spacing.section_gap
             - spacing.footer_margin())
         .max(0.0);
 
@@ -0,0 +1,88 @@
+//! Clipboard copy through external tools (wl-copy, xclip, xsel)
+//!
+//! On the bare DRM console there is no clipboard, so the text is written to
+//! a fallback file instead.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::config::ClipboardContent;
+use crate::mock::CoinData;
+
+/// Clipboard tools tried in order: (command, args, env var that must be set)
+const BACKENDS: [(&str, &[&str], &str); 3] = [
+    ("wl-copy", &[], "WAYLAND_DISPLAY"),
+    ("xclip", &["-selection", "clipboard"], "DISPLAY"),
+    ("xsel", &["--clipboard", "--input"], "DISPLAY"),
+];
+
+/// Where copied text ended up
+pub enum CopyTarget {
+    /// System clipboard via the named tool
+    Clipboard(&'static str),
+    /// Fallback file (no clipboard tool or session available)
+    File(PathBuf),
+}
+
+/// Copy text to the system clipboard, falling back to `fallback_file`
+pub fn copy(text: &str, fallback_file: &str) -> io::Result<CopyTarget> {
+    for (command, args, session_var) in BACKENDS {
+        if std::env::var_os(session_var).is_none() {
+            continue;
+        }
+        if run_backend(command, args, text) {
+            return Ok(CopyTarget::Clipboard(command));
+        }
+    }
+
+    let path = PathBuf::from(fallback_file);
+    fs::write(&path, text)?;
+    Ok(CopyTarget::File(path))
+}
+
+/// Pipe text into a clipboard tool without waiting for it to exit; false if
+/// it is missing, won't take the text or has already failed
+fn run_backend(command: &str, args: &[&str], text: &str) -> bool {
+    let Ok(mut child) = Command::new(command)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return false;
+    };
+
+    // Dropping stdin closes the pipe so the tool can take ownership of the selection
+    let written = child
+        .stdin
+        .take()
+        .map(|mut stdin| stdin.write_all(text.as_bytes()).is_ok())
+        .unwrap_or(false);
+
+    // The tool may stay up serving the selection until another client takes it
+    // (xclip without -loops, a wl-copy that doesn't fork), so the render loop
+    // never waits on it: a tool that already failed counts as a failure, one
+    // still running is reaped on its own thread
+    match child.try_wait() {
+        Ok(Some(status)) => written && status.success(),
+        Ok(None) => {
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+            written
+        }
+        Err(_) => false,
+    }
+}
+
+/// Text copied for a coin, based on the configured content
+pub fn coin_text(coin: &CoinData, content: ClipboardContent) -> String {
+    match content {
+        ClipboardContent::Price => coin.price.to_string(),
+        ClipboardContent::Symbol => coin.symbol.clone(),
+        ClipboardContent::Both => format!("{} {}", coin.symbol, coin.price),
+    }
+}
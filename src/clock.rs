@@ -0,0 +1,16 @@
+//! Wall-clock access
+//!
+//! Time-dependent logic (rule cooldowns, candle countdowns, refresh scheduling)
+//! takes the current time as a `now` parameter instead of reading the clock
+//! itself, so it can be driven with fixed timestamps. Only the top-level
+//! callers read the real clock, through this module.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Current Unix time in seconds (0 if the system clock is before the epoch)
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
@@ -3,9 +3,18 @@ use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::api::binance::FULL_CANDLE_LIMIT;
+use crate::app::{ChartType, TimeWindow, DEFAULT_ZOOM_LEVELS};
+use crate::base::KeyRepeat;
+use crate::connection_log::DEFAULT_MAX_CONNECTION_EVENTS;
+use crate::mock::DEFAULT_MAX_CANDLES;
 use crate::notifications::NotificationRule;
 
+/// Name of the built-in theme (GlTheme::default)
+const DEFAULT_THEME_NAME: &str = "default";
+
 #[derive(Deserialize, Default)]
 pub struct Config {
     #[serde(default)]
@@ -14,8 +23,233 @@ pub struct Config {
     pub api: Option<ApiConfig>,
     #[serde(default)]
     pub pairs: Option<Vec<String>>,
+    /// Display names by coin symbol (e.g. "PEPE": "Pepe"), merged over the built-in names
+    #[serde(default)]
+    pub coin_names: Option<HashMap<String, String>>,
     #[serde(default)]
     pub notifications: Option<NotificationsConfig>,
+    #[serde(default)]
+    pub chart: Option<ChartConfig>,
+    #[serde(default)]
+    pub volume: Option<VolumeConfig>,
+    #[serde(default)]
+    pub diagnostics: Option<DiagnosticsConfig>,
+    #[serde(default)]
+    pub display: Option<DisplayConfig>,
+    #[serde(default)]
+    pub clipboard: Option<ClipboardConfig>,
+    #[serde(default)]
+    pub positions: Option<PositionsConfig>,
+    #[serde(default)]
+    pub news: Option<NewsConfig>,
+    #[serde(default)]
+    pub keyboard: Option<KeyboardConfig>,
+    /// Keys by action name (e.g. "quit": ["q", "Escape"]), replacing that action's defaults
+    #[serde(default)]
+    pub keybindings: Option<HashMap<String, KeyNames>>,
+}
+
+/// Chart data configuration
+#[derive(Deserialize, Clone)]
+pub struct ChartConfig {
+    /// Maximum candles retained in memory per coin; oldest are dropped (default: 1500)
+    #[serde(default = "default_max_candles")]
+    pub max_candles: usize,
+    /// Start with the percentage grid instead of price gridlines (default: false)
+    #[serde(default)]
+    pub percent_grid: bool,
+    /// Percent step between percentage gridlines (default: 2.0)
+    #[serde(default = "default_percent_grid_step")]
+    pub percent_grid_step: f64,
+    /// Refetch the latest candles when a candle closes instead of relying on the stream alone (default: true)
+    #[serde(default = "default_true")]
+    pub close_refresh: bool,
+    /// Number of latest candles fetched on candle close (default: 5)
+    #[serde(default = "default_close_refresh_candles")]
+    pub close_refresh_candles: u32,
+    /// Number of candles fetched for a full history load; capped at 1000 by
+    /// Binance and at one 300-candle page by Coinbase (default: 300)
+    #[serde(default = "default_candle_limit")]
+    pub candle_limit: u32,
+    /// Always show indicators as a single-line strip (default: false, auto on narrow columns)
+    #[serde(default)]
+    pub compact_indicators: bool,
+    /// Show a per-candle trade count sparkline under the price panel (default: false;
+    /// needs Binance REST history, other sources have no trade counts)
+    #[serde(default)]
+    pub trades_sparkline: bool,
+    /// Minimum candle body width in pixels; capped at the slot width so candles never overlap (default: 1.0)
+    #[serde(default = "default_min_body_width")]
+    pub min_body_width: f32,
+    /// Draw candlesticks as a line chart when more than this many candles are visible
+    /// (default: 0 = never). Each candle costs a body and a wick quad, so a line is
+    /// cheaper to draw and easier to read at wide zoom; the price axis also switches to
+    /// closes only, as in the line chart
+    #[serde(default)]
+    pub lod_candles: usize,
+    /// Zoom level presets in visible candles, ascending; the median is used at startup
+    /// (default: [20, 35, 50, 80, 120])
+    #[serde(default)]
+    pub zoom_levels: Option<Vec<usize>>,
+    /// Candle intervals cycled with 'w' (default: 15m, 1h, 4h, 1d)
+    #[serde(default)]
+    pub intervals: Vec<IntervalConfig>,
+    /// Chart type at startup: "candlestick" or "line" (default: "candlestick")
+    #[serde(default)]
+    pub default_type: Option<String>,
+    /// Startup chart type per provider, overriding `default_type`, e.g. {"coinbase": "line"}
+    #[serde(default)]
+    pub provider_types: HashMap<String, String>,
+    /// Bollinger Bands overlay on candlestick charts
+    #[serde(default)]
+    pub bollinger: BollingerConfig,
+}
+
+/// Bollinger Bands overlay settings
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct BollingerConfig {
+    /// Draw the bands (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+    /// SMA period of the middle band (default: 20)
+    #[serde(default = "default_bollinger_period")]
+    pub period: usize,
+    /// Band distance in standard deviations (default: 2.0)
+    #[serde(default = "default_bollinger_std_mult")]
+    pub std_mult: f64,
+}
+
+impl Default for BollingerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period: default_bollinger_period(),
+            std_mult: default_bollinger_std_mult(),
+        }
+    }
+}
+
+fn default_bollinger_period() -> usize {
+    20
+}
+
+fn default_bollinger_std_mult() -> f64 {
+    2.0
+}
+
+/// One selectable candle interval
+#[derive(Deserialize, Clone)]
+pub struct IntervalConfig {
+    /// Binance kline interval, e.g. "5m", "1h", "1w"
+    pub interval: String,
+    /// Display label (default: the interval)
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Expected candle length in seconds; must match the interval if given
+    #[serde(default)]
+    pub granularity_secs: Option<u32>,
+}
+
+impl ChartConfig {
+    /// Validated interval list; invalid entries are skipped with a warning and
+    /// an empty result falls back to the defaults
+    pub fn time_windows(&self) -> Vec<TimeWindow> {
+        let windows: Vec<TimeWindow> = self
+            .intervals
+            .iter()
+            .filter_map(|entry| {
+                TimeWindow::new(
+                    entry.label.as_deref(),
+                    &entry.interval,
+                    entry.granularity_secs,
+                )
+                .map_err(|e| eprintln!("Warning: skipping chart interval: {}", e))
+                .ok()
+            })
+            .collect();
+
+        if windows.is_empty() {
+            TimeWindow::defaults()
+        } else {
+            windows
+        }
+    }
+
+    /// Validated zoom presets; a list that is empty, contains 0 or isn't strictly
+    /// ascending is reported and falls back to the defaults
+    pub fn zoom_levels(&self) -> Vec<usize> {
+        let Some(levels) = self.zoom_levels.as_ref() else {
+            return DEFAULT_ZOOM_LEVELS.to_vec();
+        };
+
+        let valid =
+            !levels.is_empty() && levels[0] > 0 && levels.windows(2).all(|pair| pair[0] < pair[1]);
+        if valid {
+            levels.clone()
+        } else {
+            eprintln!(
+                "Warning: chart.zoom_levels must be non-empty, non-zero and ascending, using {:?}",
+                DEFAULT_ZOOM_LEVELS
+            );
+            DEFAULT_ZOOM_LEVELS.to_vec()
+        }
+    }
+
+    /// Startup chart type for `provider`; an invalid name is reported and
+    /// falls back to candlesticks
+    pub fn chart_type(&self, provider: &str) -> ChartType {
+        let Some(name) = self
+            .provider_types
+            .get(provider)
+            .or(self.default_type.as_ref())
+        else {
+            return ChartType::default();
+        };
+
+        ChartType::from_name(name).unwrap_or_else(|e| {
+            eprintln!("Warning: {}, using candlestick", e);
+            ChartType::default()
+        })
+    }
+}
+
+impl Default for ChartConfig {
+    fn default() -> Self {
+        Self {
+            max_candles: DEFAULT_MAX_CANDLES,
+            percent_grid: false,
+            percent_grid_step: 2.0,
+            close_refresh: true,
+            close_refresh_candles: 5,
+            candle_limit: FULL_CANDLE_LIMIT,
+            compact_indicators: false,
+            trades_sparkline: false,
+            min_body_width: 1.0,
+            lod_candles: 0,
+            zoom_levels: None,
+            intervals: Vec::new(),
+            default_type: None,
+            provider_types: HashMap::new(),
+            bollinger: BollingerConfig::default(),
+        }
+    }
+}
+
+fn default_min_body_width() -> f32 {
+    1.0
+}
+
+fn default_max_candles() -> usize {
+    DEFAULT_MAX_CANDLES
+}
+fn default_percent_grid_step() -> f64 {
+    2.0
+}
+fn default_close_refresh_candles() -> u32 {
+    5
+}
+fn default_candle_limit() -> u32 {
+    FULL_CANDLE_LIMIT
 }
 
 /// Notification system configuration
@@ -25,8 +259,30 @@ pub struct NotificationsConfig {
     pub enabled: bool,
     #[serde(default = "default_true")]
     pub audio_enabled: bool,
+    /// Also show alerts as desktop notifications via `notify-send` (default: false)
+    #[serde(default)]
+    pub desktop_notifications: bool,
+    /// Webhook (Slack/Discord/custom) that receives each alert as a JSON POST (default: unset)
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    /// ALSA device passed to aplay as `-D`, e.g. "plughw:1,0" (default: unset = system default)
+    #[serde(default)]
+    pub audio_device: Option<String>,
+    /// Master volume for generated sounds, 0.0-1.0; adjustable with '['/']' (default: 1.0)
+    #[serde(default = "default_one")]
+    pub volume: f32,
+    /// Gain for notification alert beeps, on top of `volume` (default: 1.0)
+    #[serde(default = "default_one")]
+    pub alert_gain: f32,
+    /// Gain for ticker tones, on top of `volume` (default: 1.0)
+    #[serde(default = "default_one")]
+    pub tone_gain: f32,
     #[serde(default = "default_cooldown")]
     pub cooldown_secs: u64,
+    /// Fold repeats of the same alert for a coin within this many seconds into
+    /// one log entry with a counter (default: 0 = disabled)
+    #[serde(default)]
+    pub coalesce_window_secs: u64,
     #[serde(default = "default_log_file")]
     pub log_file: String,
     #[serde(default = "default_max_entries")]
@@ -35,6 +291,10 @@ pub struct NotificationsConfig {
     pub rules: Vec<NotificationRule>,
     #[serde(default)]
     pub ticker_tones: TickerTonesConfig,
+    #[serde(default)]
+    pub sounds: ConnectionSoundsConfig,
+    #[serde(default)]
+    pub flash_screen: FlashScreenConfig,
 }
 
 impl Default for NotificationsConfig {
@@ -42,11 +302,20 @@ impl Default for NotificationsConfig {
         Self {
             enabled: true,
             audio_enabled: true,
+            desktop_notifications: false,
+            webhook_url: None,
+            audio_device: None,
+            volume: 1.0,
+            alert_gain: 1.0,
+            tone_gain: 1.0,
             cooldown_secs: 60,
+            coalesce_window_secs: 0,
             log_file: "notifications.json".to_string(),
             max_log_entries: 100,
             rules: Vec::new(),
             ticker_tones: TickerTonesConfig::default(),
+            sounds: ConnectionSoundsConfig::default(),
+            flash_screen: FlashScreenConfig::default(),
         }
     }
 }
@@ -85,6 +354,12 @@ pub struct TickerTonesConfig {
     /// Tone duration in milliseconds (default: 50)
     #[serde(default = "default_tone_duration_ms")]
     pub duration_ms: u32,
+    /// Minimum time between tones for the same coin in milliseconds (default: 100)
+    #[serde(default = "default_min_tone_interval_ms")]
+    pub min_tone_interval_ms: u64,
+    /// Pitch mapping for change magnitude: "linear", "pentatonic" or "chromatic" (default: linear)
+    #[serde(default)]
+    pub scale: ToneScale,
 }
 
 impl Default for TickerTonesConfig {
@@ -96,6 +371,8 @@ impl Default for TickerTonesConfig {
             max_freq: 1200.0,
             min_freq: 150.0,
             duration_ms: 50,
+            min_tone_interval_ms: 100,
+            scale: ToneScale::default(),
         }
     }
 }
@@ -116,9 +393,499 @@ fn default_tone_duration_ms() -> u32 {
     50
 }
 
+fn default_min_tone_interval_ms() -> u64 {
+    100
+}
+
+/// Sounds for feed connection changes (files from sounds/)
+#[derive(Deserialize, Clone)]
+pub struct ConnectionSoundsConfig {
+    /// Sound played when the feed connects (default: none)
+    #[serde(default)]
+    pub connect: Option<String>,
+    /// Sound played when the feed drops (default: none)
+    #[serde(default)]
+    pub disconnect: Option<String>,
+    /// Minimum seconds between connection sounds, to ride out reconnect flapping (default: 10)
+    #[serde(default = "default_connection_debounce_secs")]
+    pub debounce_secs: u64,
+}
+
+impl Default for ConnectionSoundsConfig {
+    fn default() -> Self {
+        Self {
+            connect: None,
+            disconnect: None,
+            debounce_secs: 10,
+        }
+    }
+}
+
+fn default_connection_debounce_secs() -> u64 {
+    10
+}
+
+/// How ticker tones map a price move's magnitude onto pitch
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ToneScale {
+    /// Continuous glide between the base and limit frequencies
+    #[default]
+    Linear,
+    /// Major pentatonic degrees (no clashing intervals between rapid tones)
+    Pentatonic,
+    /// Every semitone
+    Chromatic,
+}
+
+/// Rendering style for the overview sparklines
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SparklineStyle {
+    /// Block bars, one per candle
+    #[default]
+    Bars,
+    /// Connected braille line, two candles per glyph
+    Braille,
+    /// Braille dots, one per candle
+    Dots,
+    /// Anti-aliased line drawn by the chart renderer (text contexts use the braille line)
+    Line,
+}
+
+/// General display options
+#[derive(Deserialize, Clone)]
+pub struct DisplayConfig {
+    /// Briefly brighten a coin's price on each ticker update (default: true)
+    #[serde(default = "default_true")]
+    pub price_pulse: bool,
+    /// Pulse fade-out duration in milliseconds (default: 300)
+    #[serde(default = "default_price_pulse_ms")]
+    pub price_pulse_ms: u64,
+    /// Abbreviate the big price in the details view, e.g. "$67.4K" (default: false)
+    #[serde(default)]
+    pub compact_price: bool,
+    /// Thousands separators in the big price display, "$67,432.10" vs "$67432.10" (default: true)
+    #[serde(default = "default_true")]
+    pub group_thousands: bool,
+    /// Price ticks smaller than this percentage keep the neutral color instead of
+    /// flickering red/green, e.g. 0.01 (default: 0.0 = every visible change is colored)
+    #[serde(default)]
+    pub neutral_zone_pct: f64,
+    /// Show the status header above each view; toggle at runtime with 'f' (default: true)
+    #[serde(default = "default_true")]
+    pub show_header: bool,
+    /// Show the FPS / frame time overlay; toggle at runtime with 't' (default: false)
+    #[serde(default)]
+    pub show_fps: bool,
+    /// Overview sparkline style: "bars", "braille", "dots" or "line" (default: "bars")
+    #[serde(default)]
+    pub sparkline: SparklineStyle,
+    /// Below this view width, layouts collapse to a single column (default: 800)
+    #[serde(default = "default_narrow_width")]
+    pub narrow_width: f32,
+    /// Below this view height, non-essential panels are hidden (default: 480)
+    #[serde(default = "default_short_height")]
+    pub short_height: f32,
+    /// Skip optional GL effects (screen flash, price pulse); enabled automatically on software renderers (default: false)
+    #[serde(default)]
+    pub safe_mode: bool,
+    /// MSAA samples per pixel: 0, 2 or 4 (default: 0). Smooths chart lines and candle
+    /// edges, but multiplies fill cost and framebuffer memory; falls back to fewer
+    /// samples when the driver can't provide the requested count
+    #[serde(default)]
+    pub msaa: u8,
+    /// Gamma applied to all theme colors; > 1.0 lifts dark tones, adjustable with '-'/'=' (default: 1.0, clamped to 0.5-2.5)
+    #[serde(default = "default_one")]
+    pub gamma: f32,
+    /// Multiplier for all theme colors (default: 1.0)
+    #[serde(default = "default_one")]
+    pub brightness: f32,
+    /// Price updates applied per frame; the rest wait for the next frame so input stays
+    /// responsive during bursts such as a reconnect replay (default: 200, 0 = unlimited)
+    #[serde(default = "default_max_updates_per_frame")]
+    pub max_updates_per_frame: usize,
+    /// Frame rate cap; the loop sleeps off the rest of each frame instead of relying on
+    /// vsync, which headless and mock runs don't have (default: 0 = uncapped)
+    #[serde(default)]
+    pub max_fps: u32,
+    /// Crossfade between views in milliseconds; both views are drawn while it runs,
+    /// which costs FPS on the Pi; off in safe mode (default: 0 = instant switch)
+    #[serde(default)]
+    pub view_fade_ms: u64,
+    /// Ease the news article and alert log scrolling instead of jumping a line at a
+    /// time (default: false)
+    #[serde(default)]
+    pub smooth_scroll: bool,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            price_pulse: true,
+            price_pulse_ms: 300,
+            compact_price: false,
+            group_thousands: true,
+            neutral_zone_pct: 0.0,
+            show_header: true,
+            show_fps: false,
+            sparkline: SparklineStyle::default(),
+            narrow_width: default_narrow_width(),
+            short_height: default_short_height(),
+            safe_mode: false,
+            msaa: 0,
+            gamma: 1.0,
+            brightness: 1.0,
+            max_updates_per_frame: default_max_updates_per_frame(),
+            max_fps: 0,
+            view_fade_ms: 0,
+            smooth_scroll: false,
+        }
+    }
+}
+
+fn default_price_pulse_ms() -> u64 {
+    300
+}
+
+fn default_max_updates_per_frame() -> usize {
+    200
+}
+
+fn default_one() -> f32 {
+    1.0
+}
+
+fn default_narrow_width() -> f32 {
+    800.0
+}
+
+fn default_short_height() -> f32 {
+    480.0
+}
+
+/// Connection history (Diagnostics view)
+#[derive(Deserialize, Clone)]
+pub struct DiagnosticsConfig {
+    /// Connection events kept in memory (default: 100)
+    #[serde(default = "default_max_connection_events")]
+    pub max_events: usize,
+    /// Append connection events to this JSON-lines file (default: none)
+    #[serde(default)]
+    pub log_file: Option<String>,
+    /// Seconds without a ticker/kline while connected before the header shows
+    /// the connection as stale, 0 = never (default: 30)
+    #[serde(default = "default_stale_after_secs")]
+    pub stale_after_secs: u64,
+}
+
+impl Default for DiagnosticsConfig {
+    fn default() -> Self {
+        Self {
+            max_events: DEFAULT_MAX_CONNECTION_EVENTS,
+            log_file: None,
+            stale_after_secs: 30,
+        }
+    }
+}
+
+fn default_stale_after_secs() -> u64 {
+    30
+}
+
+fn default_max_connection_events() -> usize {
+    DEFAULT_MAX_CONNECTION_EVENTS
+}
+
+/// Positions view settings
+#[derive(Deserialize, Clone)]
+pub struct PositionsConfig {
+    /// Refetch the margin account every time the view is entered (default: true)
+    #[serde(default = "default_true")]
+    pub refresh_on_focus: bool,
+}
+
+impl Default for PositionsConfig {
+    fn default() -> Self {
+        Self {
+            refresh_on_focus: true,
+        }
+    }
+}
+
+/// News view settings
+#[derive(Deserialize, Clone)]
+pub struct NewsConfig {
+    /// Refetch news every time the view is entered (default: false, 'r' refreshes)
+    #[serde(default)]
+    pub refresh_on_focus: bool,
+    /// Refetch news this long after the last fetch (default: 900, 0 = never)
+    #[serde(default = "default_news_refresh_secs")]
+    pub refresh_secs: u64,
+}
+
+fn default_news_refresh_secs() -> u64 {
+    900
+}
+
+impl Default for NewsConfig {
+    fn default() -> Self {
+        Self {
+            refresh_on_focus: false,
+            refresh_secs: default_news_refresh_secs(),
+        }
+    }
+}
+
+/// Keyboard settings
+#[derive(Deserialize, Clone)]
+pub struct KeyboardConfig {
+    /// Repeat held navigation keys: arrows, h/j/k/l, PgUp/PgDn (default: false)
+    #[serde(default)]
+    pub key_repeat: bool,
+    /// Hold time before the first repeat in ms (default: 300)
+    #[serde(default = "default_repeat_delay_ms")]
+    pub repeat_delay_ms: u64,
+    /// Time between repeats in ms (default: 50)
+    #[serde(default = "default_repeat_interval_ms")]
+    pub repeat_interval_ms: u64,
+}
+
+fn default_repeat_delay_ms() -> u64 {
+    300
+}
+
+fn default_repeat_interval_ms() -> u64 {
+    50
+}
+
+impl Default for KeyboardConfig {
+    fn default() -> Self {
+        Self {
+            key_repeat: false,
+            repeat_delay_ms: default_repeat_delay_ms(),
+            repeat_interval_ms: default_repeat_interval_ms(),
+        }
+    }
+}
+
+impl KeyboardConfig {
+    /// Repeat timing when enabled
+    pub fn key_repeat(&self) -> Option<KeyRepeat> {
+        self.key_repeat.then(|| KeyRepeat {
+            delay: Duration::from_millis(self.repeat_delay_ms),
+            interval: Duration::from_millis(self.repeat_interval_ms.max(1)),
+        })
+    }
+}
+
+/// Keys bound to one action: a single key name or a list
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum KeyNames {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// What the copy key ('y') puts on the clipboard
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardContent {
+    /// Current price, e.g. "67412.53"
+    #[default]
+    Price,
+    /// Symbol, e.g. "BTC"
+    Symbol,
+    /// Symbol and price, e.g. "BTC 67412.53"
+    Both,
+}
+
+/// Copy-to-clipboard settings
+#[derive(Deserialize, Clone)]
+pub struct ClipboardConfig {
+    /// Copied content: "price", "symbol" or "both" (default: "price")
+    #[serde(default)]
+    pub content: ClipboardContent,
+    /// File written when no clipboard tool is available, e.g. on the DRM console (default: "clipboard.txt")
+    #[serde(default = "default_clipboard_file")]
+    pub fallback_file: String,
+}
+
+impl Default for ClipboardConfig {
+    fn default() -> Self {
+        Self {
+            content: ClipboardContent::default(),
+            fallback_file: default_clipboard_file(),
+        }
+    }
+}
+
+fn default_clipboard_file() -> String {
+    "clipboard.txt".to_string()
+}
+
+/// Which side of the pair volumes are shown in
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VolumeUnit {
+    /// Quote (USD) and base side by side where there is room, base in the volume pane
+    #[default]
+    Both,
+    /// Quote currency (USD)
+    Quote,
+    /// Base currency (coin-denominated)
+    Base,
+}
+
+/// Volume formatting: units, K/M/B thresholds and precision
+#[derive(Deserialize, Clone, Debug)]
+pub struct VolumeConfig {
+    /// Units shown in the overview, details readout and volume pane (default: both)
+    #[serde(default)]
+    pub unit: VolumeUnit,
+    /// Decimal places of abbreviated volumes (default: per suffix, e.g. $28.4B, $421M, 1.2M, 42K)
+    #[serde(default)]
+    pub decimals: Option<usize>,
+    /// Size of one K; volumes at or above it use the K suffix (default: 1000)
+    #[serde(default = "default_volume_thousand")]
+    pub thousand: f64,
+    /// Size of one M; volumes at or above it use the M suffix (default: 1000000)
+    #[serde(default = "default_volume_million")]
+    pub million: f64,
+    /// Size of one B; volumes at or above it use the B suffix (default: 1000000000)
+    #[serde(default = "default_volume_billion")]
+    pub billion: f64,
+}
+
+impl Default for VolumeConfig {
+    fn default() -> Self {
+        Self {
+            unit: VolumeUnit::Both,
+            decimals: None,
+            thousand: 1_000.0,
+            million: 1_000_000.0,
+            billion: 1_000_000_000.0,
+        }
+    }
+}
+
+fn default_volume_thousand() -> f64 {
+    1_000.0
+}
+fn default_volume_million() -> f64 {
+    1_000_000.0
+}
+fn default_volume_billion() -> f64 {
+    1_000_000_000.0
+}
+
+/// Full-screen flash for critical alerts
+#[derive(Deserialize, Clone)]
+pub struct FlashScreenConfig {
+    /// Flash the whole screen when a critical alert fires (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Fade-out duration of the flash in milliseconds (default: 600)
+    #[serde(default = "default_flash_duration_ms")]
+    pub duration_ms: u64,
+    /// Minimum seconds between flashes, so a volatile burst doesn't strobe (default: 30)
+    #[serde(default = "default_flash_min_interval_secs")]
+    pub min_interval_secs: u64,
+}
+
+impl Default for FlashScreenConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_ms: 600,
+            min_interval_secs: 30,
+        }
+    }
+}
+
+fn default_flash_duration_ms() -> u64 {
+    600
+}
+fn default_flash_min_interval_secs() -> u64 {
+    30
+}
+
 #[derive(Deserialize, Clone)]
 pub struct ApiConfig {
-    pub provider: String,
+    /// "binance", "coinbase", "mock" or "replay", or a list to stream several providers at once
+    pub provider: ProviderNames,
+    /// Reconnect backoff for the Binance stream
+    #[serde(default)]
+    pub reconnect: ReconnectConfig,
+    /// Most REST candle requests started per second; the rest wait their turn (default: 5, 0 = unlimited)
+    #[serde(default = "default_candle_requests_per_sec")]
+    pub candle_requests_per_sec: f64,
+    /// Directory of recorded candle files played back by the "replay" provider (default: "replay")
+    #[serde(default = "default_replay_dir")]
+    pub replay_dir: String,
+    /// Milliseconds between replayed candles (default: 1000)
+    #[serde(default = "default_replay_step_ms")]
+    pub replay_step_ms: u64,
+}
+
+fn default_candle_requests_per_sec() -> f64 {
+    5.0
+}
+
+fn default_replay_dir() -> String {
+    "replay".to_string()
+}
+
+fn default_replay_step_ms() -> u64 {
+    1000
+}
+
+/// Exponential reconnect backoff (delays double from `base_ms` up to `max_ms`, plus jitter)
+#[derive(Deserialize, Clone, Debug)]
+pub struct ReconnectConfig {
+    /// First reconnect delay in milliseconds (default: 1000)
+    #[serde(default = "default_reconnect_base_ms")]
+    pub base_ms: u64,
+    /// Longest reconnect delay in milliseconds (default: 60000)
+    #[serde(default = "default_reconnect_max_ms")]
+    pub max_ms: u64,
+    /// A connection that stays up this many seconds resets the delay to `base_ms` (default: 30)
+    #[serde(default = "default_reconnect_reset_after_secs")]
+    pub reset_after_secs: u64,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            base_ms: 1000,
+            max_ms: 60_000,
+            reset_after_secs: 30,
+        }
+    }
+}
+
+fn default_reconnect_base_ms() -> u64 {
+    1000
+}
+
+fn default_reconnect_max_ms() -> u64 {
+    60_000
+}
+
+fn default_reconnect_reset_after_secs() -> u64 {
+    30
+}
+
+/// `api.provider`: a single name or a list of names
+///
+/// With several live providers each pair is routed by its format: Binance
+/// pairs have no separator ("BTCUSDT"), Coinbase pairs are dash-separated
+/// ("BTC-USD"). A single provider takes every pair as before.
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum ProviderNames {
+    One(String),
+    Many(Vec<String>),
 }
 
 #[derive(Deserialize, Default, Clone)]
@@ -140,23 +907,42 @@ impl ThemeConfig {
     pub fn load_by_name(name: &str) -> Option<Self> {
         let filename = format!("{}.json", name);
 
-        // Try themes directory next to executable
+        Self::theme_dirs()
+            .iter()
+            .find_map(|dir| Self::load_from_path(&dir.join(&filename)))
+    }
+
+    /// Names of all themes available on disk (sorted, deduplicated)
+    pub fn available_themes() -> Vec<String> {
+        let mut names: Vec<String> = Self::theme_dirs()
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "json" {
+                    return None;
+                }
+                Some(path.file_stem()?.to_str()?.to_string())
+            })
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Theme search directories. Search order:
+    /// 1. themes/ next to the executable
+    /// 2. themes/ in the current working directory
+    fn theme_dirs() -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
         if let Ok(exe_path) = env::current_exe() {
             if let Some(exe_dir) = exe_path.parent() {
-                let theme_path = exe_dir.join("themes").join(&filename);
-                if let Some(config) = Self::load_from_path(&theme_path) {
-                    return Some(config);
-                }
+                dirs.push(exe_dir.join("themes"));
             }
         }
-
-        // Try themes directory in current working directory
-        let cwd_path = PathBuf::from("themes").join(&filename);
-        if let Some(config) = Self::load_from_path(&cwd_path) {
-            return Some(config);
-        }
-
-        None
+        dirs.push(PathBuf::from("themes"));
+        dirs
     }
 
     fn load_from_path(path: &PathBuf) -> Option<Self> {
@@ -186,7 +972,27 @@ struct RawConfig {
     #[serde(default)]
     pairs: Option<Vec<String>>,
     #[serde(default)]
+    coin_names: Option<HashMap<String, String>>,
+    #[serde(default)]
     notifications: Option<NotificationsConfig>,
+    #[serde(default)]
+    chart: Option<ChartConfig>,
+    #[serde(default)]
+    volume: Option<VolumeConfig>,
+    #[serde(default)]
+    diagnostics: Option<DiagnosticsConfig>,
+    #[serde(default)]
+    display: Option<DisplayConfig>,
+    #[serde(default)]
+    clipboard: Option<ClipboardConfig>,
+    #[serde(default)]
+    positions: Option<PositionsConfig>,
+    #[serde(default)]
+    news: Option<NewsConfig>,
+    #[serde(default)]
+    keyboard: Option<KeyboardConfig>,
+    #[serde(default)]
+    keybindings: Option<HashMap<String, KeyNames>>,
 }
 
 impl Config {
@@ -214,32 +1020,80 @@ impl Config {
     }
 
     pub fn load(filename: &str) -> Self {
-        let path = match Self::find_config_path(filename) {
-            Some(p) => p,
-            None => return Self::default(),
-        };
+        Self::try_load(filename).unwrap_or_default()
+    }
 
-        let content = match fs::read_to_string(&path) {
-            Ok(c) => c,
-            Err(_) => return Self::default(),
-        };
+    /// Like `load`, but reports why the file could not be used instead of
+    /// falling back to defaults (for reloading a running dashboard)
+    pub fn try_load(filename: &str) -> Result<Self, String> {
+        let path =
+            Self::find_config_path(filename).ok_or_else(|| format!("{} not found", filename))?;
+
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        let raw = serde_json::from_str::<RawConfig>(&content)
+            .map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
 
-        match serde_json::from_str::<RawConfig>(&content) {
-            Ok(raw) => Self {
-                theme: raw.theme,
-                api: raw.api,
-                pairs: raw.pairs,
-                notifications: raw.notifications,
-            },
-            Err(_) => Self::default(),
+        Ok(Self {
+            theme: raw.theme,
+            api: raw.api,
+            pairs: raw.pairs,
+            coin_names: raw.coin_names,
+            notifications: raw.notifications,
+            chart: raw.chart,
+            volume: raw.volume,
+            diagnostics: raw.diagnostics,
+            display: raw.display,
+            clipboard: raw.clipboard,
+            positions: raw.positions,
+            news: raw.news,
+            keyboard: raw.keyboard,
+            keybindings: raw.keybindings,
+        })
+    }
+
+    /// Configured provider names, in config order ("mock" when unset or empty)
+    pub fn provider(&self) -> Vec<&str> {
+        match self.api.as_ref().map(|a| &a.provider) {
+            Some(ProviderNames::One(name)) => vec![name.as_str()],
+            Some(ProviderNames::Many(names)) if !names.is_empty() => {
+                names.iter().map(String::as_str).collect()
+            }
+            _ => vec!["mock"],
         }
     }
 
-    pub fn provider(&self) -> &str {
+    /// REST candle request rate limit (requests per second, 0 = unlimited)
+    pub fn candle_requests_per_sec(&self) -> f64 {
         self.api
             .as_ref()
-            .map(|a| a.provider.as_str())
-            .unwrap_or("mock")
+            .map(|a| a.candle_requests_per_sec)
+            .unwrap_or_else(default_candle_requests_per_sec)
+    }
+
+    /// Recorded candle directory for the "replay" provider
+    pub fn replay_dir(&self) -> String {
+        self.api
+            .as_ref()
+            .map(|a| a.replay_dir.clone())
+            .unwrap_or_else(default_replay_dir)
+    }
+
+    /// Milliseconds between replayed candles
+    pub fn replay_step_ms(&self) -> u64 {
+        self.api
+            .as_ref()
+            .map(|a| a.replay_step_ms)
+            .unwrap_or_else(default_replay_step_ms)
+    }
+
+    /// Reconnect backoff settings for the streaming provider
+    pub fn reconnect_config(&self) -> ReconnectConfig {
+        self.api
+            .as_ref()
+            .map(|a| a.reconnect.clone())
+            .unwrap_or_default()
     }
 
     pub fn pairs(&self) -> Vec<String> {
@@ -252,11 +1106,30 @@ impl Config {
         })
     }
 
-    /// Load theme configuration by name, returns None if not found
+    /// Display name overrides by coin symbol
+    pub fn coin_names(&self) -> HashMap<String, String> {
+        self.coin_names.clone().unwrap_or_default()
+    }
+
+    /// Load theme configuration by name, returns None if not set, "default", or not found.
+    /// An unknown name logs a warning listing the available themes.
     pub fn theme_config(&self) -> Option<ThemeConfig> {
-        self.theme
-            .as_ref()
-            .and_then(|name| ThemeConfig::load_by_name(name))
+        let name = self.theme.as_deref()?;
+        if name.is_empty() || name == DEFAULT_THEME_NAME {
+            return None;
+        }
+
+        let theme = ThemeConfig::load_by_name(name);
+        if theme.is_none() {
+            let mut available = vec![DEFAULT_THEME_NAME.to_string()];
+            available.extend(ThemeConfig::available_themes());
+            eprintln!(
+                "Warning: theme '{}' not found, using default. Available themes: {}",
+                name,
+                available.join(", ")
+            );
+        }
+        theme
     }
 
     /// Get notifications config or default
@@ -280,6 +1153,22 @@ impl Config {
             .unwrap_or(false)
     }
 
+    /// Check if desktop (notify-send) notifications are enabled
+    pub fn desktop_notifications_enabled(&self) -> bool {
+        self.notifications
+            .as_ref()
+            .map(|n| n.desktop_notifications)
+            .unwrap_or(false)
+    }
+
+    /// Get the webhook URL alerts are forwarded to, if configured
+    pub fn webhook_url(&self) -> Option<String> {
+        self.notifications
+            .as_ref()
+            .and_then(|n| n.webhook_url.clone())
+            .filter(|url| !url.is_empty())
+    }
+
     /// Get the notification log file path
     pub fn log_file(&self) -> String {
         self.notifications
@@ -295,4 +1184,138 @@ impl Config {
             .map(|n| n.ticker_tones.clone())
             .unwrap_or_default()
     }
+
+    /// Get connection sounds config
+    pub fn connection_sounds_config(&self) -> ConnectionSoundsConfig {
+        self.notifications
+            .as_ref()
+            .map(|n| n.sounds.clone())
+            .unwrap_or_default()
+    }
+
+    /// Get critical-alert screen flash config
+    pub fn flash_screen_config(&self) -> FlashScreenConfig {
+        self.notifications
+            .as_ref()
+            .map(|n| n.flash_screen.clone())
+            .unwrap_or_default()
+    }
+
+    /// Get chart config or default
+    pub fn chart_config(&self) -> ChartConfig {
+        self.chart.clone().unwrap_or_default()
+    }
+
+    /// Get volume display config or default
+    pub fn volume_config(&self) -> VolumeConfig {
+        self.volume.clone().unwrap_or_default()
+    }
+
+    /// Get display config or default
+    pub fn display_config(&self) -> DisplayConfig {
+        self.display.clone().unwrap_or_default()
+    }
+
+    /// Frame rate cap, None when uncapped (`display.max_fps` unset or 0)
+    pub fn max_fps(&self) -> Option<u32> {
+        Some(self.display_config().max_fps).filter(|&fps| fps > 0)
+    }
+
+    /// Get keyboard config or default
+    pub fn keyboard_config(&self) -> KeyboardConfig {
+        self.keyboard.clone().unwrap_or_default()
+    }
+
+    /// Configured key names by action name (empty when unset)
+    pub fn keybindings(&self) -> HashMap<String, Vec<String>> {
+        self.keybindings
+            .iter()
+            .flatten()
+            .map(|(action, keys)| {
+                let keys = match keys {
+                    KeyNames::One(key) => vec![key.clone()],
+                    KeyNames::Many(keys) => keys.clone(),
+                };
+                (action.clone(), keys)
+            })
+            .collect()
+    }
+
+    /// Get positions config or default
+    pub fn positions_config(&self) -> PositionsConfig {
+        self.positions.clone().unwrap_or_default()
+    }
+
+    /// Get news config or default
+    pub fn news_config(&self) -> NewsConfig {
+        self.news.clone().unwrap_or_default()
+    }
+
+    /// Get clipboard config or default
+    pub fn clipboard_config(&self) -> ClipboardConfig {
+        self.clipboard.clone().unwrap_or_default()
+    }
+
+    /// Get diagnostics config or default
+    pub fn diagnostics_config(&self) -> DiagnosticsConfig {
+        self.diagnostics.clone().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_theme(name: &str) -> Config {
+        Config {
+            theme: Some(name.to_string()),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn configured_chart_type_applies_and_still_cycles() {
+        let chart = ChartConfig {
+            default_type: Some("line".to_string()),
+            provider_types: HashMap::from([("binance".to_string(), "candle".to_string())]),
+            ..ChartConfig::default()
+        };
+        assert_eq!(chart.chart_type("binance"), ChartType::Candlestick);
+
+        let mut app = crate::app::App::new(Vec::new(), "coinbase");
+        app.chart_type = chart.chart_type("coinbase");
+        assert_eq!(app.chart_type, ChartType::Polygonal);
+        app.cycle_chart_type();
+        assert_eq!(app.chart_type, ChartType::Candlestick);
+        app.cycle_chart_type();
+        assert_eq!(app.chart_type, ChartType::Polygonal);
+    }
+
+    #[test]
+    fn invalid_or_missing_chart_type_falls_back_to_candlestick() {
+        let invalid = ChartConfig {
+            default_type: Some("renko".to_string()),
+            ..ChartConfig::default()
+        };
+        assert_eq!(invalid.chart_type("binance"), ChartType::Candlestick);
+        assert_eq!(
+            ChartConfig::default().chart_type("binance"),
+            ChartType::Candlestick
+        );
+    }
+
+    #[test]
+    fn named_theme_loads_from_themes_dir() {
+        let theme = with_theme("nord").theme_config();
+        assert!(theme.is_some());
+        assert!(ThemeConfig::available_themes().contains(&"nord".to_string()));
+    }
+
+    #[test]
+    fn unknown_or_default_theme_falls_back_to_default() {
+        assert!(with_theme("nonexistent").theme_config().is_none());
+        assert!(with_theme(DEFAULT_THEME_NAME).theme_config().is_none());
+        assert!(with_theme("").theme_config().is_none());
+        assert!(Config::default().theme_config().is_none());
+    }
 }
@@ -0,0 +1,112 @@
+//! Connection history - bounded log of feed connection events for diagnostics
+//!
+//! Events can optionally be appended to a JSON-lines file so flaky overnight
+//! runs can be inspected after the fact.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default number of connection events kept in memory
+pub const DEFAULT_MAX_CONNECTION_EVENTS: usize = 100;
+
+/// Kind of connection event
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionEventKind {
+    Connected,
+    Disconnected,
+    Reconnecting,
+    Error,
+}
+
+impl ConnectionEventKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectionEventKind::Connected => "Connected",
+            ConnectionEventKind::Disconnected => "Disconnected",
+            ConnectionEventKind::Reconnecting => "Reconnecting",
+            ConnectionEventKind::Error => "Error",
+        }
+    }
+}
+
+/// A single connection event
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionEvent {
+    pub timestamp: u64,
+    pub kind: ConnectionEventKind,
+    pub detail: String,
+}
+
+impl ConnectionEvent {
+    /// Format timestamp as HH:MM:SS
+    pub fn time_str(&self) -> String {
+        let secs = self.timestamp % 86400; // seconds in day
+        let hours = (secs / 3600) % 24;
+        let minutes = (secs % 3600) / 60;
+        let seconds = secs % 60;
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
+
+/// Bounded history of connection events (oldest dropped first)
+pub struct ConnectionLog {
+    events: VecDeque<ConnectionEvent>,
+    max_events: usize,
+    log_file: Option<String>,
+}
+
+impl ConnectionLog {
+    /// Create a log keeping at most `max_events`, optionally persisted to `log_file`
+    pub fn new(max_events: usize, log_file: Option<String>) -> Self {
+        Self {
+            events: VecDeque::new(),
+            max_events: max_events.max(1),
+            log_file,
+        }
+    }
+
+    /// Record an event with the current timestamp
+    pub fn push(&mut self, kind: ConnectionEventKind, detail: &str) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let event = ConnectionEvent {
+            timestamp,
+            kind,
+            detail: detail.to_string(),
+        };
+
+        if let Some(path) = &self.log_file {
+            if let Err(e) = append_event(path, &event) {
+                eprintln!("Failed to write connection log: {}", e);
+            }
+        }
+
+        self.events.push_back(event);
+        while self.events.len() > self.max_events {
+            self.events.pop_front();
+        }
+    }
+
+    /// Events ordered newest first
+    pub fn newest_first(&self) -> impl Iterator<Item = &ConnectionEvent> {
+        self.events.iter().rev()
+    }
+
+    /// Number of events currently retained
+    pub fn count(&self) -> usize {
+        self.events.len()
+    }
+}
+
+/// Append one event as a JSON line
+fn append_event(path: &str, event: &ConnectionEvent) -> std::io::Result<()> {
+    let line = serde_json::to_string(event)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
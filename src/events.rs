@@ -1,7 +1,8 @@
 //! Keyboard event handling for OpenGL dashboard
 
-use crate::app::{App, View};
-use crate::base::{KeyEvent, KeyboardInput};
+use crate::app::{App, Modal, View, GAMMA_STEP, VOLUME_STEP};
+use crate::base::renderer::rect_renderer::Rect;
+use crate::base::{FocusManager, KeyEvent, KeyboardInput, PointerEvent, PointerInput};
 
 /// Key event types we care about
 pub enum AppEvent {
@@ -12,22 +13,64 @@ pub enum AppEvent {
     MoveRight,
     ZoomIn,
     ZoomOut,
+    FitCandles,
     Select,
     SwitchView,
     CycleWindow,
     CycleChartType,
     ResetScroll,
     ToggleMute,
+    CycleFocus,
+    TogglePercentGrid,
+    ToggleEma,
+    ToggleCrosshair,
+    ToggleMacd,
+    ToggleScaleMode,
+    LockScale,
+    UnlockScale,
+    ExportSnapshot,
+    CopyToClipboard,
+    GammaDown,
+    GammaUp,
+    VolumeDown,
+    VolumeUp,
+    ToggleHeader,
+    ToggleFps,
+    ReloadConfig,
+    // Price alert entry events
+    StartPriceEntry,
+    PriceEntryChar(char),
+    PriceEntryBackspace,
+    ConfirmPriceEntry,
+    CancelPriceEntry,
+    // Coin search overlay events
+    StartSearch,
+    SearchChar(char),
+    SearchBackspace,
+    SearchUp,
+    SearchDown,
+    ConfirmSearch,
+    CancelSearch,
+    ToggleHelp,
+    CloseModal,
     // Notifications view events
     NotificationRuleUp,
     NotificationRuleDown,
     ToggleNotificationRule,
+    CycleNotificationFilter,
+    CycleSortKey,
+    ToggleSortDirection,
+    NotificationUp,
+    NotificationDown,
+    DismissNotification,
+    ClearNotifications,
     // News view events
     NewsScrollUp,
     NewsScrollDown,
     ContentScrollUp,
     ContentScrollDown,
     RefreshNews,
+    ToggleNewsFilter,
     // Positions view events
     PositionUp,
     PositionDown,
@@ -38,74 +81,113 @@ pub enum AppEvent {
 /// Poll and handle keyboard events
 pub fn handle_gl_events(keyboard: &mut KeyboardInput, app: &mut App) {
     for event in keyboard.poll_events() {
-        let action = map_key_event(event, app.view);
+        let action = if app.modal.is_some() {
+            map_modal_key_event(event)
+        } else if app.price_entry.is_some() {
+            map_price_entry_key_event(event)
+        } else if app.search.is_some() {
+            map_search_key_event(event)
+        } else {
+            app.keymap.event(event, app.view).unwrap_or(AppEvent::None)
+        };
         apply_action(app, action);
     }
 }
 
-fn map_key_event(event: KeyEvent, view: View) -> AppEvent {
-    match event {
-        KeyEvent::Escape | KeyEvent::Char('q') => AppEvent::Quit,
+/// Pointer state carried between frames
+#[derive(Default)]
+pub struct PointerState {
+    /// Chart panel bounds from the last layout (drag targets)
+    pub chart_rects: Vec<Rect>,
+    /// Chart drag in progress
+    drag: Option<ChartDrag>,
+}
 
-        // Navigation / Zoom (context-dependent on view)
-        KeyEvent::Up | KeyEvent::Char('k') => match view {
-            View::Details => AppEvent::ZoomIn,
-            View::Notifications => AppEvent::NotificationRuleUp,
-            View::News => AppEvent::NewsScrollUp,
-            View::Positions => AppEvent::PositionUp,
-            View::Overview => AppEvent::MoveUp,
-        },
-        KeyEvent::Down | KeyEvent::Char('j') => match view {
-            View::Details => AppEvent::ZoomOut,
-            View::Notifications => AppEvent::NotificationRuleDown,
-            View::News => AppEvent::NewsScrollDown,
-            View::Positions => AppEvent::PositionDown,
-            View::Overview => AppEvent::MoveDown,
-        },
-        KeyEvent::Left | KeyEvent::Char('h') => {
-            if view == View::Details {
-                AppEvent::MoveLeft
-            } else {
-                AppEvent::None
+/// A press that started on a chart: horizontal motion scrolls the candles
+struct ChartDrag {
+    start_x: f32,
+    start_offset: isize,
+    /// Pixels per candle at the current zoom
+    candle_width: f32,
+}
+
+/// Poll and handle pointer events: a tap focuses the panel under it, and
+/// dragging a Details chart scrolls it (right = back in time)
+pub fn handle_pointer_events(
+    pointer: &mut PointerInput,
+    state: &mut PointerState,
+    focus_manager: &mut FocusManager,
+    app: &mut App,
+) {
+    for event in pointer.poll_events() {
+        match event {
+            PointerEvent::Down { x, y } => {
+                if let Some(index) = focus_manager.focus_at(x, y) {
+                    app.focus_index = Some(index);
+                }
+                let chart = state.chart_rects.iter().find(|rect| rect.contains(x, y));
+                if let Some(rect) =
+                    chart.filter(|_| app.view == View::Details && app.modal.is_none())
+                {
+                    state.drag = Some(ChartDrag {
+                        start_x: x,
+                        start_offset: app.candle_scroll_offset,
+                        candle_width: rect.width / app.visible_candles.max(1) as f32,
+                    });
+                }
             }
-        }
-        KeyEvent::Right | KeyEvent::Char('l') => {
-            if view == View::Details {
-                AppEvent::MoveRight
-            } else {
-                AppEvent::None
+            PointerEvent::Move { x, .. } => {
+                if let Some(drag) = &state.drag {
+                    let candles = ((x - drag.start_x) / drag.candle_width).round() as isize;
+                    app.set_candle_scroll(drag.start_offset + candles);
+                }
             }
+            PointerEvent::Up { .. } => state.drag = None,
         }
+    }
+}
 
-        // Actions
-        KeyEvent::Space => {
-            if view == View::Notifications {
-                AppEvent::ToggleNotificationRule
-            } else {
-                AppEvent::Select
-            }
-        }
-        KeyEvent::Tab | KeyEvent::Enter => AppEvent::SwitchView,
-        KeyEvent::Char('w') => AppEvent::CycleWindow,
-        KeyEvent::Char('c') => AppEvent::CycleChartType,
-        KeyEvent::Char('r') => match view {
-            View::News => AppEvent::RefreshNews,
-            View::Positions => AppEvent::RefreshPositions,
-            _ => AppEvent::ResetScroll,
-        },
-        KeyEvent::Home => AppEvent::ResetScroll,
-        KeyEvent::Char('m') => AppEvent::ToggleMute,
+/// Keys while a modal is open: Tab cycles focus within it, everything else is swallowed
+fn map_modal_key_event(event: KeyEvent) -> AppEvent {
+    match event {
+        KeyEvent::Escape | KeyEvent::Char('q') => AppEvent::CloseModal,
+        KeyEvent::Char('?') => AppEvent::ToggleHelp,
+        KeyEvent::Tab | KeyEvent::Char('i') => AppEvent::CycleFocus,
+        _ => AppEvent::None,
+    }
+}
 
-        // Page Up/Down for content scrolling in News view
-        KeyEvent::PageUp => match view {
-            View::News => AppEvent::ContentScrollUp,
-            _ => AppEvent::None,
-        },
-        KeyEvent::PageDown => match view {
-            View::News => AppEvent::ContentScrollDown,
-            _ => AppEvent::None,
-        },
+/// Keys while typing a price alert: digits and '.', Backspace, Enter, Esc
+fn map_price_entry_key_event(event: KeyEvent) -> AppEvent {
+    match event {
+        KeyEvent::Enter => AppEvent::ConfirmPriceEntry,
+        KeyEvent::Escape => AppEvent::CancelPriceEntry,
+        KeyEvent::Backspace => AppEvent::PriceEntryBackspace,
+        KeyEvent::Num1 => AppEvent::PriceEntryChar('1'),
+        KeyEvent::Num2 => AppEvent::PriceEntryChar('2'),
+        KeyEvent::Num3 => AppEvent::PriceEntryChar('3'),
+        KeyEvent::Num4 => AppEvent::PriceEntryChar('4'),
+        KeyEvent::Num5 => AppEvent::PriceEntryChar('5'),
+        KeyEvent::Char(ch) if ch.is_ascii_digit() || ch == '.' => AppEvent::PriceEntryChar(ch),
+        _ => AppEvent::None,
+    }
+}
 
+/// Search overlay keys: every character is typed into the query
+fn map_search_key_event(event: KeyEvent) -> AppEvent {
+    match event {
+        KeyEvent::Enter => AppEvent::ConfirmSearch,
+        KeyEvent::Escape => AppEvent::CancelSearch,
+        KeyEvent::Backspace => AppEvent::SearchBackspace,
+        KeyEvent::Up | KeyEvent::ShiftTab => AppEvent::SearchUp,
+        KeyEvent::Down | KeyEvent::Tab => AppEvent::SearchDown,
+        KeyEvent::Space => AppEvent::SearchChar(' '),
+        KeyEvent::Num1 => AppEvent::SearchChar('1'),
+        KeyEvent::Num2 => AppEvent::SearchChar('2'),
+        KeyEvent::Num3 => AppEvent::SearchChar('3'),
+        KeyEvent::Num4 => AppEvent::SearchChar('4'),
+        KeyEvent::Num5 => AppEvent::SearchChar('5'),
+        KeyEvent::Char(ch) => AppEvent::SearchChar(ch),
         _ => AppEvent::None,
     }
 }
@@ -124,29 +206,78 @@ fn apply_action(app: &mut App, action: AppEvent) {
             }
         }
         AppEvent::MoveLeft => {
-            app.scroll_candles_left();
+            if app.crosshair_index.is_some() {
+                app.move_crosshair_left();
+            } else {
+                app.scroll_candles_left();
+            }
         }
         AppEvent::MoveRight => {
-            app.scroll_candles_right();
+            if app.crosshair_index.is_some() {
+                app.move_crosshair_right();
+            } else {
+                app.scroll_candles_right();
+            }
         }
         AppEvent::ZoomIn => app.zoom_in(),
         AppEvent::ZoomOut => app.zoom_out(),
+        AppEvent::FitCandles => app.fit_candles(),
         AppEvent::Select => app.toggle_selection(),
         AppEvent::SwitchView => app.switch_view(),
         AppEvent::CycleWindow => app.cycle_window(),
         AppEvent::CycleChartType => app.cycle_chart_type(),
         AppEvent::ResetScroll => app.reset_candle_scroll(),
         AppEvent::ToggleMute => app.toggle_mute(),
+        AppEvent::CycleFocus => app.cycle_focus(),
+        AppEvent::TogglePercentGrid => app.toggle_percent_grid(),
+        AppEvent::ToggleEma => app.toggle_ema(),
+        AppEvent::ToggleCrosshair => app.toggle_crosshair(),
+        AppEvent::ToggleMacd => app.toggle_macd(),
+        AppEvent::ToggleScaleMode => app.toggle_scale_mode(),
+        AppEvent::LockScale => app.lock_price_scale(),
+        AppEvent::UnlockScale => app.unlock_price_scale(),
+        AppEvent::ExportSnapshot => app.export_snapshot(),
+        AppEvent::CopyToClipboard => app.copy_to_clipboard(),
+        AppEvent::GammaDown => app.adjust_gamma(-GAMMA_STEP),
+        AppEvent::GammaUp => app.adjust_gamma(GAMMA_STEP),
+        AppEvent::VolumeDown => app.adjust_volume(-VOLUME_STEP),
+        AppEvent::VolumeUp => app.adjust_volume(VOLUME_STEP),
+        AppEvent::ToggleHeader => app.toggle_header(),
+        AppEvent::ToggleFps => app.toggle_fps(),
+        AppEvent::ReloadConfig => app.reload_config(),
+        // Price alert entry actions
+        AppEvent::StartPriceEntry => app.start_price_entry(),
+        AppEvent::PriceEntryChar(ch) => app.price_entry_push(ch),
+        AppEvent::PriceEntryBackspace => app.price_entry_backspace(),
+        AppEvent::ConfirmPriceEntry => app.confirm_price_entry(),
+        AppEvent::CancelPriceEntry => app.cancel_price_entry(),
+        AppEvent::StartSearch => app.start_search(),
+        AppEvent::SearchChar(ch) => app.search_push(ch),
+        AppEvent::SearchBackspace => app.search_backspace(),
+        AppEvent::SearchUp => app.search_move(-1),
+        AppEvent::SearchDown => app.search_move(1),
+        AppEvent::ConfirmSearch => app.confirm_search(),
+        AppEvent::CancelSearch => app.cancel_search(),
+        AppEvent::ToggleHelp => app.toggle_modal(Modal::Help),
+        AppEvent::CloseModal => app.close_modal(),
         // Notifications view actions
         AppEvent::NotificationRuleUp => app.select_prev_rule(),
         AppEvent::NotificationRuleDown => app.select_next_rule(),
         AppEvent::ToggleNotificationRule => app.toggle_notification_rule(),
+        AppEvent::CycleNotificationFilter => app.cycle_notification_filter(),
+        AppEvent::CycleSortKey => app.cycle_sort_key(),
+        AppEvent::ToggleSortDirection => app.toggle_sort_direction(),
+        AppEvent::NotificationUp => app.select_prev_notification(),
+        AppEvent::NotificationDown => app.select_next_notification(),
+        AppEvent::DismissNotification => app.dismiss_selected_notification(),
+        AppEvent::ClearNotifications => app.clear_notifications(),
         // News view actions
         AppEvent::NewsScrollUp => app.scroll_news_up(),
         AppEvent::NewsScrollDown => app.scroll_news_down(),
         AppEvent::ContentScrollUp => app.scroll_content_up(),
         AppEvent::ContentScrollDown => app.scroll_content_down(),
         AppEvent::RefreshNews => app.refresh_news(),
+        AppEvent::ToggleNewsFilter => app.toggle_news_filter(),
         // Positions view actions
         AppEvent::PositionUp => app.select_prev_position(),
         AppEvent::PositionDown => app.select_next_position(),
@@ -0,0 +1,128 @@
+//! Snapshot export - dump the data behind the current view to a JSON file
+
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::app::{App, View};
+use crate::clock::unix_now;
+use crate::mock::CoinData;
+
+/// Assemble a serializable snapshot of the data relevant to the current view
+///
+/// Overview exports all coins, Details the displayed coins with their candles,
+/// and the other views their own lists.
+pub fn export_snapshot(app: &App) -> Value {
+    let data = match app.view {
+        View::Overview => Value::Array(app.coins.iter().map(|c| coin_json(c, false)).collect()),
+        View::Details => Value::Array(
+            app.active_coins()
+                .iter()
+                .map(|(_, c)| coin_json(c, true))
+                .collect(),
+        ),
+        View::Notifications => {
+            serde_json::to_value(&app.notification_manager.notifications).unwrap_or(Value::Null)
+        }
+        View::News => Value::Array(
+            app.news_articles
+                .iter()
+                .map(|a| {
+                    json!({
+                        "title": a.title,
+                        "source": a.source,
+                        "published_at": a.published_at,
+                        "link": a.link,
+                        "description": a.description,
+                    })
+                })
+                .collect(),
+        ),
+        View::Positions => match &app.margin_account {
+            Some(account) => json!({
+                "account_type": account.account_type,
+                "margin_level": account.margin_level,
+                "total_asset_usd": account.total_asset_usd,
+                "total_liability_usd": account.total_liability_usd,
+                "total_net_usd": account.total_net_usd,
+                "positions": account
+                    .positions
+                    .iter()
+                    .map(|p| {
+                        json!({
+                            "asset": p.asset,
+                            "free": p.free,
+                            "locked": p.locked,
+                            "borrowed": p.borrowed,
+                            "interest": p.interest,
+                            "net_asset": p.net_asset,
+                            "current_price": p.current_price,
+                            "total_value_usd": p.total_value_usd,
+                            "borrowed_value_usd": p.borrowed_value_usd,
+                            "net_value_usd": p.net_value_usd,
+                            "session_entry": p.session_entry,
+                            "session_pnl": p.session_pnl,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            }),
+            None => Value::Null,
+        },
+        View::Diagnostics => Value::Array(
+            app.connection_log
+                .newest_first()
+                .filter_map(|e| serde_json::to_value(e).ok())
+                .collect(),
+        ),
+    };
+
+    json!({
+        "exported_at": unix_now(),
+        "view": format!("{:?}", app.view),
+        "provider": app.provider,
+        "time_window": app.time_window().as_str(),
+        "data": data,
+    })
+}
+
+/// Write the snapshot to `snapshot-<unix time>.json` in the working directory
+pub fn write_snapshot(app: &App) -> std::io::Result<PathBuf> {
+    let path = PathBuf::from(format!("snapshot-{}.json", unix_now()));
+    let content = serde_json::to_string_pretty(&export_snapshot(app))?;
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+fn coin_json(coin: &CoinData, with_candles: bool) -> Value {
+    let ind = &coin.indicators;
+    let mut value = json!({
+        "symbol": coin.symbol,
+        "price": coin.price,
+        "change_24h": coin.change_24h,
+        "volume_usd": coin.volume_usd,
+        "volume_base": coin.volume_base,
+        "high_24h": coin.high_24h,
+        "low_24h": coin.low_24h,
+        "indicators": {
+            "rsi_6": ind.rsi_6,
+            "rsi_12": ind.rsi_12,
+            "rsi_24": ind.rsi_24,
+            "ema_7": ind.ema_7,
+            "ema_25": ind.ema_25,
+            "ema_99": ind.ema_99,
+            "macd_line": ind.macd_line,
+            "macd_signal": ind.macd_signal,
+            "macd_histogram": ind.macd_histogram,
+        },
+    });
+
+    if with_candles {
+        value["candles"] = coin
+            .candles
+            .iter()
+            .map(|c| json!([c.time, c.open, c.high, c.low, c.close, c.volume]))
+            .collect();
+    }
+
+    value
+}
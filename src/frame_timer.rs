@@ -0,0 +1,58 @@
+//! Rolling frame timing for the render loop
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Frames averaged for the FPS readout
+const WINDOW: usize = 60;
+
+/// Measures the time between loop iterations over the last `WINDOW` frames
+pub struct FrameTimer {
+    last: Instant,
+    samples: VecDeque<Duration>,
+    total: Duration,
+}
+
+impl FrameTimer {
+    pub fn new() -> Self {
+        Self {
+            last: Instant::now(),
+            samples: VecDeque::with_capacity(WINDOW),
+            total: Duration::ZERO,
+        }
+    }
+
+    /// Record the end of a frame
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        let delta = now - self.last;
+        self.last = now;
+
+        if self.samples.len() == WINDOW {
+            if let Some(oldest) = self.samples.pop_front() {
+                self.total -= oldest;
+            }
+        }
+        self.samples.push_back(delta);
+        self.total += delta;
+    }
+
+    /// Average frame time over the window (zero before the first tick)
+    pub fn average(&self) -> Duration {
+        match self.samples.len() {
+            0 => Duration::ZERO,
+            n => self.total / n as u32,
+        }
+    }
+
+    /// Overlay text, e.g. "FPS: 60 | frame: 16.7 ms"
+    pub fn label(&self) -> String {
+        let frame_ms = self.average().as_secs_f64() * 1000.0;
+        let fps = if frame_ms > 0.0 {
+            1000.0 / frame_ms
+        } else {
+            0.0
+        };
+        format!("FPS: {:.0} | frame: {:.1} ms", fps, frame_ms)
+    }
+}
@@ -0,0 +1,494 @@
+//! Key bindings: named actions bound to keys, overridable in config.json
+//!
+//! Each action has default keys (the built-in layout) and applies to some
+//! views. Several actions may share a key when they live in different views
+//! ('s' sorts in Overview and toggles log scale in Details); the first action
+//! valid in the current view wins, and keys from config.json take precedence.
+//! Shift+letter falls back to the plain letter's binding unless the uppercase
+//! letter is bound itself ('R' reloads the config, Shift+q still quits).
+//! Key hints in the footers and the help dialog are generated from the keymap.
+
+use std::collections::HashMap;
+
+use crate::app::View;
+use crate::base::KeyEvent;
+use crate::events::AppEvent;
+
+/// A bindable action
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    NextView,
+    ZoomIn,
+    ZoomOut,
+    MoveUp,
+    MoveDown,
+    ScrollLeft,
+    ScrollRight,
+    Select,
+    CycleWindow,
+    ChartType,
+    Refresh,
+    ResetScroll,
+    ToggleMute,
+    Export,
+    Help,
+    Search,
+    GammaDown,
+    GammaUp,
+    VolumeDown,
+    VolumeUp,
+    ToggleHeader,
+    ToggleFps,
+    ReloadConfig,
+    Copy,
+    SeverityFilter,
+    NewsFilter,
+    AddAlert,
+    PercentGrid,
+    Ema,
+    FitCandles,
+    ToggleScale,
+    CycleSort,
+    ReverseSort,
+    Macd,
+    ClearAlerts,
+    Crosshair,
+    DismissAlert,
+    LockScale,
+    UnlockScale,
+    FocusIndicators,
+    ArticleUp,
+    ArticleDown,
+    PrevAlert,
+    NextAlert,
+}
+
+impl Action {
+    /// Every action, in lookup priority order for shared default keys
+    pub const ALL: [Action; 45] = [
+        Action::Quit,
+        Action::NextView,
+        Action::ZoomIn,
+        Action::ZoomOut,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::ScrollLeft,
+        Action::ScrollRight,
+        Action::Select,
+        Action::CycleWindow,
+        Action::ChartType,
+        Action::Refresh,
+        Action::ResetScroll,
+        Action::ToggleMute,
+        Action::Export,
+        Action::Help,
+        Action::Search,
+        Action::GammaDown,
+        Action::GammaUp,
+        Action::VolumeDown,
+        Action::VolumeUp,
+        Action::ToggleHeader,
+        Action::ToggleFps,
+        Action::ReloadConfig,
+        Action::Copy,
+        Action::SeverityFilter,
+        Action::NewsFilter,
+        Action::AddAlert,
+        Action::PercentGrid,
+        Action::Ema,
+        Action::FitCandles,
+        Action::ToggleScale,
+        Action::CycleSort,
+        Action::ReverseSort,
+        Action::Macd,
+        Action::ClearAlerts,
+        Action::Crosshair,
+        Action::DismissAlert,
+        Action::LockScale,
+        Action::UnlockScale,
+        Action::FocusIndicators,
+        Action::ArticleUp,
+        Action::ArticleDown,
+        Action::PrevAlert,
+        Action::NextAlert,
+    ];
+
+    /// Name used in the config.json `keybindings` section
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::NextView => "next_view",
+            Action::ZoomIn => "zoom_in",
+            Action::ZoomOut => "zoom_out",
+            Action::MoveUp => "move_up",
+            Action::MoveDown => "move_down",
+            Action::ScrollLeft => "scroll_left",
+            Action::ScrollRight => "scroll_right",
+            Action::Select => "select",
+            Action::CycleWindow => "cycle_window",
+            Action::ChartType => "chart_type",
+            Action::Refresh => "refresh",
+            Action::ResetScroll => "reset_scroll",
+            Action::ToggleMute => "toggle_mute",
+            Action::Export => "export",
+            Action::Help => "help",
+            Action::Search => "search",
+            Action::GammaDown => "gamma_down",
+            Action::GammaUp => "gamma_up",
+            Action::VolumeDown => "volume_down",
+            Action::VolumeUp => "volume_up",
+            Action::ToggleHeader => "toggle_header",
+            Action::ToggleFps => "toggle_fps",
+            Action::ReloadConfig => "reload_config",
+            Action::Copy => "copy",
+            Action::SeverityFilter => "severity_filter",
+            Action::NewsFilter => "news_filter",
+            Action::AddAlert => "add_alert",
+            Action::PercentGrid => "percent_grid",
+            Action::Ema => "ema",
+            Action::FitCandles => "fit_candles",
+            Action::ToggleScale => "toggle_scale",
+            Action::CycleSort => "cycle_sort",
+            Action::ReverseSort => "reverse_sort",
+            Action::Macd => "macd",
+            Action::ClearAlerts => "clear_alerts",
+            Action::Crosshair => "crosshair",
+            Action::DismissAlert => "dismiss_alert",
+            Action::LockScale => "lock_scale",
+            Action::UnlockScale => "unlock_scale",
+            Action::FocusIndicators => "focus_indicators",
+            Action::ArticleUp => "article_up",
+            Action::ArticleDown => "article_down",
+            Action::PrevAlert => "prev_alert",
+            Action::NextAlert => "next_alert",
+        }
+    }
+
+    /// Keys bound when config.json doesn't rebind the action
+    fn default_keys(self) -> &'static [KeyEvent] {
+        use KeyEvent::*;
+        match self {
+            Action::Quit => &[Escape, Char('q')],
+            Action::NextView => &[Tab, Enter],
+            Action::ZoomIn | Action::MoveUp => &[Up, Char('k')],
+            Action::ZoomOut | Action::MoveDown => &[Down, Char('j')],
+            Action::ScrollLeft => &[Left, Char('h')],
+            Action::ScrollRight => &[Right, Char('l')],
+            Action::Select => &[Space],
+            Action::CycleWindow => &[Char('w')],
+            Action::ChartType => &[Char('c')],
+            Action::Refresh => &[Char('r')],
+            Action::ResetScroll => &[Char('r'), Home],
+            Action::ToggleMute => &[Char('m')],
+            Action::Export => &[Char('e')],
+            Action::Help => &[Char('?')],
+            Action::Search => &[Char('/')],
+            Action::GammaDown => &[Char('-')],
+            Action::GammaUp => &[Char('=')],
+            Action::VolumeDown => &[Char('[')],
+            Action::VolumeUp => &[Char(']')],
+            Action::ToggleHeader => &[Char('f')],
+            Action::ToggleFps => &[Char('t')],
+            Action::ReloadConfig => &[Char('R')],
+            Action::Copy => &[Char('y')],
+            Action::SeverityFilter | Action::NewsFilter => &[Char('v')],
+            Action::AddAlert => &[Char('a')],
+            Action::PercentGrid => &[Char('g')],
+            Action::Ema => &[Char('o')],
+            Action::FitCandles => &[Char('z')],
+            Action::ToggleScale | Action::CycleSort => &[Char('s')],
+            Action::ReverseSort => &[Char('S')],
+            Action::Macd => &[Char('d')],
+            Action::ClearAlerts => &[Char('C')],
+            Action::Crosshair | Action::DismissAlert => &[Char('x')],
+            Action::LockScale => &[Char('p')],
+            Action::UnlockScale => &[Char('u')],
+            Action::FocusIndicators => &[Char('i')],
+            Action::ArticleUp | Action::PrevAlert => &[PageUp],
+            Action::ArticleDown | Action::NextAlert => &[PageDown],
+        }
+    }
+
+    /// What the action does in `view` (None where it doesn't apply)
+    pub fn event(self, view: View) -> Option<AppEvent> {
+        let event = match (self, view) {
+            (Action::Quit, _) => AppEvent::Quit,
+            (Action::NextView, _) => AppEvent::SwitchView,
+            (Action::ZoomIn, View::Details) => AppEvent::ZoomIn,
+            (Action::ZoomOut, View::Details) => AppEvent::ZoomOut,
+            (Action::MoveUp, View::Overview) => AppEvent::MoveUp,
+            (Action::MoveUp, View::Notifications) => AppEvent::NotificationRuleUp,
+            (Action::MoveUp, View::News) => AppEvent::NewsScrollUp,
+            (Action::MoveUp, View::Positions) => AppEvent::PositionUp,
+            (Action::MoveDown, View::Overview) => AppEvent::MoveDown,
+            (Action::MoveDown, View::Notifications) => AppEvent::NotificationRuleDown,
+            (Action::MoveDown, View::News) => AppEvent::NewsScrollDown,
+            (Action::MoveDown, View::Positions) => AppEvent::PositionDown,
+            (Action::ScrollLeft, View::Details) => AppEvent::MoveLeft,
+            (Action::ScrollRight, View::Details) => AppEvent::MoveRight,
+            (Action::Select, View::Notifications) => AppEvent::ToggleNotificationRule,
+            (Action::Select, _) => AppEvent::Select,
+            (Action::CycleWindow, _) => AppEvent::CycleWindow,
+            (Action::ChartType, _) => AppEvent::CycleChartType,
+            (Action::Refresh, View::News) => AppEvent::RefreshNews,
+            (Action::Refresh, View::Positions) => AppEvent::RefreshPositions,
+            (Action::ResetScroll, _) => AppEvent::ResetScroll,
+            (Action::ToggleMute, _) => AppEvent::ToggleMute,
+            (Action::Export, _) => AppEvent::ExportSnapshot,
+            (Action::Help, _) => AppEvent::ToggleHelp,
+            (Action::Search, _) => AppEvent::StartSearch,
+            (Action::GammaDown, _) => AppEvent::GammaDown,
+            (Action::GammaUp, _) => AppEvent::GammaUp,
+            (Action::VolumeDown, _) => AppEvent::VolumeDown,
+            (Action::VolumeUp, _) => AppEvent::VolumeUp,
+            (Action::ToggleHeader, _) => AppEvent::ToggleHeader,
+            (Action::ToggleFps, _) => AppEvent::ToggleFps,
+            (Action::ReloadConfig, _) => AppEvent::ReloadConfig,
+            (Action::Copy, View::Overview | View::Details) => AppEvent::CopyToClipboard,
+            (Action::SeverityFilter, View::Notifications) => AppEvent::CycleNotificationFilter,
+            (Action::NewsFilter, View::News) => AppEvent::ToggleNewsFilter,
+            (Action::AddAlert, View::Overview) => AppEvent::StartPriceEntry,
+            (Action::PercentGrid, View::Details) => AppEvent::TogglePercentGrid,
+            (Action::Ema, View::Details) => AppEvent::ToggleEma,
+            (Action::FitCandles, View::Details) => AppEvent::FitCandles,
+            (Action::ToggleScale, View::Details) => AppEvent::ToggleScaleMode,
+            (Action::CycleSort, View::Overview) => AppEvent::CycleSortKey,
+            (Action::ReverseSort, View::Overview) => AppEvent::ToggleSortDirection,
+            (Action::Macd, View::Details) => AppEvent::ToggleMacd,
+            (Action::ClearAlerts, View::Notifications) => AppEvent::ClearNotifications,
+            (Action::Crosshair, View::Details) => AppEvent::ToggleCrosshair,
+            (Action::DismissAlert, View::Notifications) => AppEvent::DismissNotification,
+            (Action::LockScale, View::Details) => AppEvent::LockScale,
+            (Action::UnlockScale, View::Details) => AppEvent::UnlockScale,
+            (Action::FocusIndicators, View::Details) => AppEvent::CycleFocus,
+            (Action::ArticleUp, View::News) => AppEvent::ContentScrollUp,
+            (Action::ArticleDown, View::News) => AppEvent::ContentScrollDown,
+            (Action::PrevAlert, View::Notifications) => AppEvent::NotificationUp,
+            (Action::NextAlert, View::Notifications) => AppEvent::NotificationDown,
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+/// Key -> candidate actions lookup used by the event handler
+pub struct Keymap {
+    bindings: HashMap<KeyEvent, Vec<Action>>,
+    /// Keys of each action in binding order, for key hints
+    keys: HashMap<Action, Vec<KeyEvent>>,
+}
+
+impl Keymap {
+    /// Defaults with `overrides` (action name -> key names) applied; an
+    /// overridden action loses its default keys. Unknown action or key names
+    /// are reported and skipped.
+    pub fn new(overrides: &HashMap<String, Vec<String>>) -> Self {
+        let mut configured: Vec<(Action, Vec<KeyEvent>)> = Vec::new();
+        for (name, keys) in overrides {
+            let Some(action) = Action::from_name(name) else {
+                eprintln!("Unknown keybinding action '{}', ignored", name);
+                continue;
+            };
+            let keys = keys
+                .iter()
+                .filter_map(|key| {
+                    let parsed = parse_key(key);
+                    if parsed.is_none() {
+                        eprintln!("Unknown key '{}' for keybinding '{}', ignored", key, name);
+                    }
+                    parsed
+                })
+                .collect();
+            configured.push((action, keys));
+        }
+
+        let mut bindings: HashMap<KeyEvent, Vec<Action>> = HashMap::new();
+        let mut action_keys: HashMap<Action, Vec<KeyEvent>> = HashMap::new();
+        for action in Action::ALL {
+            if configured.iter().any(|(a, _)| *a == action) {
+                continue;
+            }
+            for &key in action.default_keys() {
+                bindings.entry(key).or_default().push(action);
+            }
+            action_keys.insert(action, action.default_keys().to_vec());
+        }
+        // Configured keys win over defaults that share them
+        for (action, keys) in configured {
+            for &key in &keys {
+                bindings.entry(key).or_default().insert(0, action);
+            }
+            action_keys.insert(action, keys);
+        }
+
+        Self {
+            bindings,
+            keys: action_keys,
+        }
+    }
+
+    /// Event for a key press in `view` (None if unbound there); an uppercase
+    /// letter with nothing bound in `view` acts as its lowercase letter
+    pub fn event(&self, key: KeyEvent, view: View) -> Option<AppEvent> {
+        self.bound_event(key, view).or_else(|| match key {
+            KeyEvent::Char(ch) if ch.is_ascii_uppercase() => {
+                self.bound_event(KeyEvent::Char(ch.to_ascii_lowercase()), view)
+            }
+            _ => None,
+        })
+    }
+
+    fn bound_event(&self, key: KeyEvent, view: View) -> Option<AppEvent> {
+        self.bindings
+            .get(&key)?
+            .iter()
+            .find_map(|action| action.event(view))
+    }
+
+    /// Hint for every key bound to `action`, e.g. "Esc/q" for quit ("-" if unbound)
+    pub fn label(&self, action: Action) -> String {
+        join_keys(self.keys(action).iter().copied())
+    }
+
+    /// Hint for a pair of opposite actions from their first keys, e.g. "▲/▼"
+    pub fn pair_label(&self, first: Action, second: Action) -> String {
+        let first_key = |action| self.keys(action).first().copied();
+        join_keys([first_key(first), first_key(second)].into_iter().flatten())
+    }
+
+    fn keys(&self, action: Action) -> &[KeyEvent] {
+        self.keys.get(&action).map_or(&[], Vec::as_slice)
+    }
+}
+
+fn join_keys(keys: impl Iterator<Item = KeyEvent>) -> String {
+    let names: Vec<String> = keys.map(key_name).collect();
+    if names.is_empty() {
+        "-".to_string()
+    } else {
+        names.join("/")
+    }
+}
+
+/// Short display name of a key, as the footers and help dialog show it
+fn key_name(key: KeyEvent) -> String {
+    let name = match key {
+        KeyEvent::Up => "▲",
+        KeyEvent::Down => "▼",
+        KeyEvent::Left => "←",
+        KeyEvent::Right => "→",
+        KeyEvent::Enter => "Enter",
+        KeyEvent::Escape => "Esc",
+        KeyEvent::Tab => "Tab",
+        KeyEvent::ShiftTab => "Shift+Tab",
+        KeyEvent::PageUp => "PgUp",
+        KeyEvent::PageDown => "PgDn",
+        KeyEvent::Home => "Home",
+        KeyEvent::End => "End",
+        KeyEvent::Num1 => "1",
+        KeyEvent::Num2 => "2",
+        KeyEvent::Num3 => "3",
+        KeyEvent::Num4 => "4",
+        KeyEvent::Num5 => "5",
+        KeyEvent::Space => "Space",
+        KeyEvent::Backspace => "Backspace",
+        KeyEvent::Char(ch) => return ch.to_string(),
+    };
+    name.to_string()
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&HashMap::new())
+    }
+}
+
+/// Parse a key name: a single character ("q", "?", "S") or a named key
+/// ("Up", "PageDown", "Shift+Tab", ...; names are case-insensitive)
+fn parse_key(name: &str) -> Option<KeyEvent> {
+    let mut chars = name.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Some(match ch {
+            '1' => KeyEvent::Num1,
+            '2' => KeyEvent::Num2,
+            '3' => KeyEvent::Num3,
+            '4' => KeyEvent::Num4,
+            '5' => KeyEvent::Num5,
+            ' ' => KeyEvent::Space,
+            ch => KeyEvent::Char(ch),
+        });
+    }
+
+    let key = match name.to_ascii_lowercase().as_str() {
+        "up" => KeyEvent::Up,
+        "down" => KeyEvent::Down,
+        "left" => KeyEvent::Left,
+        "right" => KeyEvent::Right,
+        "enter" => KeyEvent::Enter,
+        "esc" | "escape" => KeyEvent::Escape,
+        "tab" => KeyEvent::Tab,
+        "shift+tab" => KeyEvent::ShiftTab,
+        "pgup" | "pageup" => KeyEvent::PageUp,
+        "pgdn" | "pagedown" => KeyEvent::PageDown,
+        "home" => KeyEvent::Home,
+        "end" => KeyEvent::End,
+        "space" => KeyEvent::Space,
+        "backspace" => KeyEvent::Backspace,
+        _ => return None,
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keymap(overrides: &[(&str, &[&str])]) -> Keymap {
+        let overrides = overrides
+            .iter()
+            .map(|(action, keys)| {
+                let keys = keys.iter().map(|key| key.to_string()).collect();
+                (action.to_string(), keys)
+            })
+            .collect();
+        Keymap::new(&overrides)
+    }
+
+    #[test]
+    fn labels_follow_the_bound_keys() {
+        let default = Keymap::default();
+        assert_eq!(default.label(Action::Quit), "Esc/q");
+        assert_eq!(default.label(Action::NextView), "Tab/Enter");
+        assert_eq!(default.pair_label(Action::MoveUp, Action::MoveDown), "▲/▼");
+        assert_eq!(
+            default.pair_label(Action::ArticleUp, Action::ArticleDown),
+            "PgUp/PgDn"
+        );
+
+        let rebound = keymap(&[("refresh", &["F"]), ("quit", &["x"]), ("help", &[])]);
+        assert_eq!(rebound.label(Action::Refresh), "F");
+        assert_eq!(rebound.label(Action::Quit), "x");
+        assert_eq!(rebound.label(Action::Help), "-");
+    }
+
+    #[test]
+    fn shift_letters_fall_back_to_the_lowercase_binding() {
+        let keymap = Keymap::default();
+        let event = |ch, view| keymap.event(KeyEvent::Char(ch), view);
+        assert!(matches!(event('Q', View::Overview), Some(AppEvent::Quit)));
+        // Uppercase letters with their own binding keep it
+        assert!(matches!(
+            event('S', View::Overview),
+            Some(AppEvent::ToggleSortDirection)
+        ));
+        assert!(matches!(
+            event('R', View::News),
+            Some(AppEvent::ReloadConfig)
+        ));
+    }
+}
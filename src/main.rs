@@ -1,60 +1,147 @@
 mod api;
 mod app;
 mod base;
+mod clipboard;
+mod clock;
 mod config;
+mod connection_log;
 mod events;
+mod export;
+mod frame_timer;
+mod keymap;
 mod mock;
 mod notifications;
 mod news_cache;
+mod session;
 mod views;
 mod widgets;
 
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+use crate::base::renderer::rect_renderer::Rect;
+use crate::base::view::Breakpoints;
 use crate::base::{
-    glow, render, taffy, Display, FocusManager, FontAtlas, KeyboardInput, LayoutTree, RectRenderer,
-    ScissorStack, TextRenderer,
+    glow, render, render_tooltip, taffy, text_box_line_counts, Display, DisplayOptions,
+    FocusManager, FontAtlas, KeyboardInput, LayoutTree, PointerInput, RectRenderer, ScissorStack,
+    TextRenderer, Tooltip,
 };
 use glow::HasContext;
 
-use api::binance::{fetch_candles, granularity_to_interval, BinanceProvider};
+use api::binance::{candle_open_offset, granularity_to_interval};
+use api::candle_cache::{merge_candles, CandleCache};
 use api::margin::{fetch_margin_account, MarginAccount};
-use api::news::{fetch_all_news, NewsArticle};
-use api::PriceUpdate;
-use app::{App, ChartType};
+use api::news::{fetch_all_news, has_api_keys, NewsArticle};
+use api::provider::{route_pairs, PriceProvider, Provider};
+use api::rate_limit::{is_rate_limited, RateLimiter};
+use api::{CandleRequest, PriceUpdate};
+use app::{App, ChartType, View, GAMMA_RANGE};
+use clipboard::CopyTarget;
+use clock::unix_now;
 use config::Config;
-use events::handle_gl_events;
+use connection_log::ConnectionLog;
+use events::{handle_gl_events, handle_pointer_events, PointerState};
+use frame_timer::FrameTimer;
+use keymap::Keymap;
 use mock::{coins_from_pairs, generate_mock_coins};
-use notifications::{audio, persistence, NotificationManager};
 use news_cache::NewsCache;
-use views::CHART_PANEL_PREFIX;
+use notifications::{
+    audio, desktop, persistence, webhook, NotificationManager, ScreenFlash, TickerTonesState,
+};
+use views::{CHART_PANEL_PREFIX, NEWS_CONTENT_ID};
 use widgets::candlestick_chart::render_candlestick_chart;
 use widgets::chart_renderer::{ChartRenderer, PixelRect};
+use widgets::chart_utils::volume_readout;
+use widgets::coin_table::COIN_TABLE_ID;
+use widgets::format::ValueColor;
 use widgets::polygonal_chart::render_polygonal_chart;
+use widgets::sparkline_chart::{render_sparkline_chart, SPARKLINE_PANEL_PREFIX};
 use widgets::theme::GlTheme;
 
 // Font data embedded from fonts directory
 const FONT_DATA: &[u8] = include_bytes!("../fonts/CascadiaMonoPL.ttf");
 const FONT_SIZE: f32 = 17.0;
 
+/// Delay before a focused panel's tooltip is shown
+const TOOLTIP_DELAY: Duration = Duration::from_millis(300);
+
+/// Config file, looked up next to the executable, then in the working directory
+const CONFIG_FILE: &str = "config.json";
+
+/// How long `--screenshot` runs before capturing, so startup data can load
+const SCREENSHOT_DELAY: Duration = Duration::from_secs(3);
+
+/// Command line options
+#[derive(Default)]
+struct CliArgs {
+    /// Render off-screen at this size instead of on the DRM display (`--headless WxH`)
+    headless: Option<(u32, u32)>,
+    /// Capture the screen to this PNG after `SCREENSHOT_DELAY`, then exit (`--screenshot path`)
+    screenshot: Option<PathBuf>,
+    /// Always fetch candle history from the network, bypassing the disk cache (`--no-cache`)
+    no_cache: bool,
+    /// Show the border style samples in the Diagnostics view (`--diagnostics-samples`)
+    diagnostics_samples: bool,
+}
+
+fn parse_args() -> Result<CliArgs, String> {
+    let mut cli = CliArgs::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--headless" => {
+                let size = args
+                    .next()
+                    .ok_or("--headless needs a size, e.g. 1280x720")?;
+                cli.headless = Some(parse_size(&size).ok_or(format!("Invalid size: {}", size))?);
+            }
+            "--screenshot" => {
+                let path = args.next().ok_or("--screenshot needs a file path")?;
+                cli.screenshot = Some(PathBuf::from(path));
+            }
+            "--no-cache" => cli.no_cache = true,
+            "--diagnostics-samples" => cli.diagnostics_samples = true,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    Ok(cli)
+}
+
+/// Parse "WxH" (e.g. "1280x720") into non-zero dimensions
+fn parse_size(size: &str) -> Option<(u32, u32)> {
+    let (w, h) = size.split_once('x')?;
+    let (w, h) = (w.parse().ok()?, h.parse().ok()?);
+    (w > 0 && h > 0).then_some((w, h))
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load environment variables from .env if present (for API keys)
     let _ = dotenvy::dotenv();
 
+    let cli = match parse_args() {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!(
+                "Usage: crypto-dashboard [--headless WxH] [--screenshot path.png] [--no-cache] \
+                 [--diagnostics-samples]"
+            );
+            std::process::exit(2);
+        }
+    };
+
     // Create tokio runtime manually (not async main)
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()?;
 
     // Load config
-    let config = Config::load("config.json");
+    let config = Config::load(CONFIG_FILE);
     let pairs = config.pairs();
 
     // Create GlTheme from config (loads theme by name)
-    let gl_theme = match config.theme_config() {
-        Some(theme_config) => GlTheme::from_config(&theme_config),
-        None => GlTheme::default(),
-    };
+    let gl_theme = load_theme(&config);
 
     // News cache (SQLite)
     let mut news_cache = match NewsCache::open("news_cache.db") {
@@ -65,23 +152,50 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    // Initialize DRM/GBM/EGL display
-    let mut display = Display::new().expect("Failed to initialize DRM display");
+    // Initialize DRM/GBM/EGL display (or an off-screen pbuffer with --headless)
+    let display_config = config.display_config();
+    let display_options = DisplayOptions {
+        safe_mode: display_config.safe_mode,
+        // Only 0/2/4 are supported; round anything else down
+        msaa: match display_config.msaa {
+            0 | 1 => 0,
+            2 | 3 => 2,
+            _ => 4,
+        },
+    };
+    let display = match cli.headless {
+        Some((width, height)) => Display::new_headless(width, height, display_options),
+        None => Display::new(display_options),
+    };
+    let mut display = match display {
+        Ok(display) => display,
+        Err(e) => {
+            eprintln!("Failed to initialize display: {}", e);
+            std::process::exit(1);
+        }
+    };
     let height = display.height;
 
     // Font atlas
     let atlas = FontAtlas::new(&display.gl, FONT_DATA, FONT_SIZE)?;
+    if atlas.atlas_size as i32 > display.caps.max_texture_size {
+        return Err(format!(
+            "Font atlas ({}px) exceeds the driver's max texture size ({}px)",
+            atlas.atlas_size, display.caps.max_texture_size
+        )
+        .into());
+    }
 
     // Renderers
     let mut text_renderer = TextRenderer::new(&display.gl)?;
     let mut rect_renderer = RectRenderer::new(&display.gl)?;
     let mut chart_renderer = ChartRenderer::new(&display.gl)?;
     let mut scissor_stack = ScissorStack::new(height);
-    let focus_manager = FocusManager::new();
+    let mut focus_manager = FocusManager::new();
 
     // Create channels for price updates and candle requests
     let (price_tx, mut price_rx) = mpsc::channel::<PriceUpdate>(100);
-    let (candle_req_tx, mut candle_req_rx) = mpsc::channel::<(String, u32)>(32);
+    let (candle_req_tx, mut candle_req_rx) = mpsc::channel::<CandleRequest>(32);
 
     // Create channel for news updates
     let (news_tx, mut news_rx) = mpsc::channel::<Vec<NewsArticle>>(10);
@@ -94,12 +208,40 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create channel for interval changes (for kline stream updates)
     let (interval_tx, interval_rx) = mpsc::channel::<String>(10);
 
-    // Determine provider
-    let provider = config.provider();
-    let use_live = provider == "binance";
+    // Determine providers (several live providers stream side by side)
+    let providers = config.provider();
+    let live_names: Vec<&str> = providers
+        .iter()
+        .copied()
+        .filter(|name| Provider::is_live(name))
+        .collect();
+    let use_live = !live_names.is_empty();
+    let uses_binance = live_names.contains(&"binance");
+
+    // Replay mode: recorded candle files stand in for the live feed
+    let replay_series = if !use_live && providers[0] == "replay" {
+        let replay_dir = config.replay_dir();
+        let series = api::replay::load_dir(Path::new(&replay_dir));
+        if series.is_empty() {
+            eprintln!("No replay candle files in {}, using mock data", replay_dir);
+        }
+        series
+    } else {
+        Vec::new()
+    };
+
+    // Status label ("binance", "binance+coinbase"); the first provider picks the chart type
+    let provider = if use_live {
+        live_names.join("+")
+    } else if providers[0] == "replay" && replay_series.is_empty() {
+        "mock".to_string()
+    } else {
+        providers[0].to_string()
+    };
+    let primary_provider = live_names.first().copied().unwrap_or(providers[0]);
 
     // Check if running in testnet mode
-    if use_live && api::binance::is_testnet_mode() {
+    if uses_binance && api::binance::is_testnet_mode() {
         eprintln!("⚠️  WARNING: Running in TESTNET mode");
         eprintln!("⚠️  Endpoints: https://testnet.binance.vision");
         eprintln!("⚠️  Make sure you're using testnet API keys");
@@ -107,9 +249,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create app with appropriate data source
     let coins = if use_live {
-        coins_from_pairs(&pairs)
+        coins_from_pairs(&pairs, &config.coin_names())
+    } else if !replay_series.is_empty() {
+        let replay_pairs: Vec<String> = replay_series.iter().map(|s| s.pair.clone()).collect();
+        coins_from_pairs(&replay_pairs, &config.coin_names())
     } else {
-        generate_mock_coins()
+        generate_mock_coins(&config.coin_names())
     };
 
     // Initialize notification manager from config
@@ -119,17 +264,86 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         notif_config.cooldown_secs,
         notif_config.max_log_entries,
     );
+    notification_manager.set_coalesce_window(notif_config.coalesce_window_secs);
 
     // Load existing notifications from log file
     let existing_notifications = persistence::load_notifications(&notif_config.log_file);
     notification_manager.load_notifications(existing_notifications);
+    notification_manager.load_cooldowns(
+        persistence::load_cooldowns(&notif_config.log_file),
+        unix_now(),
+    );
+
+    // Price alerts added from the UI in earlier runs
+    for rule in persistence::load_added_rules(&notif_config.log_file) {
+        notification_manager.add_rule(rule);
+    }
 
-    // Initialize audio if enabled
+    // Initialize audio if enabled (the output device also applies to ticker tones)
+    audio::set_device(notif_config.audio_device.clone());
+    audio::set_levels(
+        notif_config.volume,
+        notif_config.alert_gain,
+        notif_config.tone_gain,
+    );
     if notif_config.audio_enabled {
         audio::init_audio();
     }
 
-    let mut app = App::with_notification_manager(coins, provider, notification_manager);
+    let mut app = App::with_notification_manager(coins, &provider, notification_manager);
+    let chart_config = config.chart_config();
+    app.max_candles = chart_config.max_candles;
+    app.set_time_windows(chart_config.time_windows());
+    app.set_zoom_levels(chart_config.zoom_levels());
+    app.chart_type = chart_config.chart_type(primary_provider);
+    app.bollinger = chart_config.bollinger;
+    app.percent_grid = chart_config.percent_grid;
+    app.percent_grid_step = chart_config.percent_grid_step;
+    app.compact_indicators = chart_config.compact_indicators;
+    app.trades_sparkline = chart_config.trades_sparkline;
+    app.min_candle_body_width = chart_config.min_body_width;
+    app.lod_candles = chart_config.lod_candles;
+    app.volume_config = config.volume_config();
+    // Safe mode drops the per-tick pulse redraw
+    app.price_pulse = (display_config.price_pulse && !display.safe_mode)
+        .then(|| Duration::from_millis(display_config.price_pulse_ms));
+    // The view crossfade renders two layouts per frame, so safe mode drops it too
+    app.view_fade = (display_config.view_fade_ms > 0 && !display.safe_mode)
+        .then(|| Duration::from_millis(display_config.view_fade_ms));
+    app.set_smooth_scroll(display_config.smooth_scroll);
+    app.compact_price = display_config.compact_price;
+    app.group_thousands = display_config.group_thousands;
+    app.neutral_zone_pct = display_config.neutral_zone_pct;
+    app.sparkline_style = display_config.sparkline;
+    app.show_header = display_config.show_header;
+    app.show_fps = display_config.show_fps;
+    app.diagnostics_samples = cli.diagnostics_samples;
+    app.positions_refresh_on_focus = config.positions_config().refresh_on_focus;
+    app.news_refresh_on_focus = config.news_config().refresh_on_focus;
+    app.news_refresh_secs = config.news_config().refresh_secs;
+    app.keymap = Keymap::new(&config.keybindings());
+    app.gamma = display_config.gamma.clamp(GAMMA_RANGE.0, GAMMA_RANGE.1);
+    app.brightness = display_config.brightness;
+    app.breakpoints = Breakpoints {
+        narrow_width: display_config.narrow_width,
+        short_height: display_config.short_height,
+    };
+    let diagnostics_config = config.diagnostics_config();
+    app.connection_log =
+        ConnectionLog::new(diagnostics_config.max_events, diagnostics_config.log_file);
+    app.stale_after_secs = diagnostics_config.stale_after_secs;
+
+    // Replay history is loaded up front; the replay task streams the rest
+    for series in &replay_series {
+        if let Some(coin) = app.coins.iter_mut().find(|c| c.symbol == series.symbol) {
+            coin.set_candles(series.history.clone(), app.max_candles);
+        }
+    }
+
+    // Restore checked coins, view, chart type and interval from the last run
+    if let Some(session) = session::load_session() {
+        session.restore(&mut app);
+    }
 
     // Load cached news articles (if available)
     if let Some(cache) = news_cache.as_ref() {
@@ -140,33 +354,128 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    // Spawn WebSocket task if using live data
-    if use_live {
-        let initial_granularity = app.time_window.granularity();
-        let initial_interval = granularity_to_interval(initial_granularity);
-        let ws_provider = BinanceProvider::new(pairs.clone(), initial_interval);
+    // Spawn WebSocket tasks if using live data: per provider, one instance
+    // streams its pairs and a second serves REST candle requests for them
+    let initial_granularity = app.time_window().granularity();
+    let initial_interval = granularity_to_interval(initial_granularity);
+    let reconnect_config = config.reconnect_config();
+    let mut interval_txs = Vec::new();
+    let mut candle_providers = Vec::new();
+    for (name, provider_pairs) in route_pairs(&live_names, &pairs) {
+        let (Some(ws_provider), Some(candle_provider)) = (
+            Provider::from_name(name, provider_pairs.clone(), initial_interval)
+                .map(|p| p.with_reconnect(&reconnect_config)),
+            Provider::from_name(name, provider_pairs.clone(), initial_interval),
+        ) else {
+            continue;
+        };
+        let (provider_interval_tx, provider_interval_rx) = mpsc::channel::<String>(10);
+        interval_txs.push(provider_interval_tx);
+
         let ws_tx = price_tx.clone();
         rt.spawn(async move {
-            ws_provider.run(ws_tx, interval_rx).await;
+            ws_provider.run(ws_tx, provider_interval_rx).await;
         });
+        candle_providers.push((candle_provider, provider_pairs));
+    }
 
-        // Spawn candle fetcher task
+    if !replay_series.is_empty() {
+        let replay_tx = price_tx.clone();
+        let step = Duration::from_millis(config.replay_step_ms().max(1));
+        rt.spawn(api::replay::run(replay_series, replay_tx, step));
+    }
+
+    if !candle_providers.is_empty() {
+        // Fan interval changes out to every streaming provider
+        let mut interval_rx = interval_rx;
+        rt.spawn(async move {
+            while let Some(interval) = interval_rx.recv().await {
+                for tx in &interval_txs {
+                    let _ = tx.send(interval.clone()).await;
+                }
+            }
+        });
+
+        // Spawn candle fetcher task (routes each request to the pair's provider;
+        // requests are spaced by the rate limiter and wait in the channel meanwhile).
+        // Full history requests are served from the disk cache when fresh and
+        // only fetch the bars since the cached ones otherwise.
         let candle_tx = price_tx.clone();
+        let candle_limit = chart_config.candle_limit;
+        let mut limiter = RateLimiter::new(config.candle_requests_per_sec());
+        let cache = (!cli.no_cache).then(CandleCache::new);
         rt.spawn(async move {
-            while let Some((symbol, granularity)) = candle_req_rx.recv().await {
-                let interval = granularity_to_interval(granularity);
-                match fetch_candles(&symbol, interval).await {
-                    Ok(candles) => {
-                        // Extract symbol (e.g., "BTCUSDT" -> "BTC")
-                        let sym = symbol.trim_end_matches("USDT").to_string();
+            while let Some(request) = candle_req_rx.recv().await {
+                let Some((candle_provider, _)) = candle_providers
+                    .iter()
+                    .find(|(_, provider_pairs)| provider_pairs.contains(&request.pair))
+                else {
+                    continue;
+                };
+                // Extract symbol (e.g., "BTCUSDT" -> "BTC")
+                let symbol = candle_provider.symbol(&request.pair);
+
+                // A cached series shorter than the history limit counts as a miss,
+                // unless that is all the history the exchange has
+                let cached = match (&cache, request.tail) {
+                    (Some(cache), None) => cache
+                        .load(&request.pair, request.granularity)
+                        .filter(|entry| entry.covers(candle_limit)),
+                    _ => None,
+                };
+                let limit = match (&cached, request.tail) {
+                    (Some(entry), _) if entry.is_fresh(request.granularity) => {
+                        let candles = entry.candles.clone();
                         let _ = candle_tx
-                            .send(PriceUpdate::Candles {
-                                symbol: sym,
-                                candles,
-                            })
+                            .send(PriceUpdate::Candles { symbol, candles })
                             .await;
+                        continue;
+                    }
+                    (Some(entry), _) => entry.missing_count(request.granularity).min(candle_limit),
+                    (None, tail) => tail.unwrap_or(candle_limit),
+                };
+
+                limiter.acquire().await;
+                match candle_provider
+                    .fetch_candles(&request.pair, request.granularity, limit)
+                    .await
+                {
+                    Ok(candles) => {
+                        let update = if request.tail.is_some() {
+                            if let Some(cache) = &cache {
+                                cache.merge(
+                                    &request.pair,
+                                    request.granularity,
+                                    candles.clone(),
+                                    candle_limit,
+                                );
+                            }
+                            PriceUpdate::CandleTail { symbol, candles }
+                        } else {
+                            // A short full fetch means the exchange has no older bars
+                            let (cached, complete) = match cached {
+                                Some(entry) => (entry.candles, entry.complete),
+                                None => (Vec::new(), candles.len() < limit as usize),
+                            };
+                            let candles = merge_candles(cached, candles, candle_limit);
+                            if let Some(cache) = &cache {
+                                cache.store(&request.pair, request.granularity, &candles, complete);
+                            }
+                            PriceUpdate::Candles { symbol, candles }
+                        };
+                        let _ = candle_tx.send(update).await;
                     }
                     Err(e) => {
+                        if is_rate_limited(&e) {
+                            limiter.rate_limited();
+                        }
+                        // Stale history beats an empty chart
+                        if let Some(entry) = cached {
+                            let candles = entry.candles;
+                            let _ = candle_tx
+                                .send(PriceUpdate::Candles { symbol, candles })
+                                .await;
+                        }
                         let _ = candle_tx
                             .send(PriceUpdate::Error(format!("Candle fetch error: {}", e)))
                             .await;
@@ -176,8 +485,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
-    // Spawn positions fetcher task (requires API keys)
-    if use_live {
+    // Spawn positions fetcher task (requires Binance API keys)
+    if uses_binance {
         if let (Ok(api_key), Ok(api_secret)) = (
             std::env::var("BINANCE_API_KEY"),
             std::env::var("BINANCE_API_SECRET"),
@@ -210,14 +519,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     });
 
-    // Initialize keyboard input (evdev-based)
+    // Initialize keyboard and touchscreen/mouse input (evdev-based)
     let mut keyboard = KeyboardInput::new();
+    keyboard.set_key_repeat(config.keyboard_config().key_repeat());
+    let mut pointer = PointerInput::new(display.width, display.height);
 
     // Run the OpenGL render loop
-    run_gl_loop(
+    let result = run_gl_loop(
         &mut display,
         &mut app,
         &mut keyboard,
+        &mut pointer,
         &mut price_rx,
         candle_req_tx,
         interval_tx,
@@ -233,20 +545,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &mut rect_renderer,
         &mut chart_renderer,
         &mut scissor_stack,
-        &focus_manager,
+        &mut focus_manager,
         &gl_theme,
         &config,
-    )?;
+        cli.screenshot,
+    );
 
-    Ok(())
+    session::save_session(&app);
+    result
 }
 
 fn run_gl_loop(
     display: &mut Display,
     app: &mut App,
     keyboard: &mut KeyboardInput,
+    pointer: &mut PointerInput,
     price_rx: &mut mpsc::Receiver<PriceUpdate>,
-    candle_req_tx: mpsc::Sender<(String, u32)>,
+    candle_req_tx: mpsc::Sender<CandleRequest>,
     interval_tx: mpsc::Sender<String>,
     news_req_tx: mpsc::Sender<Vec<String>>,
     news_rx: &mut mpsc::Receiver<Vec<NewsArticle>>,
@@ -260,24 +575,88 @@ fn run_gl_loop(
     rect_renderer: &mut RectRenderer,
     chart_renderer: &mut ChartRenderer,
     scissor_stack: &mut ScissorStack,
-    focus_manager: &FocusManager,
+    focus_manager: &mut FocusManager,
     theme: &GlTheme,
     config: &Config,
+    mut screenshot: Option<PathBuf>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let (width, height) = (display.width, display.height);
     let notifications_enabled = config.notifications_enabled();
     let audio_enabled = config.audio_enabled();
+    let desktop_notifications = config.desktop_notifications_enabled();
+    let webhook_url = config.webhook_url();
     let log_file = config.log_file();
     let ticker_tones_config = config.ticker_tones_config();
+    let mut ticker_tones = TickerTonesState::new();
+    let connection_sounds_config = config.connection_sounds_config();
+    let max_updates_per_frame = config.display_config().max_updates_per_frame;
+    let frame_budget = config
+        .max_fps()
+        .map(|fps| Duration::from_secs_f64(1.0 / fps as f64));
+    let mut flash_config = config.flash_screen_config();
+    // Full-screen alpha overlays are the first thing to go in safe mode
+    flash_config.enabled &= !display.safe_mode;
+    let mut screen_flash = ScreenFlash::new(flash_config);
+    let mut focused_since: Option<(String, Instant)> = None;
+    let mut pointer_state = PointerState::default();
+    let chart_config = config.chart_config();
+    let clipboard_config = config.clipboard_config();
+    let mut next_close_refresh =
+        next_candle_close(app.time_window().granularity(), unix_now() as i64);
+    // Theme with gamma/brightness applied, rebuilt when either changes at runtime
+    let mut base_theme = *theme;
+    let mut color_adjustment = (app.gamma, app.brightness);
+    let mut adjusted_theme = base_theme.adjusted(app.gamma, app.brightness);
+    let mut frame_timer = FrameTimer::new();
+    let started = Instant::now();
+    // Previous frame's layout, and the outgoing view's while a crossfade runs
+    let mut last_frame: Option<(LayoutTree, taffy::NodeId, View)> = None;
+    let mut fade_from: Option<(LayoutTree, taffy::NodeId)> = None;
+    let mut prev_frame_start = Instant::now();
 
     while app.running {
+        let frame_start = Instant::now();
+
+        // 0. Reload config.json: theme and notification rules apply this frame;
+        // pairs and the provider stay as started (they need a resubscribe)
+        if app.needs_config_reload {
+            app.needs_config_reload = false;
+            match Config::try_load(CONFIG_FILE) {
+                Ok(reloaded) => {
+                    base_theme = load_theme(&reloaded);
+                    adjusted_theme = base_theme.adjusted(app.gamma, app.brightness);
+                    let notif_config = reloaded.notifications_config();
+                    app.notification_manager.apply_config(
+                        notif_config.rules,
+                        notif_config.cooldown_secs,
+                        notif_config.max_log_entries,
+                    );
+                    app.notification_manager
+                        .set_coalesce_window(notif_config.coalesce_window_secs);
+                    app.keymap = Keymap::new(&reloaded.keybindings());
+                    println!("Config reloaded");
+                    app.show_toast("Config reloaded");
+                }
+                Err(e) => {
+                    eprintln!("Config reload failed, keeping the current config: {}", e);
+                    app.show_toast(format!("Config reload failed: {}", e));
+                }
+            }
+        }
+
+        if color_adjustment != (app.gamma, app.brightness) {
+            color_adjustment = (app.gamma, app.brightness);
+            adjusted_theme = base_theme.adjusted(app.gamma, app.brightness);
+        }
+        let theme = &adjusted_theme;
+
         // 1. Poll tokio tasks (non-blocking)
         rt.block_on(async { tokio::task::yield_now().await });
 
         // 2. Handle candle refresh requests
         if app.needs_candle_refresh {
             app.needs_candle_refresh = false;
-            let granularity = app.time_window.granularity();
+            let granularity = app.time_window().granularity();
             let interval = granularity_to_interval(granularity);
 
             // Send interval change to WebSocket (for kline stream updates)
@@ -285,16 +664,41 @@ fn run_gl_loop(
 
             // Also fetch historical data for the new interval
             for pair in pairs {
-                let _ = rt.block_on(candle_req_tx.send((pair.clone(), granularity)));
+                let _ = rt.block_on(candle_req_tx.send(CandleRequest {
+                    pair: pair.clone(),
+                    granularity,
+                    tail: None,
+                }));
             }
+            next_close_refresh = next_candle_close(granularity, unix_now() as i64);
         }
 
-        // 2.5. Handle news refresh requests
+        // 2.1. Candle close: fetch only the latest few candles and merge them
+        if chart_config.close_refresh && unix_now() as i64 >= next_close_refresh {
+            let granularity = app.time_window().granularity();
+            for pair in pairs {
+                // Non-blocking: skip this refresh if the fetcher is backed up
+                let _ = candle_req_tx.try_send(CandleRequest {
+                    pair: pair.clone(),
+                    granularity,
+                    tail: Some(chart_config.close_refresh_candles),
+                });
+            }
+            next_close_refresh = next_candle_close(granularity, unix_now() as i64);
+        }
+
+        // 2.5. Handle news refresh requests (manual, on focus, or the
+        // auto-refresh interval); one fetch at a time
+        if app.news_refresh_in() == Some(Duration::ZERO) && !app.news_loading && has_api_keys() {
+            app.needs_news_refresh = true;
+        }
         if app.needs_news_refresh {
             app.needs_news_refresh = false;
-            app.news_loading = true;
-            let selected_symbols = app.selected_symbols();
-            let _ = rt.block_on(news_req_tx.send(selected_symbols));
+            if !app.news_loading {
+                app.news_loading = true;
+                let selected_symbols = app.selected_symbols();
+                let _ = rt.block_on(news_req_tx.send(selected_symbols));
+            }
         }
 
         // 2.6. Process news updates (non-blocking)
@@ -330,40 +734,163 @@ fn run_gl_loop(
             app.handle_update(PriceUpdate::MarginPositions { account });
         }
 
-        // 3. Process price updates (non-blocking)
-        while let Ok(update) = price_rx.try_recv() {
+        // 2.9. Export a snapshot of the current view's data
+        if app.needs_snapshot_export {
+            app.needs_snapshot_export = false;
+            match export::write_snapshot(app) {
+                Ok(path) => app.show_toast(format!("Exported {}", path.display())),
+                Err(e) => app.show_toast(format!("Export failed: {}", e)),
+            }
+        }
+
+        // 2.10. Copy the focused coin's price/symbol to the clipboard
+        if app.needs_clipboard_copy {
+            app.needs_clipboard_copy = false;
+            // Indicator focus ids look like "ind_{symbol}_{prefix}_{period}"
+            let focused_symbol = focus_manager
+                .current()
+                .and_then(|id| id.strip_prefix("ind_"))
+                .and_then(|rest| rest.split('_').next());
+            if let Some(coin) = app.focused_coin(focused_symbol) {
+                let text = clipboard::coin_text(coin, clipboard_config.content);
+                let message = match clipboard::copy(&text, &clipboard_config.fallback_file) {
+                    Ok(CopyTarget::Clipboard(tool)) => format!("Copied {} ({})", text, tool),
+                    Ok(CopyTarget::File(path)) => {
+                        format!("No clipboard, wrote {} to {}", text, path.display())
+                    }
+                    Err(e) => format!("Copy failed: {}", e),
+                };
+                app.show_toast(message);
+            }
+        }
+
+        // 2.11. Persist rules added from the UI (price alerts)
+        if app.needs_rules_save {
+            app.needs_rules_save = false;
+            persistence::save_added_rules(app.notification_manager.added_rules(), &log_file);
+        }
+
+        // 2.12. Rewrite the notification log after entries were cleared or dismissed
+        if app.needs_notifications_save {
+            app.needs_notifications_save = false;
+            persistence::save_notifications(&app.notification_manager.notifications, &log_file);
+        }
+
+        // 3. Process price updates (non-blocking, capped per frame)
+        for update in api::drain_updates(price_rx, max_updates_per_frame) {
             app.handle_update(update);
         }
 
         // 3.5. Play ticker tones for price changes (checked coins only, if not muted)
         if ticker_tones_config.enabled && !app.ticker_muted {
-            notifications::process_ticker_tones(&app.coins, &app.checked, &ticker_tones_config);
+            notifications::process_ticker_tones(
+                &mut ticker_tones,
+                &app.coins,
+                &app.checked,
+                &ticker_tones_config,
+            );
+        }
+
+        // 3.6. Connect/disconnect cue (debounced, respects audio setting and mute)
+        if audio_enabled && !app.ticker_muted {
+            notifications::process_connection_sound(
+                app.connection_status,
+                &connection_sounds_config,
+            );
         }
 
         // 4. Check notification rules after price updates (checked coins only)
         if notifications_enabled {
-            let new_notifications = app
-                .notification_manager
-                .check_rules(&app.coins, &app.checked);
+            let new_notifications =
+                app.notification_manager
+                    .check_rules(&app.coins, &app.checked, unix_now());
             if !new_notifications.is_empty() {
+                screen_flash.trigger(&new_notifications);
                 // Play audio for each new notification
                 if audio_enabled {
                     for notif in &new_notifications {
                         audio::play_alert(notif.sound.as_deref());
                     }
                 }
+                if desktop_notifications {
+                    for notif in &new_notifications {
+                        desktop::send(notif);
+                    }
+                }
+                // Forward to the webhook (spawned tasks, driven by the per-frame yield)
+                if let Some(url) = &webhook_url {
+                    for notif in &new_notifications {
+                        webhook::post(rt, url, notif);
+                    }
+                }
                 // Save updated notifications to log file
                 persistence::save_notifications(&app.notification_manager.notifications, &log_file);
+                persistence::save_cooldowns(app.notification_manager.cooldowns(), &log_file);
             }
         }
 
         // 5. Handle keyboard input (evdev-based)
         handle_gl_events(keyboard, app);
+        // Hit-tested against the previous frame's layout (what is on screen)
+        handle_pointer_events(pointer, &mut pointer_state, focus_manager, app);
+
+        // 5.1. Ease smooth scrolls toward the offsets input just set
+        app.update_scroll(frame_start - prev_frame_start);
+        prev_frame_start = frame_start;
 
         // 6. Build layout tree
         let mut tree = LayoutTree::new();
         let view_result = build_current_view(&mut tree, app, theme, width as f32, height as f32);
         tree.compute_with_text(view_result.root, width as f32, height as f32, atlas);
+        if let Some(overlay) = view_result.overlay {
+            tree.compute_with_text(overlay, width as f32, height as f32, atlas);
+        }
+
+        // 6.1. Crossfade: hold on to the outgoing view's last frame until the fade ends
+        let fade = app.view_transition_progress();
+        if let Some((prev_tree, prev_root, prev_view)) = last_frame.take() {
+            if fade.is_some() && prev_view != app.view {
+                fade_from = Some((prev_tree, prev_root));
+            }
+        }
+        if fade.is_none() {
+            fade_from = None;
+        }
+
+        // 6.2. Bound the news article scroll by its wrapped length at this size
+        if let Some(node) = tree.find_node_by_id(view_result.root, NEWS_CONTENT_ID) {
+            if let Some((total, visible)) = text_box_line_counts(&tree, node, atlas) {
+                app.set_news_content_lines(total, visible);
+            }
+        }
+
+        // 6.5. Sync keyboard focus with the focusable panels of this frame,
+        // keeping their bounds and the charts' for pointer hit-testing
+        let focus_rects = tree.focusable_rects(view_result.focus_root());
+        let focusable: Vec<String> = focus_rects.iter().map(|(id, _)| id.clone()).collect();
+        focus_manager.set_focus_rects(focus_rects);
+        pointer_state.chart_rects = tree
+            .find_panels_by_prefix(view_result.root, CHART_PANEL_PREFIX)
+            .into_iter()
+            .map(|(_, x, y, w, h)| Rect::new(x, y, w, h))
+            .collect();
+        let focused_id = app.focus_index.and_then(|i| focusable.get(i).cloned());
+        if focused_id.is_none() {
+            app.focus_index = None;
+        }
+        focus_manager.set_focus_order(focusable);
+        match focused_id {
+            Some(ref id) => {
+                focus_manager.set_focus(id);
+                if focused_since.as_ref().map(|(prev, _)| prev) != Some(id) {
+                    focused_since = Some((id.clone(), Instant::now()));
+                }
+            }
+            None => {
+                focus_manager.clear();
+                focused_since = None;
+            }
+        }
 
         // 7. Clear screen
         unsafe {
@@ -376,7 +903,27 @@ fn run_gl_loop(
             display.gl.clear(glow::COLOR_BUFFER_BIT);
         }
 
-        // 8. Render layout tree
+        // 8. Render layout tree; during a crossfade the outgoing view fades out
+        // underneath the incoming one (charts snap, they're drawn separately)
+        if let (Some(progress), Some((from_tree, from_root))) = (fade, &fade_from) {
+            rect_renderer.set_alpha(1.0 - progress);
+            text_renderer.set_alpha(1.0 - progress);
+            render(
+                &display.gl,
+                from_tree,
+                *from_root,
+                rect_renderer,
+                text_renderer,
+                atlas,
+                scissor_stack,
+                focus_manager,
+                display.safe_mode,
+                width,
+                height,
+            );
+            rect_renderer.set_alpha(progress);
+            text_renderer.set_alpha(progress);
+        }
         render(
             &display.gl,
             &tree,
@@ -386,9 +933,12 @@ fn run_gl_loop(
             atlas,
             scissor_stack,
             focus_manager,
+            display.safe_mode,
             width,
             height,
         );
+        rect_renderer.set_alpha(1.0);
+        text_renderer.set_alpha(1.0);
 
         // 9. Chart rendering
         if !view_result.chart_areas.is_empty() {
@@ -425,29 +975,49 @@ fn run_gl_loop(
                                 .scissor(*x as i32, scissor_y, *w as i32, *h as i32);
                         }
 
+                        let options = app.chart_options(coin);
+
                         chart_renderer.begin();
-                        match app.chart_type {
+                        let mut labels = match app.rendered_chart_type() {
                             ChartType::Candlestick => render_candlestick_chart(
                                 chart_renderer,
                                 &coin.candles,
                                 &coin.chart_indicators, // Use cached indicators
-                                app.candle_scroll_offset,
-                                app.visible_candles,
-                                0.05, // 5% price margin
+                                &options,
                                 rect,
                                 theme,
                             ),
                             ChartType::Polygonal => render_polygonal_chart(
                                 chart_renderer,
                                 &coin.candles,
-                                app.candle_scroll_offset,
-                                app.visible_candles,
-                                0.05, // 5% price margin
+                                &options,
                                 rect,
                                 theme,
                             ),
-                        }
+                        };
                         chart_renderer.end(&display.gl, width, height);
+                        labels.extend(volume_readout(
+                            &coin.candles,
+                            &options,
+                            &app.volume_config,
+                            &rect,
+                        ));
+
+                        // Chart text labels (still clipped to the chart bounds)
+                        if !labels.is_empty() {
+                            text_renderer.begin();
+                            for label in &labels {
+                                text_renderer.draw_text(
+                                    atlas,
+                                    &label.text,
+                                    label.x,
+                                    label.y,
+                                    theme.font_small,
+                                    theme.foreground_muted,
+                                );
+                            }
+                            text_renderer.end(&display.gl, atlas, width, height);
+                        }
 
                         unsafe {
                             display.gl.disable(glow::SCISSOR_TEST);
@@ -457,17 +1027,200 @@ fn run_gl_loop(
             }
         }
 
-        // 10. Swap buffers (vsync)
+        // 9.5. Overview line sparklines, clipped to the table's visible rows
+        if let Some(table) = tree.find_panel_by_id(view_result.root, COIN_TABLE_ID) {
+            let cells = tree.find_panels_by_prefix(view_result.root, SPARKLINE_PANEL_PREFIX);
+            if !cells.is_empty() {
+                unsafe {
+                    display.gl.enable(glow::SCISSOR_TEST);
+                    let scissor_y = height as i32 - (table.y as i32 + table.height as i32);
+                    display.gl.scissor(
+                        table.x as i32,
+                        scissor_y,
+                        table.width as i32,
+                        table.height as i32,
+                    );
+                }
+
+                chart_renderer.begin();
+                for (id, x, y, w, h) in &cells {
+                    let coin = id
+                        .strip_prefix(SPARKLINE_PANEL_PREFIX)
+                        .and_then(|index| index.parse::<usize>().ok())
+                        .and_then(|index| app.coins.get(index));
+                    if let Some(coin) = coin {
+                        let color = ValueColor::Sign.color(coin.change_24h, theme);
+                        let rect = PixelRect::new(*x, *y, *w, *h);
+                        render_sparkline_chart(chart_renderer, &coin.sparkline, rect, color);
+                    }
+                }
+                chart_renderer.end(&display.gl, width, height);
+
+                unsafe {
+                    display.gl.disable(glow::SCISSOR_TEST);
+                }
+            }
+        }
+
+        // 9.9. Modal dialog (own layout root, drawn over the view and charts)
+        if let Some(overlay) = view_result.overlay {
+            render(
+                &display.gl,
+                &tree,
+                overlay,
+                rect_renderer,
+                text_renderer,
+                atlas,
+                scissor_stack,
+                focus_manager,
+                display.safe_mode,
+                width,
+                height,
+            );
+        }
+
+        // 10. Tooltip overlay for the focused panel (drawn last, on top)
+        if let Some((ref id, since)) = focused_since {
+            if since.elapsed() >= TOOLTIP_DELAY {
+                let text = tree.find_tooltip(view_result.focus_root(), id);
+                let anchor = tree.find_panel_by_id(view_result.focus_root(), id);
+                if let (Some(text), Some(anchor)) = (text, anchor) {
+                    let tooltip = Tooltip {
+                        text: &text,
+                        anchor,
+                        background: theme.background_panel,
+                        border: theme.border_focus,
+                        color: theme.foreground,
+                        scale: theme.font_small,
+                    };
+                    render_tooltip(
+                        &display.gl,
+                        &tooltip,
+                        rect_renderer,
+                        text_renderer,
+                        atlas,
+                        width,
+                        height,
+                    );
+                }
+            }
+        }
+
+        // 10.5. Status toast (bottom-left, over the footer); the price alert
+        // prompt takes its place while typing
+        let prompt = app.price_entry_prompt();
+        if let Some(message) = prompt.as_deref().or(app.active_toast()) {
+            let margin = theme.panel_gap * 2.0;
+            let toast = Tooltip {
+                text: message,
+                anchor: Rect::new(margin, height as f32, 0.0, 0.0),
+                background: theme.background_panel,
+                border: theme.accent,
+                color: theme.foreground,
+                scale: theme.font_normal,
+            };
+            render_tooltip(
+                &display.gl,
+                &toast,
+                rect_renderer,
+                text_renderer,
+                atlas,
+                width,
+                height,
+            );
+        }
+
+        // 10.6. Frame timing overlay (bottom-right)
+        if app.show_fps {
+            let label = frame_timer.label();
+            let fps = Tooltip {
+                text: &label,
+                anchor: Rect::new(width as f32, height as f32, 0.0, 0.0),
+                background: theme.background_panel,
+                border: theme.border,
+                color: theme.foreground_muted,
+                scale: theme.font_small,
+            };
+            render_tooltip(
+                &display.gl,
+                &fps,
+                rect_renderer,
+                text_renderer,
+                atlas,
+                width,
+                height,
+            );
+        }
+
+        // 11. Critical alert flash (full-screen overlay fading out)
+        if let Some(alpha) = screen_flash.alpha() {
+            let [r, g, b, _] = theme.negative;
+            rect_renderer.begin();
+            rect_renderer.draw_rect(
+                &Rect::new(0.0, 0.0, width as f32, height as f32),
+                [r, g, b, alpha],
+            );
+            rect_renderer.end(&display.gl, width, height);
+        }
+
+        // 11.5. Screenshot mode: capture this frame once startup data had time to load, then exit
+        if let Some(path) = screenshot.take_if(|_| started.elapsed() >= SCREENSHOT_DELAY) {
+            display.request_screenshot(path);
+            app.quit();
+        }
+
+        // 12. Swap buffers (vsync)
         display.swap_buffers()?;
+        last_frame = Some((tree, view_result.root, app.view));
+
+        // 13. Frame rate cap: wait out the rest of the frame budget, unless price
+        // updates are already queued (handle them next frame without delay).
+        // Sleeping inside the runtime keeps the current-thread network tasks running.
+        if let Some(budget) = frame_budget {
+            if price_rx.is_empty() {
+                if let Some(remaining) = budget.checked_sub(frame_start.elapsed()) {
+                    rt.block_on(tokio::time::sleep(remaining));
+                }
+            }
+        }
+        app.dropped_glyphs = text_renderer.dropped_glyphs();
+        frame_timer.tick();
     }
 
     Ok(())
 }
 
+/// Theme named in config (the built-in default if unset or not found)
+fn load_theme(config: &Config) -> GlTheme {
+    match config.theme_config() {
+        Some(theme_config) => GlTheme::from_config(&theme_config),
+        None => GlTheme::default(),
+    }
+}
+
+/// Seconds to wait past a candle boundary so the exchange has closed the candle
+const CLOSE_REFRESH_DELAY_SECS: i64 = 2;
+
+/// Unix time of the next candle-close refresh after `now` for the given interval
+fn next_candle_close(granularity: u32, now: i64) -> i64 {
+    let offset = candle_open_offset(granularity);
+    let granularity = granularity.max(1) as i64;
+    ((now - offset) / granularity + 1) * granularity + offset + CLOSE_REFRESH_DELAY_SECS
+}
+
 /// Result of building a view, includes layout root and optional chart areas
 struct ViewResult {
     root: taffy::NodeId,
     chart_areas: Vec<views::ChartArea>,
+    /// Separate root for an open modal or the search overlay, rendered above the charts
+    overlay: Option<taffy::NodeId>,
+}
+
+impl ViewResult {
+    /// Root whose panels receive keyboard focus (the modal traps focus while open)
+    fn focus_root(&self) -> taffy::NodeId {
+        self.overlay.unwrap_or(self.root)
+    }
 }
 
 fn build_current_view(
@@ -477,35 +1230,53 @@ fn build_current_view(
     width: f32,
     height: f32,
 ) -> ViewResult {
-    use crate::app::View;
+    use crate::app::Modal;
     use crate::views::{
-        build_details_view, build_news_view, build_notifications_view, build_overview_view,
-        build_positions_view,
+        build_details_view, build_diagnostics_view, build_help_modal, build_news_view,
+        build_notifications_view, build_overview_view, build_positions_view, build_search_overlay,
+    };
+
+    let overlay = match app.modal {
+        Some(Modal::Help) => Some(build_help_modal(&app.keymap, theme).build(tree)),
+        None => app
+            .search
+            .is_some()
+            .then(|| build_search_overlay(app, theme, width).build(tree)),
     };
 
     match app.view {
         View::Overview => ViewResult {
             root: build_overview_view(app, theme, width, height).build(tree),
             chart_areas: vec![],
+            overlay,
         },
         View::Details => {
             let (panel, chart_areas) = build_details_view(app, theme, width, height);
             ViewResult {
                 root: panel.build(tree),
                 chart_areas,
+                overlay,
             }
         }
         View::Notifications => ViewResult {
             root: build_notifications_view(app, theme, width, height).build(tree),
             chart_areas: vec![],
+            overlay,
         },
         View::News => ViewResult {
             root: build_news_view(app, theme, width, height).build(tree),
             chart_areas: vec![],
+            overlay,
         },
         View::Positions => ViewResult {
             root: build_positions_view(app, theme, width, height).build(tree),
             chart_areas: vec![],
+            overlay,
+        },
+        View::Diagnostics => ViewResult {
+            root: build_diagnostics_view(app, theme, width, height).build(tree),
+            chart_areas: vec![],
+            overlay,
         },
     }
 }
@@ -1,12 +1,16 @@
+use crate::api::provider::pair_symbol;
 use crate::api::Candle;
-use crate::widgets::indicators::CandleIndicators;
-use std::collections::VecDeque;
+use crate::widgets::indicators::{sma, vwap, CandleIndicators};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
 
 const CHANGE_HISTORY_SIZE: usize = 120; // Number of samples to average
 
+/// Default cap on candles retained per coin (see `ChartConfig::max_candles`)
+pub const DEFAULT_MAX_CANDLES: usize = 1500;
+
 pub struct CoinData {
     pub symbol: String,
-    #[allow(dead_code)]
     pub name: String,
     pub price: f64,
     pub prev_price: f64,               // Previous price for change detection
@@ -21,6 +25,10 @@ pub struct CoinData {
     pub candles: Vec<Candle>,
     /// Cached per-candle indicators for chart rendering (RSI/EMA arrays)
     pub chart_indicators: CandleIndicators,
+    /// Pinned chart Y-axis range (min, max); autoscale when None
+    pub locked_price_range: Option<(f64, f64)>,
+    /// When the last ticker update arrived (drives the live-price pulse)
+    pub last_tick_at: Option<Instant>,
 }
 
 pub struct IndicatorData {
@@ -36,6 +44,11 @@ pub struct IndicatorData {
     pub macd_line: f64,
     pub macd_signal: f64,
     pub macd_histogram: f64,
+    // SMA values (0.0 = not enough candles)
+    pub sma_50: f64,
+    pub sma_200: f64,
+    // Volume-weighted average price over the loaded candles (0.0 = not calculated)
+    pub vwap: f64,
 }
 
 impl Default for IndicatorData {
@@ -50,6 +63,9 @@ impl Default for IndicatorData {
             macd_line: 0.0,
             macd_signal: 0.0,
             macd_histogram: 0.0,
+            sma_50: 0.0,
+            sma_200: 0.0,
+            vwap: 0.0,
         }
     }
 }
@@ -71,6 +87,20 @@ impl CoinData {
             sparkline: vec![50; 20],
             candles: Vec::new(),
             chart_indicators: CandleIndicators::default(),
+            locked_price_range: None,
+            last_tick_at: None,
+        }
+    }
+
+    /// Live-tick pulse strength: 1.0 right after a ticker update, decaying linearly to 0.0
+    /// over `duration` (always 0.0 when the pulse is disabled)
+    pub fn tick_pulse(&self, duration: Option<Duration>) -> f32 {
+        match (duration, self.last_tick_at) {
+            (Some(duration), Some(at)) if !duration.is_zero() => {
+                let t = at.elapsed().as_secs_f32() / duration.as_secs_f32();
+                (1.0 - t).max(0.0)
+            }
+            _ => 0.0,
         }
     }
 
@@ -148,8 +178,9 @@ impl CoinData {
     }
 
     /// Set candles from historical data and recalculate indicators
-    pub fn set_candles(&mut self, candles: Vec<Candle>) {
+    pub fn set_candles(&mut self, candles: Vec<Candle>, max_candles: usize) {
         self.candles = candles;
+        self.trim_candles(max_candles);
         self.recalculate_indicators();
         self.update_sparkline();
 
@@ -161,11 +192,32 @@ impl CoinData {
         }
     }
 
+    /// Merge a freshly fetched tail of candles into the series
+    ///
+    /// Existing candles from the first fetched timestamp onward are replaced,
+    /// so overlapping periods are deduped and partially-formed candles updated.
+    pub fn merge_candles(&mut self, candles: Vec<Candle>, max_candles: usize) {
+        let first_time = match candles.first() {
+            Some(c) => c.time,
+            None => return,
+        };
+
+        let split = self.candles.partition_point(|c| c.time < first_time);
+        self.candles.truncate(split);
+        self.candles.extend(candles);
+        self.trim_candles(max_candles);
+
+        if let Some(last) = self.candles.last() {
+            self.price = last.close;
+        }
+
+        self.recalculate_indicators();
+        self.update_sparkline();
+    }
+
     /// Update candles from real-time kline WebSocket data
     /// Creates new candles when timestamp advances (period change)
-    pub fn update_candle(&mut self, candle: Candle, _is_closed: bool) {
-        const MAX_CANDLES: usize = 500;
-
+    pub fn update_candle(&mut self, candle: Candle, _is_closed: bool, max_candles: usize) {
         println!("[DEBUG] update_candle: time={}, candles.len()={}", candle.time, self.candles.len());
 
         // Find or create the candle based on timestamp
@@ -180,10 +232,7 @@ impl CoinData {
                 self.candles.push(candle);
 
                 // Trim old candles if exceeding max
-                if self.candles.len() > MAX_CANDLES {
-                    let trim_count = self.candles.len() - MAX_CANDLES;
-                    self.candles.drain(0..trim_count);
-                }
+                self.trim_candles(max_candles);
             } else {
                 // Ignore old data (candle.time < last.time)
                 println!("[WARN] Received old candle data, ignoring");
@@ -204,6 +253,14 @@ impl CoinData {
         self.update_sparkline();
     }
 
+    /// Drop the oldest candles so at most `max_candles` remain (order preserved)
+    fn trim_candles(&mut self, max_candles: usize) {
+        if max_candles > 0 && self.candles.len() > max_candles {
+            let trim_count = self.candles.len() - max_candles;
+            self.candles.drain(0..trim_count);
+        }
+    }
+
     fn recalculate_indicators(&mut self) {
         // Extract close prices from candles
         let closes: Vec<f64> = self.candles.iter().map(|c| c.close).collect();
@@ -222,20 +279,22 @@ impl CoinData {
         self.indicators.rsi_12 = Self::calculate_rsi(&closes, 12);
         self.indicators.rsi_24 = Self::calculate_rsi(&closes, 24);
 
-        // Calculate MACD (12, 26, 9)
-        let ema_12 = Self::calculate_ema(&closes, 12);
-        let ema_26 = Self::calculate_ema(&closes, 26);
-        self.indicators.macd_line = ema_12 - ema_26;
+        // Calculate SMAs (50, 200)
+        self.indicators.sma_50 = sma(&closes, 50);
+        self.indicators.sma_200 = sma(&closes, 200);
 
-        // Calculate MACD signal line (9-period EMA of MACD line)
-        // For proper calculation, we'd need MACD history, but approximate with smoothing
-        let macd_smoothing = 2.0 / 10.0;
-        self.indicators.macd_signal = self.indicators.macd_signal * (1.0 - macd_smoothing)
-            + self.indicators.macd_line * macd_smoothing;
-        self.indicators.macd_histogram = self.indicators.macd_line - self.indicators.macd_signal;
+        // Calculate VWAP over all loaded candles
+        self.indicators.vwap = vwap(&self.candles);
 
         // Update per-candle chart indicators (cached for rendering)
         self.chart_indicators = CandleIndicators::from_candles(&self.candles, 14);
+
+        // MACD (12, 26, 9) from the latest entry of the per-candle series
+        if let Some(&(line, signal, histogram)) = self.chart_indicators.macd.last() {
+            self.indicators.macd_line = line;
+            self.indicators.macd_signal = signal;
+            self.indicators.macd_histogram = histogram;
+        }
     }
 
     fn update_sparkline(&mut self) {
@@ -341,8 +400,9 @@ impl CoinData {
     }
 }
 
-pub fn generate_mock_coins() -> Vec<CoinData> {
-    vec![
+/// Sample coins for mock mode, with display names from `names` taking precedence
+pub fn generate_mock_coins(names: &HashMap<String, String>) -> Vec<CoinData> {
+    let mut coins = vec![
         CoinData {
             symbol: "BTC".to_string(),
             name: "Bitcoin".to_string(),
@@ -364,12 +424,17 @@ pub fn generate_mock_coins() -> Vec<CoinData> {
                 macd_line: 12.4,
                 macd_signal: 8.2,
                 macd_histogram: 4.2,
+                sma_50: 0.0,
+                sma_200: 0.0,
+                vwap: 67210.0,
             },
             sparkline: vec![
                 65, 66, 64, 67, 68, 70, 69, 71, 72, 70, 68, 69, 71, 73, 72, 70, 68, 69, 70, 72,
             ],
             candles: Vec::new(),
             chart_indicators: CandleIndicators::default(),
+            locked_price_range: None,
+            last_tick_at: None,
         },
         CoinData {
             symbol: "ETH".to_string(),
@@ -392,12 +457,17 @@ pub fn generate_mock_coins() -> Vec<CoinData> {
                 macd_line: -5.1,
                 macd_signal: -3.2,
                 macd_histogram: -1.9,
+                sma_50: 0.0,
+                sma_200: 0.0,
+                vwap: 3498.2,
             },
             sparkline: vec![
                 72, 70, 68, 66, 65, 64, 62, 63, 65, 67, 69, 71, 73, 72, 70, 68, 66, 64, 65, 67,
             ],
             candles: Vec::new(),
             chart_indicators: CandleIndicators::default(),
+            locked_price_range: None,
+            last_tick_at: None,
         },
         CoinData {
             symbol: "SOL".to_string(),
@@ -420,12 +490,17 @@ pub fn generate_mock_coins() -> Vec<CoinData> {
                 macd_line: 3.2,
                 macd_signal: 2.1,
                 macd_histogram: 1.1,
+                sma_50: 0.0,
+                sma_200: 0.0,
+                vwap: 141.05,
             },
             sparkline: vec![
                 55, 58, 60, 63, 65, 68, 70, 72, 75, 73, 71, 74, 76, 78, 80, 82, 80, 78, 76, 75,
             ],
             candles: Vec::new(),
             chart_indicators: CandleIndicators::default(),
+            locked_price_range: None,
+            last_tick_at: None,
         },
         CoinData {
             symbol: "XRP".to_string(),
@@ -448,12 +523,17 @@ pub fn generate_mock_coins() -> Vec<CoinData> {
                 macd_line: 0.005,
                 macd_signal: 0.003,
                 macd_histogram: 0.002,
+                sma_50: 0.0,
+                sma_200: 0.0,
+                vwap: 0.5251,
             },
             sparkline: vec![
                 50, 51, 52, 51, 50, 49, 50, 51, 52, 53, 52, 51, 50, 51, 52, 53, 54, 53, 52, 51,
             ],
             candles: Vec::new(),
             chart_indicators: CandleIndicators::default(),
+            locked_price_range: None,
+            last_tick_at: None,
         },
         CoinData {
             symbol: "ADA".to_string(),
@@ -476,35 +556,52 @@ pub fn generate_mock_coins() -> Vec<CoinData> {
                 macd_line: -0.002,
                 macd_signal: -0.001,
                 macd_histogram: -0.001,
+                sma_50: 0.0,
+                sma_200: 0.0,
+                vwap: 0.4498,
             },
             sparkline: vec![
                 46, 45, 44, 45, 46, 45, 44, 43, 44, 45, 46, 45, 44, 45, 46, 47, 46, 45, 44, 45,
             ],
             candles: Vec::new(),
             chart_indicators: CandleIndicators::default(),
+            locked_price_range: None,
+            last_tick_at: None,
         },
-    ]
+    ];
+
+    for coin in &mut coins {
+        if let Some(name) = names.get(&coin.symbol) {
+            coin.name = name.clone();
+        }
+    }
+    coins
 }
 
-/// Create coins from pairs list
-/// Supports both formats: "BTC-USD" (Coinbase) and "BTCUSDT" (Binance)
-pub fn coins_from_pairs(pairs: &[String]) -> Vec<CoinData> {
+/// Create coins from pairs list, naming them from `names` before the built-in map
+/// Supports both formats: "BTC-USD" (Coinbase) and "BTCUSDT" (Binance);
+/// pairs from several providers are merged into one coin per symbol
+pub fn coins_from_pairs(pairs: &[String], names: &HashMap<String, String>) -> Vec<CoinData> {
+    let mut seen = HashSet::new();
     pairs
         .iter()
-        .map(|pair| {
+        .filter_map(|pair| {
             // Handle both "BTC-USD" and "BTCUSDT" formats
-            let symbol = if pair.contains('-') {
-                pair.split('-').next().unwrap_or(pair)
-            } else {
-                pair.trim_end_matches("USDT")
-            };
-            let name = symbol_to_name(symbol);
-            CoinData::new(symbol, &name)
+            let symbol = pair_symbol(pair);
+            // The same coin listed on several providers shares one row
+            if !seen.insert(symbol) {
+                return None;
+            }
+            let name = names
+                .get(symbol)
+                .cloned()
+                .unwrap_or_else(|| symbol_to_name(symbol));
+            Some(CoinData::new(symbol, &name))
         })
         .collect()
 }
 
-fn symbol_to_name(symbol: &str) -> String {
+pub fn symbol_to_name(symbol: &str) -> String {
     match symbol {
         "BTC" => "Bitcoin".to_string(),
         "ETH" => "Ethereum".to_string(),
@@ -519,3 +616,71 @@ fn symbol_to_name(symbol: &str) -> String {
         _ => symbol.to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candles(times: std::ops::Range<i64>) -> Vec<Candle> {
+        times
+            .map(|time| Candle {
+                time,
+                close: time as f64,
+                ..Candle::default()
+            })
+            .collect()
+    }
+
+    fn times(coin: &CoinData) -> Vec<i64> {
+        coin.candles.iter().map(|c| c.time).collect()
+    }
+
+    #[test]
+    fn set_candles_keeps_newest_within_cap() {
+        let mut coin = CoinData::new("BTC", "Bitcoin");
+        coin.set_candles(candles(0..10), 4);
+        assert_eq!(times(&coin), vec![6, 7, 8, 9]);
+
+        // 0 disables the cap
+        coin.set_candles(candles(0..10), 0);
+        assert_eq!(coin.candles.len(), 10);
+    }
+
+    #[test]
+    fn new_kline_period_trims_oldest_in_order() {
+        let mut coin = CoinData::new("BTC", "Bitcoin");
+        coin.set_candles(candles(0..4), 4);
+        coin.update_candle(candles(4..5).remove(0), true, 4);
+        assert_eq!(times(&coin), vec![1, 2, 3, 4]);
+
+        // Same period updates in place without trimming
+        coin.update_candle(candles(4..5).remove(0), false, 4);
+        assert_eq!(times(&coin), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn merge_candles_replaces_overlap_and_trims() {
+        let mut coin = CoinData::new("BTC", "Bitcoin");
+        coin.set_candles(candles(0..5), 5);
+        coin.merge_candles(candles(3..7), 5);
+        assert_eq!(times(&coin), vec![2, 3, 4, 5, 6]);
+        assert_eq!(coin.price, 6.0);
+    }
+
+    #[test]
+    fn configured_names_take_precedence_over_built_in() {
+        let overrides = HashMap::from([
+            ("BTC".to_string(), "Digital Gold".to_string()),
+            ("PEPE".to_string(), "Pepe".to_string()),
+        ]);
+        let pairs = ["BTCUSDT", "ETH-USD", "PEPEUSDT", "WIFUSDT"].map(String::from);
+        let coins = coins_from_pairs(&pairs, &overrides);
+        let names: Vec<&str> = coins.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["Digital Gold", "Ethereum", "Pepe", "WIF"]);
+
+        let mock = generate_mock_coins(&overrides);
+        assert_eq!(mock[0].symbol, "BTC");
+        assert_eq!(mock[0].name, "Digital Gold");
+        assert_eq!(mock[1].name, "Ethereum");
+    }
+}
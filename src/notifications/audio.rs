@@ -7,7 +7,27 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 use std::process::{Child, Command};
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
+
+/// ALSA output device for aplay (`-D`), None = system default
+static DEVICE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Master volume and per-channel gains applied to generated sounds
+struct Levels {
+    volume: f32,
+    alert_gain: f32,
+    tone_gain: f32,
+}
+
+static LEVELS: Mutex<Levels> = Mutex::new(Levels {
+    volume: 1.0,
+    alert_gain: 1.0,
+    tone_gain: 1.0,
+});
+
+/// Full-scale sine amplitudes (8-bit samples around 128) at volume and gain 1.0
+const BEEP_AMPLITUDE: f32 = 60.0;
+const TONE_AMPLITUDE: f32 = 50.0;
 
 /// Separate audio channels to prevent interference
 /// Alert channel for notifications
@@ -107,6 +127,61 @@ fn find_sound_file(filename: &str) -> Option<PathBuf> {
     None
 }
 
+/// Set the ALSA device used for all playback (first call wins; call at startup)
+pub fn set_device(device: Option<String>) {
+    let _ = DEVICE.set(device.filter(|d| !d.is_empty()));
+}
+
+/// Spawn a quiet aplay for `path` on the configured device
+fn spawn_aplay(path: &str) -> std::io::Result<Child> {
+    let mut command = Command::new("aplay");
+    command.arg("-q");
+    if let Some(device) = DEVICE.get().and_then(|d| d.as_deref()) {
+        command.args(["-D", device]);
+    }
+    command.arg(path).spawn()
+}
+
+/// Set master volume (0.0-1.0) and the alert / ticker tone channel gains
+pub fn set_levels(volume: f32, alert_gain: f32, tone_gain: f32) {
+    if let Ok(mut levels) = LEVELS.lock() {
+        levels.volume = volume.clamp(0.0, 1.0);
+        levels.alert_gain = alert_gain.max(0.0);
+        levels.tone_gain = tone_gain.max(0.0);
+    }
+}
+
+/// Change the master volume by `delta` (clamped to 0.0-1.0), returning the new value.
+/// Tones pick it up on the next play; the fallback beep is regenerated here.
+pub fn adjust_volume(delta: f32) -> f32 {
+    let volume = match LEVELS.lock() {
+        Ok(mut levels) => {
+            // Round away float drift so repeated steps land on clean values
+            levels.volume = ((levels.volume + delta).clamp(0.0, 1.0) * 10.0).round() / 10.0;
+            levels.volume
+        }
+        Err(_) => return 1.0,
+    };
+    if std::path::Path::new(BEEP_PATH).exists() {
+        init_audio();
+    }
+    volume
+}
+
+/// Sine amplitude for a channel gain at the current master volume
+fn channel_amplitude(full_scale: f32, gain: impl Fn(&Levels) -> f32) -> f32 {
+    LEVELS
+        .lock()
+        .map(|levels| full_scale * levels.volume * gain(&levels))
+        .unwrap_or(full_scale)
+}
+
+/// One 8-bit unsigned sample, clamped so loud gains clip instead of wrapping around 128
+fn sine_sample(t: f32, frequency: f32, amplitude: f32) -> u8 {
+    let value = (t * frequency * 2.0 * std::f32::consts::PI).sin() * amplitude + 128.0;
+    value.round().clamp(0.0, 255.0) as u8
+}
+
 /// Initialize audio by writing the fallback beep WAV file
 pub fn init_audio() -> bool {
     // Generate the actual audio data (sine wave)
@@ -116,11 +191,11 @@ pub fn init_audio() -> bool {
     let sample_rate = 8000.0;
     let frequency = 440.0;
     let samples = 2048;
+    let amplitude = channel_amplitude(BEEP_AMPLITUDE, |levels| levels.alert_gain);
 
     for i in 0..samples {
         let t = i as f32 / sample_rate;
-        let sample = ((t * frequency * 2.0 * std::f32::consts::PI).sin() * 60.0 + 128.0) as u8;
-        wav_data.push(sample);
+        wav_data.push(sine_sample(t, frequency, amplitude));
     }
 
     // Update data chunk size in header
@@ -166,21 +241,22 @@ pub fn play_alert(sound: Option<&str>) {
     };
 
     // Use aplay with quiet mode (suppress output)
-    if let Ok(child) = Command::new("aplay").args(["-q", &sound_path]).spawn() {
+    if let Ok(child) = spawn_aplay(&sound_path) {
         if let Ok(mut guard) = ALERT_PROCESS.lock() {
             *guard = Some(child);
         }
     }
 }
 
-/// Generate a tone WAV file at the specified frequency and duration.
-/// Returns the path to the generated temporary file.
-pub fn generate_tone(frequency: f32, duration_ms: u32) -> Option<String> {
-    let sample_rate = 8000.0_f32;
-    let num_samples = ((sample_rate * duration_ms as f32) / 1000.0) as usize;
+/// Sample rate of generated tones (8-bit unsigned mono PCM)
+const TONE_SAMPLE_RATE: f32 = 8000.0;
 
-    // Build WAV header (copy template)
-    let mut wav_data = BEEP_WAV[..44].to_vec();
+/// Sine wave samples for a tone, with a short fade in/out to avoid clicks
+fn tone_samples(frequency: f32, duration_ms: u32) -> Vec<u8> {
+    let sample_rate = TONE_SAMPLE_RATE;
+    let num_samples = ((sample_rate * duration_ms as f32) / 1000.0) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+    let amplitude = channel_amplitude(TONE_AMPLITUDE, |levels| levels.tone_gain);
 
     // Generate sine wave samples with envelope to reduce clicks
     let fade_samples = (sample_rate * 0.005) as usize; // 5ms fade in/out
@@ -194,13 +270,23 @@ pub fn generate_tone(frequency: f32, duration_ms: u32) -> Option<String> {
         } else {
             1.0
         };
-        let sample =
-            ((t * frequency * 2.0 * std::f32::consts::PI).sin() * 50.0 * envelope + 128.0) as u8;
-        wav_data.push(sample);
+        samples.push(sine_sample(t, frequency, amplitude * envelope));
     }
 
+    samples
+}
+
+/// Generate a tone WAV file at the specified frequency and duration.
+/// Returns the path to the generated temporary file.
+pub fn generate_tone(frequency: f32, duration_ms: u32) -> Option<String> {
+    let samples = tone_samples(frequency, duration_ms);
+
+    // Build WAV header (copy template)
+    let mut wav_data = BEEP_WAV[..44].to_vec();
+    wav_data.extend_from_slice(&samples);
+
     // Update data chunk size in header
-    let data_size = num_samples as u32;
+    let data_size = samples.len() as u32;
     wav_data[40] = (data_size & 0xFF) as u8;
     wav_data[41] = ((data_size >> 8) & 0xFF) as u8;
     wav_data[42] = ((data_size >> 16) & 0xFF) as u8;
@@ -229,7 +315,7 @@ pub fn play_tone(frequency: f32, duration_ms: u32) {
     cleanup_tone_process();
 
     if let Some(path) = generate_tone(frequency, duration_ms) {
-        if let Ok(child) = Command::new("aplay").args(["-q", &path]).spawn() {
+        if let Ok(child) = spawn_aplay(&path) {
             if let Ok(mut guard) = TONE_PROCESS.lock() {
                 *guard = Some(child);
             }
@@ -0,0 +1,43 @@
+//! Connection sounds - audible cue when the live feed connects or drops
+//!
+//! Status changes are debounced: a sound plays only when the status differs
+//! from the last announced one and the debounce window has elapsed, so a
+//! reconnection storm produces at most one cue per window (and the final
+//! state is still announced once things settle).
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::audio;
+use crate::app::ConnectionStatus;
+use crate::config::ConnectionSoundsConfig;
+
+/// Last announced status and when it was announced
+static LAST_ANNOUNCED: Mutex<Option<(ConnectionStatus, Instant)>> = Mutex::new(None);
+
+/// Play the configured connect/disconnect sound if the status changed (call every frame)
+pub fn process_connection_sound(status: ConnectionStatus, config: &ConnectionSoundsConfig) {
+    let sound = match status {
+        ConnectionStatus::Connected => config.connect.as_deref(),
+        ConnectionStatus::Disconnected => config.disconnect.as_deref(),
+        // Transitional / mock states are never announced
+        ConnectionStatus::Connecting | ConnectionStatus::Mock => return,
+    };
+
+    let mut last = LAST_ANNOUNCED.lock().unwrap();
+    let debounce = Duration::from_secs(config.debounce_secs);
+
+    let should_play = match *last {
+        Some((last_status, last_time)) => last_status != status && last_time.elapsed() >= debounce,
+        None => true,
+    };
+
+    if !should_play {
+        return;
+    }
+
+    *last = Some((status, Instant::now()));
+    if let Some(sound) = sound {
+        audio::play_alert(Some(sound));
+    }
+}
@@ -0,0 +1,46 @@
+//! Desktop notifications through `notify-send` (libnotify)
+//!
+//! For sessions running under a desktop compositor. Spawning is fire-and-forget:
+//! a missing binary or a failed send is ignored, and the render loop never
+//! waits on the child.
+
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
+use super::notification::{Notification, Severity};
+
+/// Application name shown by the notification daemon
+const APP_NAME: &str = "crypto-dashboard";
+
+/// Spawned notify-send processes, reaped on later sends so none linger as zombies
+static PENDING: Mutex<Vec<Child>> = Mutex::new(Vec::new());
+
+/// Show `notif` as a desktop notification (non-blocking, failures ignored)
+pub fn send(notif: &Notification) {
+    let title = format!("{} alert", notif.symbol);
+    let spawned = Command::new("notify-send")
+        .args(["--app-name", APP_NAME, "--urgency", urgency(notif.severity)])
+        .arg(&title)
+        .arg(&notif.message)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    if let Ok(mut pending) = PENDING.lock() {
+        // Drop children that have exited; keep the ones still running
+        pending.retain_mut(|child| matches!(child.try_wait(), Ok(None)));
+        if let Ok(child) = spawned {
+            pending.push(child);
+        }
+    }
+}
+
+/// libnotify urgency level for a severity
+fn urgency(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "low",
+        Severity::Warning => "normal",
+        Severity::Critical => "critical",
+    }
+}
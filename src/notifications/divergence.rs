@@ -0,0 +1,142 @@
+//! RSI divergence detection over recent candles
+//!
+//! Bearish: price makes a higher swing high while RSI makes a lower high.
+//! Bullish: price makes a lower swing low while RSI makes a higher low.
+
+use crate::api::Candle;
+
+/// Bars on each side a swing point must exceed
+const SWING_STRENGTH: usize = 2;
+
+/// Divergence direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divergence {
+    Bullish,
+    Bearish,
+}
+
+/// A divergence between the last two swing points of the window
+#[derive(Debug, Clone, Copy)]
+pub struct DivergenceSignal {
+    pub kind: Divergence,
+    /// Time of the second (most recent) swing candle, used to notify once per swing
+    pub swing_time: i64,
+    pub rsi: f64,
+}
+
+/// Detect divergence between price swings and RSI over the last `lookback` candles
+///
+/// `rsi` is indexed the same as `candles`. When both kinds are present, the one
+/// confirmed by the most recent swing wins.
+pub fn detect_divergence(
+    candles: &[Candle],
+    rsi: &[f64],
+    lookback: usize,
+) -> Option<DivergenceSignal> {
+    let len = candles.len().min(rsi.len());
+    let start = len.saturating_sub(lookback);
+
+    let highs: Vec<f64> = candles[..len].iter().map(|c| c.high).collect();
+    let lows: Vec<f64> = candles[..len].iter().map(|c| c.low).collect();
+
+    let bearish = last_two(&swing_points(&highs, start, |a, b| a > b)).and_then(|(a, b)| {
+        (highs[b] > highs[a] && rsi[b] < rsi[a]).then_some((Divergence::Bearish, b))
+    });
+    let bullish = last_two(&swing_points(&lows, start, |a, b| a < b)).and_then(|(a, b)| {
+        (lows[b] < lows[a] && rsi[b] > rsi[a]).then_some((Divergence::Bullish, b))
+    });
+
+    let (kind, idx) = match (bearish, bullish) {
+        (Some(bear), Some(bull)) => {
+            if bear.1 >= bull.1 {
+                bear
+            } else {
+                bull
+            }
+        }
+        (Some(signal), None) | (None, Some(signal)) => signal,
+        (None, None) => return None,
+    };
+
+    Some(DivergenceSignal {
+        kind,
+        swing_time: candles[idx].time,
+        rsi: rsi[idx],
+    })
+}
+
+/// Indices (>= start) whose value beats the `SWING_STRENGTH` neighbours on each side
+fn swing_points(values: &[f64], start: usize, beats: impl Fn(f64, f64) -> bool) -> Vec<usize> {
+    if values.len() < SWING_STRENGTH * 2 + 1 {
+        return Vec::new();
+    }
+
+    (start.max(SWING_STRENGTH)..values.len() - SWING_STRENGTH)
+        .filter(|&i| {
+            (1..=SWING_STRENGTH)
+                .all(|d| beats(values[i], values[i - d]) && beats(values[i], values[i + d]))
+        })
+        .collect()
+}
+
+fn last_two(points: &[usize]) -> Option<(usize, usize)> {
+    match points {
+        [.., a, b] => Some((*a, *b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Candles with the given highs, lows one below, timestamped by index
+    fn candles_from_highs(highs: &[f64]) -> Vec<Candle> {
+        highs
+            .iter()
+            .enumerate()
+            .map(|(i, &high)| Candle {
+                time: i as i64,
+                high,
+                low: high - 1.0,
+                close: high,
+                ..Candle::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn higher_price_high_with_lower_rsi_high_is_bearish() {
+        let candles = candles_from_highs(&[1.0, 2.0, 5.0, 2.0, 1.0, 2.0, 6.0, 2.0, 1.0]);
+        let rsi = [50.0, 50.0, 70.0, 50.0, 50.0, 50.0, 60.0, 50.0, 50.0];
+        let signal = detect_divergence(&candles, &rsi, 9).unwrap();
+        assert_eq!(signal.kind, Divergence::Bearish);
+        assert_eq!(signal.swing_time, 6);
+        assert_eq!(signal.rsi, 60.0);
+    }
+
+    #[test]
+    fn lower_price_low_with_higher_rsi_low_is_bullish() {
+        let candles = candles_from_highs(&[6.0, 5.0, 2.0, 5.0, 6.0, 5.0, 1.5, 5.0, 6.0]);
+        let rsi = [50.0, 50.0, 30.0, 50.0, 50.0, 50.0, 35.0, 50.0, 50.0];
+        let signal = detect_divergence(&candles, &rsi, 9).unwrap();
+        assert_eq!(signal.kind, Divergence::Bullish);
+        assert_eq!(signal.swing_time, 6);
+    }
+
+    #[test]
+    fn confirming_rsi_is_not_divergence() {
+        // Higher high confirmed by a higher RSI high
+        let candles = candles_from_highs(&[1.0, 2.0, 5.0, 2.0, 1.0, 2.0, 6.0, 2.0, 1.0]);
+        let rsi = [50.0, 50.0, 70.0, 50.0, 50.0, 50.0, 80.0, 50.0, 50.0];
+        assert!(detect_divergence(&candles, &rsi, 9).is_none());
+    }
+
+    #[test]
+    fn swings_before_the_lookback_are_ignored() {
+        let candles = candles_from_highs(&[1.0, 2.0, 5.0, 2.0, 1.0, 2.0, 6.0, 2.0, 1.0]);
+        let rsi = [50.0, 50.0, 70.0, 50.0, 50.0, 50.0, 60.0, 50.0, 50.0];
+        assert!(detect_divergence(&candles, &rsi, 6).is_none());
+        assert!(detect_divergence(&candles[..4], &rsi[..4], 9).is_none());
+    }
+}
@@ -1,14 +1,27 @@
 //! Notification manager - handles rule checking and notification state
 
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::divergence::{detect_divergence, Divergence};
 use super::notification::{Notification, Severity};
 use super::rules::{CrossDir, NotificationRule, ThresholdDir};
 use crate::mock::CoinData;
+use crate::widgets::indicators::{bollinger_bands, sma, CandleIndicators};
 
 const DEFAULT_MAX_NOTIFICATIONS: usize = 100;
 
+/// RSI periods of history fed in ahead of the divergence window, so the Wilder
+/// smoothing has settled by the first candle searched for swings
+const DIVERGENCE_RSI_WARMUP_PERIODS: usize = 4;
+
+/// Where the price sits relative to the Bollinger Bands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BandPosition {
+    Above,
+    Inside,
+    Below,
+}
+
 /// Manages notification rules and triggered notifications
 pub struct NotificationManager {
     pub rules: Vec<NotificationRule>,
@@ -17,10 +30,18 @@ pub struct NotificationManager {
     pub selected_rule: usize,
     max_notifications: usize,
     cooldown_secs: u64,
+    /// A repeat of the newest entry's rule for the same coin within this many
+    /// seconds bumps its counter instead of appending (0 = never coalesce)
+    coalesce_window_secs: u64,
+    /// Rules added at runtime, kept as the tail of `rules`
+    added_rule_count: usize,
     // State tracking for crossing detection
     prev_prices: HashMap<String, f64>,
     prev_ema_positions: HashMap<(String, u8), bool>, // (symbol, period) -> was_above_ema
-    prev_rsi_positions: HashMap<(String, u8), bool>, // (symbol, period) -> was_above_threshold
+    prev_sma_positions: HashMap<(String, u8, u8), bool>, // (symbol, fast, slow) -> fast_was_above_slow
+    prev_rsi_positions: HashMap<(String, u8), bool>,     // (symbol, period) -> was_above_threshold
+    prev_divergence_swings: HashMap<(String, u8, usize), Option<i64>>, // (symbol, period, lookback) -> last seen swing time
+    prev_band_positions: HashMap<(String, u8, u64), BandPosition>, // (symbol, period, std_mult bits) -> price vs bands
     // Cooldown tracking: rule_key -> last_trigger_timestamp
     cooldowns: HashMap<String, u64>,
 }
@@ -39,13 +60,70 @@ impl NotificationManager {
                 DEFAULT_MAX_NOTIFICATIONS
             },
             cooldown_secs,
+            coalesce_window_secs: 0,
+            added_rule_count: 0,
             prev_prices: HashMap::new(),
             prev_ema_positions: HashMap::new(),
+            prev_sma_positions: HashMap::new(),
             prev_rsi_positions: HashMap::new(),
+            prev_divergence_swings: HashMap::new(),
+            prev_band_positions: HashMap::new(),
             cooldowns: HashMap::new(),
         }
     }
 
+    /// Swap in reloaded rules and limits, keeping the log, cooldowns and
+    /// crossing state so nothing re-fires just because of the reload
+    pub fn apply_config(
+        &mut self,
+        rules: Vec<NotificationRule>,
+        cooldown_secs: u64,
+        max_notifications: usize,
+    ) {
+        // Runtime-added rules are not part of config.json; carry them over
+        // unless the reloaded config now has an equivalent rule (same key)
+        let added: Vec<NotificationRule> = self
+            .rules
+            .split_off(self.rules.len() - self.added_rule_count)
+            .into_iter()
+            .filter(|added| rules.iter().all(|r| r.key() != added.key()))
+            .collect();
+        self.added_rule_count = added.len();
+        self.rules = rules;
+        self.rules.extend(added);
+        self.selected_rule = self.selected_rule.min(self.rules.len().saturating_sub(1));
+        self.cooldown_secs = cooldown_secs;
+        self.max_notifications = if max_notifications > 0 {
+            max_notifications
+        } else {
+            DEFAULT_MAX_NOTIFICATIONS
+        };
+        self.rotate_log();
+    }
+
+    /// Set the window for coalescing repeated alerts (0 = keep every entry)
+    pub fn set_coalesce_window(&mut self, secs: u64) {
+        self.coalesce_window_secs = secs;
+    }
+
+    /// Add a rule at runtime (e.g. a price alert typed in the UI)
+    ///
+    /// Returns false if an equivalent rule (same key) already exists.
+    pub fn add_rule(&mut self, rule: NotificationRule) -> bool {
+        let key = rule.key();
+        if self.rules.iter().any(|r| r.key() == key) {
+            return false;
+        }
+        self.rules.push(rule);
+        self.added_rule_count += 1;
+        true
+    }
+
+    /// Rules added at runtime (persisted separately from config.json)
+    pub fn added_rules(&self) -> &[NotificationRule] {
+        &self.rules[self.rules.len() - self.added_rule_count..]
+    }
+
     /// Create with default settings
     pub fn default() -> Self {
         Self::new(Vec::new(), 60, DEFAULT_MAX_NOTIFICATIONS)
@@ -58,11 +136,29 @@ impl NotificationManager {
         self.rotate_log();
     }
 
+    /// Restore persisted cooldowns, discarding entries whose window had elapsed by `now`
+    pub fn load_cooldowns(&mut self, cooldowns: HashMap<String, u64>, now: u64) {
+        self.cooldowns = cooldowns
+            .into_iter()
+            .filter(|(_, last_trigger)| now.saturating_sub(*last_trigger) < self.cooldown_secs)
+            .collect();
+    }
+
+    /// Current cooldown timestamps (for persistence)
+    pub fn cooldowns(&self) -> &HashMap<String, u64> {
+        &self.cooldowns
+    }
+
     /// Check all rules against current coin data, returns new notifications
-    /// Only checks rules for coins that are checked/selected
-    pub fn check_rules(&mut self, coins: &[CoinData], checked: &[bool]) -> Vec<Notification> {
+    /// Only checks rules for coins that are checked/selected; `now` (Unix seconds)
+    /// drives cooldowns and notification timestamps
+    pub fn check_rules(
+        &mut self,
+        coins: &[CoinData],
+        checked: &[bool],
+        now: u64,
+    ) -> Vec<Notification> {
         let mut new_notifications = Vec::new();
-        let now = now_secs();
 
         // Clone rules to avoid borrow conflict
         let rules: Vec<NotificationRule> = self.rules.clone();
@@ -81,12 +177,13 @@ impl NotificationManager {
                 // Check cooldown
                 let rule_key = format!("{}_{}", coin.symbol, rule.key());
                 if let Some(&last_trigger) = self.cooldowns.get(&rule_key) {
-                    if now - last_trigger < self.cooldown_secs {
+                    if now.saturating_sub(last_trigger) < self.cooldown_secs {
                         continue;
                     }
                 }
 
-                if let Some(notif) = self.check_single_rule(rule, coin, now, rule.sound()) {
+                if let Some(mut notif) = self.check_single_rule(rule, coin, now, rule.sound()) {
+                    notif.rule_key = rule_key.clone();
                     self.cooldowns.insert(rule_key, now);
                     new_notifications.push(notif);
                 }
@@ -98,20 +195,45 @@ impl NotificationManager {
 
         // Add new notifications
         for notif in &new_notifications {
-            self.notifications.push(notif.clone());
-            self.unread_count += 1;
+            self.push_notification(notif.clone());
         }
 
         self.rotate_log();
         new_notifications
     }
 
+    /// Append a notification, or fold it into the newest entry when that entry
+    /// came from the same rule for the same coin within the coalesce window
+    fn push_notification(&mut self, notif: Notification) {
+        let window = self.coalesce_window_secs;
+        let newest = self.notifications.last_mut().filter(|newest| {
+            window > 0
+                && !notif.rule_key.is_empty()
+                && newest.rule_key == notif.rule_key
+                && notif.timestamp.saturating_sub(newest.timestamp) <= window
+        });
+        if let Some(merged) = newest {
+            if merged.read {
+                self.unread_count += 1;
+            }
+            merged.count += 1;
+            merged.timestamp = notif.timestamp;
+            merged.message = notif.message;
+            merged.severity = notif.severity;
+            merged.read = false;
+            return;
+        }
+
+        self.notifications.push(notif);
+        self.unread_count += 1;
+    }
+
     /// Check a single rule against a coin
     fn check_single_rule(
         &mut self,
         rule: &NotificationRule,
         coin: &CoinData,
-        _now: u64,
+        now: u64,
         sound: Option<&str>,
     ) -> Option<Notification> {
         match rule {
@@ -120,11 +242,26 @@ impl NotificationManager {
                 threshold,
                 direction,
                 ..
-            } => self.check_rsi_rule(coin, *period, *threshold, *direction, sound),
+            } => self.check_rsi_rule(coin, *period, *threshold, *direction, now, sound),
 
             NotificationRule::EmaCross {
                 period, direction, ..
-            } => self.check_ema_cross_rule(coin, *period, *direction, sound),
+            } => self.check_ema_cross_rule(coin, *period, *direction, now, sound),
+
+            NotificationRule::SmaCross {
+                fast,
+                slow,
+                direction,
+                ..
+            } => self.check_sma_cross_rule(coin, (*fast, *slow), *direction, now, sound),
+
+            NotificationRule::RsiDivergence {
+                period, lookback, ..
+            } => self.check_rsi_divergence_rule(coin, *period, *lookback, now, sound),
+
+            NotificationRule::BollingerBreak {
+                period, std_mult, ..
+            } => self.check_bollinger_rule(coin, *period, *std_mult, now, sound),
 
             NotificationRule::PriceLevel {
                 symbol,
@@ -135,7 +272,7 @@ impl NotificationManager {
                 // Only check if symbol matches (strip USDT suffix)
                 let coin_base = coin.symbol.trim_end_matches("USDT");
                 if coin_base == symbol || coin.symbol == *symbol {
-                    self.check_price_level_rule(coin, *price, *direction, sound)
+                    self.check_price_level_rule(coin, *price, *direction, now, sound)
                 } else {
                     None
                 }
@@ -150,6 +287,7 @@ impl NotificationManager {
         period: u8,
         threshold: f64,
         direction: ThresholdDir,
+        now: u64,
         sound: Option<&str>,
     ) -> Option<Notification> {
         let rsi = match period {
@@ -194,6 +332,7 @@ impl NotificationManager {
                 &message,
                 "rsi",
                 severity,
+                now,
                 sound,
             ));
         }
@@ -207,6 +346,7 @@ impl NotificationManager {
         coin: &CoinData,
         period: u8,
         direction: CrossDir,
+        now: u64,
         sound: Option<&str>,
     ) -> Option<Notification> {
         let ema = match period {
@@ -251,6 +391,7 @@ impl NotificationManager {
                 &message,
                 "ema_cross",
                 Severity::Info,
+                now,
                 sound,
             ));
         }
@@ -258,12 +399,180 @@ impl NotificationManager {
         None
     }
 
+    /// Check SMA crossing rule (fast SMA crossing the slow one)
+    fn check_sma_cross_rule(
+        &mut self,
+        coin: &CoinData,
+        (fast, slow): (u8, u8),
+        direction: CrossDir,
+        now: u64,
+        sound: Option<&str>,
+    ) -> Option<Notification> {
+        let fast_sma = sma_value(coin, fast);
+        let slow_sma = sma_value(coin, slow);
+
+        // Skip until both SMAs have enough candles
+        if fast_sma == 0.0 || slow_sma == 0.0 {
+            return None;
+        }
+
+        let key = (coin.symbol.clone(), fast, slow);
+        let currently_above = fast_sma > slow_sma;
+
+        let prev_above = self.prev_sma_positions.insert(key, currently_above);
+
+        // Need previous state to detect crossing
+        let prev_above = prev_above?;
+
+        let crossed = match direction {
+            CrossDir::CrossAbove => !prev_above && currently_above,
+            CrossDir::CrossBelow => prev_above && !currently_above,
+        };
+        if !crossed {
+            return None;
+        }
+
+        let dir_text = match direction {
+            CrossDir::CrossAbove => "golden cross",
+            CrossDir::CrossBelow => "death cross",
+        };
+        let message = format!(
+            "{} {}: SMA({}) {:.2} / SMA({}) {:.2}",
+            coin.symbol, dir_text, fast, fast_sma, slow, slow_sma
+        );
+
+        Some(Notification::new(
+            &coin.symbol,
+            &message,
+            "sma_cross",
+            Severity::Info,
+            now,
+            sound,
+        ))
+    }
+
+    /// Check RSI divergence rule (notifies once per confirming swing)
+    ///
+    /// RSI is only computed over the lookback window plus a warm-up. The first
+    /// pass with candles records the current swing without notifying, so
+    /// divergences already in the loaded history don't alert at startup.
+    fn check_rsi_divergence_rule(
+        &mut self,
+        coin: &CoinData,
+        period: u8,
+        lookback: usize,
+        now: u64,
+        sound: Option<&str>,
+    ) -> Option<Notification> {
+        if coin.candles.is_empty() {
+            return None;
+        }
+
+        let warmup = period as usize * DIVERGENCE_RSI_WARMUP_PERIODS;
+        let start = coin.candles.len().saturating_sub(lookback + warmup);
+        let candles = &coin.candles[start..];
+        let closes: Vec<f64> = candles.iter().map(|c| c.close).collect();
+        let rsi = CandleIndicators::calculate_rsi_series(&closes, period as usize);
+        let signal = detect_divergence(candles, &rsi, lookback);
+        let swing_time = signal.map(|s| s.swing_time);
+
+        let key = (coin.symbol.clone(), period, lookback);
+        match self.prev_divergence_swings.get(&key) {
+            None => {
+                self.prev_divergence_swings.insert(key, swing_time);
+                return None;
+            }
+            Some(&last) if signal.is_none() || last == swing_time => return None,
+            Some(_) => {}
+        }
+        self.prev_divergence_swings.insert(key, swing_time);
+        let signal = signal?;
+
+        let kind_text = match signal.kind {
+            Divergence::Bullish => "bullish",
+            Divergence::Bearish => "bearish",
+        };
+
+        let message = format!(
+            "{} {} RSI({}) divergence (RSI {:.1})",
+            coin.symbol, kind_text, period, signal.rsi
+        );
+
+        Some(Notification::new(
+            &coin.symbol,
+            &message,
+            "rsi_divergence",
+            Severity::Warning,
+            now,
+            sound,
+        ))
+    }
+
+    /// Check Bollinger break rule (notifies when price moves outside a band)
+    fn check_bollinger_rule(
+        &mut self,
+        coin: &CoinData,
+        period: u8,
+        std_mult: f64,
+        now: u64,
+        sound: Option<&str>,
+    ) -> Option<Notification> {
+        let period_len = period as usize;
+        if period_len == 0 || coin.candles.len() < period_len {
+            return None;
+        }
+
+        let closes: Vec<f64> = coin.candles[coin.candles.len() - period_len..]
+            .iter()
+            .map(|c| c.close)
+            .collect();
+        let (upper, _, lower) = bollinger_bands(&closes, period_len, std_mult);
+        let (upper, lower) = (*upper.last()?, *lower.last()?);
+
+        let position = if coin.price > upper {
+            BandPosition::Above
+        } else if coin.price < lower {
+            BandPosition::Below
+        } else {
+            BandPosition::Inside
+        };
+
+        let key = (coin.symbol.clone(), period, std_mult.to_bits());
+        let prev_position = self.prev_band_positions.insert(key, position);
+
+        // Need previous state to detect the break
+        if prev_position? == position {
+            return None;
+        }
+
+        let (dir_text, band) = match position {
+            BandPosition::Above => ("broke above", upper),
+            BandPosition::Below => ("broke below", lower),
+            BandPosition::Inside => return None,
+        };
+
+        let message = format!(
+            "{} {} BB({}, {:.1}) ${:.2} at ${:.2}",
+            coin.symbol, dir_text, period, std_mult, band, coin.price
+        );
+
+        Some(Notification::new(
+            &coin.symbol,
+            &message,
+            "bollinger_break",
+            Severity::Warning,
+            now,
+            sound,
+        ))
+    }
+
     /// Check price level rule
     fn check_price_level_rule(
         &mut self,
         coin: &CoinData,
         target_price: f64,
         direction: ThresholdDir,
+        now: u64,
         sound: Option<&str>,
     ) -> Option<Notification> {
         let prev_price = self.prev_prices.get(&coin.symbol).copied()?;
@@ -286,6 +595,7 @@ impl NotificationManager {
                 &message,
                 "price_level",
                 Severity::Warning,
+                now,
                 sound,
             ));
         }
@@ -301,6 +611,24 @@ impl NotificationManager {
         self.unread_count = 0;
     }
 
+    /// Drop every notification from the log
+    pub fn clear_all(&mut self) {
+        self.notifications.clear();
+        self.unread_count = 0;
+    }
+
+    /// Remove the notification at `index` (oldest first); None if out of bounds
+    pub fn remove(&mut self, index: usize) -> Option<Notification> {
+        if index >= self.notifications.len() {
+            return None;
+        }
+        let removed = self.notifications.remove(index);
+        if !removed.read {
+            self.unread_count = self.unread_count.saturating_sub(1);
+        }
+        Some(removed)
+    }
+
     /// Toggle the selected rule's enabled state
     pub fn toggle_selected_rule(&mut self) {
         if let Some(rule) = self.rules.get_mut(self.selected_rule) {
@@ -330,9 +658,20 @@ impl NotificationManager {
         }
     }
 
-    /// Get notifications slice for display
-    pub fn get_notifications(&self) -> &[Notification] {
-        &self.notifications
+    /// Notifications at or above `min_severity`, oldest first
+    pub fn notifications_filtered(&self, min_severity: Severity) -> Vec<&Notification> {
+        self.notifications
+            .iter()
+            .filter(|n| n.severity >= min_severity)
+            .collect()
+    }
+
+    /// Unread notifications at or above `min_severity`
+    pub fn unread_count_filtered(&self, min_severity: Severity) -> usize {
+        self.notifications
+            .iter()
+            .filter(|n| !n.read && n.severity >= min_severity)
+            .count()
     }
 
     /// Get rules slice for display
@@ -341,9 +680,179 @@ impl NotificationManager {
     }
 }
 
-fn now_secs() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
+/// SMA of the coin's closes for `period` (cached for 50/200, 0.0 = not enough candles)
+fn sma_value(coin: &CoinData, period: u8) -> f64 {
+    match period {
+        50 => coin.indicators.sma_50,
+        200 => coin.indicators.sma_200,
+        _ => {
+            let closes: Vec<f64> = coin.candles.iter().map(|c| c.close).collect();
+            sma(&closes, period as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Candle;
+
+    fn coin_with_closes(closes: &[f64]) -> CoinData {
+        let candles = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| Candle {
+                time: i as i64,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                ..Candle::default()
+            })
+            .collect();
+        let mut coin = CoinData::new("BTC", "Bitcoin");
+        coin.set_candles(candles, 0);
+        coin
+    }
+
+    /// A sharp rally to 10, then a choppy grind to a higher high at 10.5 on weaker RSI
+    const BEARISH_CLOSES: [f64; 15] = [
+        1.0, 2.0, 4.0, 7.0, 10.0, 8.0, 7.0, 8.0, 9.0, 9.5, 10.0, 10.2, 10.5, 9.0, 8.0,
+    ];
+
+    fn divergence_manager() -> NotificationManager {
+        let rule = NotificationRule::RsiDivergence {
+            period: 2,
+            lookback: 20,
+            enabled: true,
+            sound: None,
+        };
+        NotificationManager::new(vec![rule], 0, 10)
+    }
+
+    #[test]
+    fn divergence_already_in_history_does_not_alert_at_startup() {
+        let mut manager = divergence_manager();
+        let coins = [coin_with_closes(&BEARISH_CLOSES)];
+        assert!(manager.check_rules(&coins, &[true], 100).is_empty());
+        assert!(manager.check_rules(&coins, &[true], 200).is_empty());
+    }
+
+    #[test]
+    fn new_divergence_alerts_once() {
+        let mut manager = divergence_manager();
+        // First pass sees only the initial rally (no swings yet)
+        let rally = coin_with_closes(&BEARISH_CLOSES[..6]);
+        assert!(manager.check_rules(&[rally], &[true], 100).is_empty());
+
+        let coins = [coin_with_closes(&BEARISH_CLOSES)];
+        let fired = manager.check_rules(&coins, &[true], 200);
+        assert_eq!(fired.len(), 1);
+        assert!(fired[0].message.contains("bearish"), "{}", fired[0].message);
+
+        // Same swing on the next pass stays quiet
+        assert!(manager.check_rules(&coins, &[true], 300).is_empty());
+    }
+
+    fn price_level(symbol: &str, price: f64) -> NotificationRule {
+        NotificationRule::PriceLevel {
+            symbol: symbol.to_string(),
+            price,
+            direction: ThresholdDir::Above,
+            enabled: true,
+            sound: None,
+        }
+    }
+
+    #[test]
+    fn reload_drops_added_rules_now_in_config() {
+        let mut manager = NotificationManager::new(Vec::new(), 60, 10);
+        assert!(manager.add_rule(price_level("BTC", 100.0)));
+        assert!(manager.add_rule(price_level("ETH", 5.0)));
+
+        manager.apply_config(vec![price_level("BTC", 100.0)], 60, 10);
+        let keys: Vec<String> = manager.rules.iter().map(|r| r.key()).collect();
+        assert_eq!(keys, ["price_BTC_100_Above", "price_ETH_5_Above"]);
+        assert_eq!(manager.added_rules(), [price_level("ETH", 5.0)]);
+
+        // Reloading the same config again keeps exactly one copy of each
+        manager.apply_config(vec![price_level("BTC", 100.0)], 60, 10);
+        assert_eq!(manager.rules.len(), 2);
+    }
+
+    #[test]
+    fn cooldown_suppresses_repeat_alerts_until_it_expires() {
+        let mut manager = NotificationManager::new(vec![price_level("BTC", 100.0)], 60, 10);
+        let mut pass = |price, now| {
+            let mut coin = CoinData::new("BTCUSDT", "Bitcoin");
+            coin.price = price;
+            manager.check_rules(&[coin], &[true], now).len()
+        };
+
+        assert_eq!(pass(99.0, 0), 0);
+        assert_eq!(pass(101.0, 10), 1);
+        // Crosses again inside the 60s window
+        assert_eq!(pass(99.0, 20), 0);
+        assert_eq!(pass(101.0, 30), 0);
+        // Window elapsed at 70
+        assert_eq!(pass(99.0, 69), 0);
+        assert_eq!(pass(101.0, 70), 1);
+    }
+
+    #[test]
+    fn repeats_coalesce_only_into_the_newest_entry_for_the_same_rule() {
+        let rules = vec![price_level("BTC", 100.0), price_level("BTC", 110.0)];
+        let mut manager = NotificationManager::new(rules, 0, 10);
+        manager.set_coalesce_window(600);
+        let prices = [99.0, 101.0, 111.0, 99.0, 101.0, 99.0, 101.0];
+        for (tick, price) in prices.into_iter().enumerate() {
+            let mut coin = CoinData::new("BTCUSDT", "Bitcoin");
+            coin.price = price;
+            manager.check_rules(&[coin], &[true], tick as u64 * 10);
+        }
+
+        // 100 fired, then 110, then 100 twice in a row: only the last pair folds
+        let log: Vec<(&str, u32)> = manager
+            .notifications
+            .iter()
+            .map(|n| (n.message.as_str(), n.count))
+            .collect();
+        assert_eq!(
+            log,
+            [
+                ("BTCUSDT broke above $100", 1),
+                ("BTCUSDT broke above $110", 1),
+                ("BTCUSDT broke above $100", 2),
+            ]
+        );
+        assert_eq!(manager.unread_count, 3);
+    }
+
+    #[test]
+    fn bollinger_rules_with_different_multipliers_track_separately() {
+        let bollinger = |std_mult| NotificationRule::BollingerBreak {
+            period: 4,
+            std_mult,
+            enabled: true,
+            sound: None,
+        };
+        let mut manager = NotificationManager::new(vec![bollinger(1.0), bollinger(3.0)], 0, 10);
+        // Mean 10, deviation 1: bands 9-11 and 7-13
+        let mut coin = coin_with_closes(&[9.0, 11.0, 9.0, 11.0]);
+        coin.price = 10.0;
+        assert!(manager.check_rules(&[coin], &[true], 100).is_empty());
+
+        // Outside the narrow band only, and still there on the next pass
+        let mut coin = coin_with_closes(&[9.0, 11.0, 9.0, 11.0]);
+        coin.price = 12.0;
+        let coins = [coin];
+        let fired = manager.check_rules(&coins, &[true], 200);
+        assert_eq!(fired.len(), 1);
+        assert!(
+            fired[0].message.contains("BB(4, 1.0)"),
+            "{}",
+            fired[0].message
+        );
+        assert!(manager.check_rules(&coins, &[true], 300).is_empty());
+    }
 }
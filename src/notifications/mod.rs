@@ -1,13 +1,20 @@
 //! Notifications module - alerts for price and indicator conditions
 
 pub mod audio;
+pub mod connection_sounds;
+pub mod desktop;
+pub mod divergence;
 pub mod manager;
 pub mod notification;
 pub mod persistence;
 pub mod rules;
+pub mod screen_flash;
 pub mod ticker_tones;
+pub mod webhook;
 
+pub use connection_sounds::process_connection_sound;
 pub use manager::NotificationManager;
 pub use notification::Severity;
 pub use rules::NotificationRule;
-pub use ticker_tones::process_ticker_tones;
+pub use screen_flash::ScreenFlash;
+pub use ticker_tones::{process_ticker_tones, TickerTonesState};
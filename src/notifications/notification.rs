@@ -3,8 +3,8 @@
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-/// Notification severity level
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+/// Notification severity level, ordered from least to most severe
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum Severity {
     Info,
@@ -12,6 +12,26 @@ pub enum Severity {
     Critical,
 }
 
+impl Severity {
+    /// Next minimum-severity filter: All -> Warning+ -> Critical only -> All
+    pub fn next_filter(self) -> Self {
+        match self {
+            Self::Info => Self::Warning,
+            Self::Warning => Self::Critical,
+            Self::Critical => Self::Info,
+        }
+    }
+
+    /// Label for a minimum-severity filter at this level
+    pub fn filter_label(self) -> &'static str {
+        match self {
+            Self::Info => "All",
+            Self::Warning => "Warning+",
+            Self::Critical => "Critical",
+        }
+    }
+}
+
 /// A notification instance - represents a triggered alert
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Notification {
@@ -25,22 +45,29 @@ pub struct Notification {
     /// Custom sound file to play (from rule config)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sound: Option<String>,
+    /// How many times this alert fired, when repeats are coalesced into one entry
+    #[serde(default = "default_count")]
+    pub count: u32,
+    /// Symbol-qualified key of the rule that fired (empty for other entries);
+    /// only entries with the same key are coalesced
+    #[serde(default)]
+    pub rule_key: String,
+}
+
+fn default_count() -> u32 {
+    1
 }
 
 impl Notification {
-    /// Create a new notification with current timestamp
+    /// Create a new notification stamped with `timestamp` (Unix seconds)
     pub fn new(
         symbol: &str,
         message: &str,
         rule_type: &str,
         severity: Severity,
+        timestamp: u64,
         sound: Option<&str>,
     ) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-
         Self {
             id: timestamp * 1000 + (rand_u64() % 1000), // Simple unique ID
             timestamp,
@@ -50,6 +77,8 @@ impl Notification {
             severity,
             read: false,
             sound: sound.map(|s| s.to_string()),
+            count: 1,
+            rule_key: String::new(),
         }
     }
 
@@ -1,6 +1,8 @@
 //! Notification persistence - load/save to JSON file
 
 use super::notification::Notification;
+use super::rules::NotificationRule;
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
@@ -28,6 +30,27 @@ fn find_log_path(filename: &str) -> PathBuf {
     PathBuf::from(filename)
 }
 
+/// File next to the log file with the given suffix (e.g. notifications.cooldowns.json)
+fn find_sibling_path(log_file: &str, suffix: &str) -> PathBuf {
+    let log_path = find_log_path(log_file);
+    let stem = log_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("notifications")
+        .to_string();
+    log_path.with_file_name(format!("{}.{}.json", stem, suffix))
+}
+
+/// Cooldown file path derived from the log file (e.g. notifications.cooldowns.json)
+fn find_cooldowns_path(log_file: &str) -> PathBuf {
+    find_sibling_path(log_file, "cooldowns")
+}
+
+/// Runtime-added rules file derived from the log file (e.g. notifications.rules.json)
+fn find_rules_path(log_file: &str) -> PathBuf {
+    find_sibling_path(log_file, "rules")
+}
+
 /// Load notifications from JSON file
 pub fn load_notifications(filename: &str) -> Vec<Notification> {
     let path = find_log_path(filename);
@@ -66,3 +89,120 @@ pub fn save_notifications(notifications: &[Notification], filename: &str) {
         }
     }
 }
+
+/// Load cooldown timestamps (rule_key -> last trigger) saved next to the log file
+pub fn load_cooldowns(log_file: &str) -> HashMap<String, u64> {
+    let path = find_cooldowns_path(log_file);
+
+    if !path.exists() {
+        return HashMap::new();
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(cooldowns) => cooldowns,
+            Err(e) => {
+                eprintln!("Failed to parse notification cooldowns: {}", e);
+                HashMap::new()
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to read notification cooldowns: {}", e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Save cooldown timestamps next to the log file
+pub fn save_cooldowns(cooldowns: &HashMap<String, u64>, log_file: &str) {
+    let path = find_cooldowns_path(log_file);
+
+    match serde_json::to_string_pretty(cooldowns) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                eprintln!("Failed to write notification cooldowns: {}", e);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to serialize notification cooldowns: {}", e);
+        }
+    }
+}
+
+/// Load rules added from the UI (price alerts) saved next to the log file
+pub fn load_added_rules(log_file: &str) -> Vec<NotificationRule> {
+    let path = find_rules_path(log_file);
+
+    if !path.exists() {
+        return Vec::new();
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(rules) => rules,
+            Err(e) => {
+                eprintln!("Failed to parse added notification rules: {}", e);
+                Vec::new()
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to read added notification rules: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Save rules added from the UI next to the log file
+pub fn save_added_rules(rules: &[NotificationRule], log_file: &str) {
+    let path = find_rules_path(log_file);
+
+    match serde_json::to_string_pretty(rules) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                eprintln!("Failed to write added notification rules: {}", e);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to serialize added notification rules: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::NotificationManager;
+
+    #[test]
+    fn cooldowns_round_trip_and_drop_stale_entries() {
+        let dir = env::temp_dir().join(format!("cooldowns-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let log_file = dir.join("notifications.json");
+        let log_file = log_file.to_str().unwrap();
+
+        let cooldowns = HashMap::from([
+            ("BTC:price_above".to_string(), 1_000),
+            ("ETH:rsi_above".to_string(), 1_250),
+        ]);
+        save_cooldowns(&cooldowns, log_file);
+        assert!(dir.join("notifications.cooldowns.json").exists());
+        assert_eq!(load_cooldowns(log_file), cooldowns);
+
+        // 300s window: BTC fired 301s ago (stale), ETH 51s ago (kept)
+        let mut manager = NotificationManager::new(Vec::new(), 300, 10);
+        manager.load_cooldowns(load_cooldowns(log_file), 1_301);
+        assert_eq!(
+            manager.cooldowns(),
+            &HashMap::from([("ETH:rsi_above".to_string(), 1_250)])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_cooldowns_file_loads_empty() {
+        let dir = env::temp_dir().join(format!("cooldowns-missing-{}", std::process::id()));
+        let log_file = dir.join("notifications.json");
+        assert!(load_cooldowns(log_file.to_str().unwrap()).is_empty());
+    }
+}
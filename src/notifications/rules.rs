@@ -12,7 +12,7 @@ pub enum ThresholdDir {
     Below,
 }
 
-/// Direction for crossing-based rules (EMA crossings)
+/// Direction for crossing-based rules (EMA crossings, fast SMA vs slow SMA)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum CrossDir {
@@ -45,6 +45,45 @@ pub enum NotificationRule {
         #[serde(default)]
         sound: Option<String>,
     },
+    /// Fast SMA crosses the slow SMA (golden cross above, death cross below)
+    SmaCross {
+        #[serde(default = "default_sma_fast")]
+        fast: u8,
+        #[serde(default = "default_sma_slow")]
+        slow: u8,
+        direction: CrossDir,
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// Custom sound file (e.g., "golden_cross.wav") in sounds/ directory
+        #[serde(default)]
+        sound: Option<String>,
+    },
+    /// Price/RSI divergence over recent candles (bullish or bearish)
+    RsiDivergence {
+        #[serde(default = "default_divergence_period")]
+        period: u8,
+        /// Number of recent candles searched for swing points
+        #[serde(default = "default_divergence_lookback")]
+        lookback: usize,
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// Custom sound file (e.g., "divergence.wav") in sounds/ directory
+        #[serde(default)]
+        sound: Option<String>,
+    },
+    /// Price breaks out of the Bollinger Bands (above upper or below lower)
+    BollingerBreak {
+        #[serde(default = "default_bollinger_period")]
+        period: u8,
+        /// Band distance in standard deviations
+        #[serde(default = "default_bollinger_std_mult")]
+        std_mult: f64,
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// Custom sound file (e.g., "breakout.wav") in sounds/ directory
+        #[serde(default)]
+        sound: Option<String>,
+    },
     /// Price crosses a specific level
     PriceLevel {
         symbol: String,
@@ -62,12 +101,39 @@ fn default_enabled() -> bool {
     true
 }
 
+fn default_sma_fast() -> u8 {
+    50
+}
+
+fn default_sma_slow() -> u8 {
+    200
+}
+
+fn default_divergence_period() -> u8 {
+    14
+}
+
+fn default_divergence_lookback() -> usize {
+    30
+}
+
+fn default_bollinger_period() -> u8 {
+    20
+}
+
+fn default_bollinger_std_mult() -> f64 {
+    2.0
+}
+
 impl NotificationRule {
     /// Check if this rule is enabled
     pub fn is_enabled(&self) -> bool {
         match self {
             NotificationRule::Rsi { enabled, .. } => *enabled,
             NotificationRule::EmaCross { enabled, .. } => *enabled,
+            NotificationRule::SmaCross { enabled, .. } => *enabled,
+            NotificationRule::RsiDivergence { enabled, .. } => *enabled,
+            NotificationRule::BollingerBreak { enabled, .. } => *enabled,
             NotificationRule::PriceLevel { enabled, .. } => *enabled,
         }
     }
@@ -77,6 +143,9 @@ impl NotificationRule {
         match self {
             NotificationRule::Rsi { enabled, .. } => *enabled = !*enabled,
             NotificationRule::EmaCross { enabled, .. } => *enabled = !*enabled,
+            NotificationRule::SmaCross { enabled, .. } => *enabled = !*enabled,
+            NotificationRule::RsiDivergence { enabled, .. } => *enabled = !*enabled,
+            NotificationRule::BollingerBreak { enabled, .. } => *enabled = !*enabled,
             NotificationRule::PriceLevel { enabled, .. } => *enabled = !*enabled,
         }
     }
@@ -86,6 +155,9 @@ impl NotificationRule {
         match self {
             NotificationRule::Rsi { sound, .. } => sound.as_deref(),
             NotificationRule::EmaCross { sound, .. } => sound.as_deref(),
+            NotificationRule::SmaCross { sound, .. } => sound.as_deref(),
+            NotificationRule::RsiDivergence { sound, .. } => sound.as_deref(),
+            NotificationRule::BollingerBreak { sound, .. } => sound.as_deref(),
             NotificationRule::PriceLevel { sound, .. } => sound.as_deref(),
         }
     }
@@ -114,6 +186,24 @@ impl NotificationRule {
                 };
                 format!("EMA({}) {}", period, dir)
             }
+            NotificationRule::SmaCross {
+                fast,
+                slow,
+                direction,
+                ..
+            } => {
+                let dir = match direction {
+                    CrossDir::CrossAbove => "Cross Above",
+                    CrossDir::CrossBelow => "Cross Below",
+                };
+                format!("SMA({}) {} SMA({})", fast, dir, slow)
+            }
+            NotificationRule::RsiDivergence {
+                period, lookback, ..
+            } => format!("RSI({}) Divergence ({} bars)", period, lookback),
+            NotificationRule::BollingerBreak {
+                period, std_mult, ..
+            } => format!("BB({}, {:.1}) Break", period, std_mult),
             NotificationRule::PriceLevel {
                 symbol,
                 price,
@@ -141,6 +231,18 @@ impl NotificationRule {
             NotificationRule::EmaCross {
                 period, direction, ..
             } => format!("ema_cross_{}_{:?}", period, direction),
+            NotificationRule::SmaCross {
+                fast,
+                slow,
+                direction,
+                ..
+            } => format!("sma_cross_{}_{}_{:?}", fast, slow, direction),
+            NotificationRule::RsiDivergence {
+                period, lookback, ..
+            } => format!("rsi_div_{}_{}", period, lookback),
+            NotificationRule::BollingerBreak {
+                period, std_mult, ..
+            } => format!("bollinger_{}_{}", period, std_mult),
             NotificationRule::PriceLevel {
                 symbol,
                 price,
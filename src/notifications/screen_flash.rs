@@ -0,0 +1,58 @@
+//! Screen flash - full-screen overlay for critical alerts
+//!
+//! A critical notification starts a short flash that fades out linearly.
+//! Flashes are rate-limited so a volatile burst of alerts doesn't strobe.
+
+use std::time::{Duration, Instant};
+
+use super::notification::{Notification, Severity};
+use crate::config::FlashScreenConfig;
+
+/// Peak opacity of the overlay at the start of a flash
+const PEAK_ALPHA: f32 = 0.6;
+
+/// Time-based full-screen flash state
+pub struct ScreenFlash {
+    config: FlashScreenConfig,
+    started: Option<Instant>,
+}
+
+impl ScreenFlash {
+    pub fn new(config: FlashScreenConfig) -> Self {
+        Self {
+            config,
+            started: None,
+        }
+    }
+
+    /// Start a flash if any new notification is critical and the minimum interval has passed
+    pub fn trigger(&mut self, notifications: &[Notification]) {
+        if !self.config.enabled
+            || !notifications
+                .iter()
+                .any(|n| n.severity == Severity::Critical)
+        {
+            return;
+        }
+
+        let min_interval = Duration::from_secs(self.config.min_interval_secs);
+        if let Some(started) = self.started {
+            if started.elapsed() < min_interval {
+                return;
+            }
+        }
+
+        self.started = Some(Instant::now());
+    }
+
+    /// Current overlay opacity, or None when no flash is active
+    pub fn alpha(&self) -> Option<f32> {
+        let started = self.started?;
+        let duration = self.config.duration_ms.max(1) as f32;
+        let elapsed = started.elapsed().as_millis() as f32;
+        if elapsed >= duration {
+            return None;
+        }
+        Some(PEAK_ALPHA * (1.0 - elapsed / duration))
+    }
+}
@@ -2,130 +2,133 @@
 //!
 //! Plays generated tones on price ticks:
 //! - Different base frequencies for UP vs DOWN movements
-//! - Higher pitch = bigger change magnitude (relative to historical average)
-//! - Rate-limited to prevent overlapping tones
+//! - Higher pitch = bigger change magnitude (relative to historical average),
+//!   optionally quantized to a pentatonic or chromatic scale
+//! - Debounced per coin, and never started while the previous tone still plays
+//! - Simultaneous changes on several coins coalesce into one tone for the largest move
 //! - Only plays once per actual price change
 
 use std::collections::HashMap;
-use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 use super::audio;
-use crate::config::TickerTonesConfig;
+use crate::config::{TickerTonesConfig, ToneScale};
 use crate::mock::CoinData;
 use crate::widgets::format::round_to_display;
 
-/// Minimum interval between tones in milliseconds.
-/// 100ms allows up to 10 tones per second.
-const MIN_TONE_INTERVAL_MS: u64 = 100;
-
-/// Track when the last tone was played (for rate limiting)
-static LAST_TONE_TIME: Mutex<Option<Instant>> = Mutex::new(None);
-
-/// Track the price at which we last played a tone for each coin
-/// This prevents playing multiple tones for the same price change
-static LAST_TONE_PRICES: Mutex<Option<HashMap<String, f64>>> = Mutex::new(None);
+/// Major pentatonic degrees within one octave, in semitones above the root
+const PENTATONIC_DEGREES: [i32; 5] = [0, 2, 4, 7, 9];
+
+/// Largest change ratio (relative to the average change) that still raises the pitch
+const MAX_CHANGE_RATIO: f64 = 4.0;
+
+/// Per-session ticker tone bookkeeping, owned by the render loop
+#[derive(Default)]
+pub struct TickerTonesState {
+    /// When the last tone started (any coin), to avoid cutting it off
+    last_tone: Option<Instant>,
+    /// When each coin last contributed to a tone
+    last_tone_times: HashMap<String, Instant>,
+    /// Rounded price each coin last played a tone for, so one change plays once
+    last_tone_prices: HashMap<String, f64>,
+}
 
-/// Check if enough time has passed since the last tone
-fn can_play_tone(duration_ms: u32) -> bool {
-    let min_interval = Duration::from_millis(MIN_TONE_INTERVAL_MS.max(duration_ms as u64));
+impl TickerTonesState {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    let last = LAST_TONE_TIME.lock().unwrap();
-    match *last {
-        Some(last_time) => last_time.elapsed() >= min_interval,
-        None => true,
+    /// Whether the previous tone has finished playing
+    fn tone_finished(&self, duration_ms: u32) -> bool {
+        self.last_tone
+            .is_none_or(|t| t.elapsed() >= Duration::from_millis(duration_ms as u64))
     }
-}
 
-/// Record that a tone was just played
-fn record_tone_played() {
-    let mut last = LAST_TONE_TIME.lock().unwrap();
-    *last = Some(Instant::now());
-}
+    /// Whether `symbol` is outside its minimum inter-tone interval
+    fn symbol_ready(&self, symbol: &str, min_interval_ms: u64) -> bool {
+        self.last_tone_times
+            .get(symbol)
+            .is_none_or(|t| t.elapsed() >= Duration::from_millis(min_interval_ms))
+    }
 
-/// Check if we should play a tone for this coin's price
-/// Returns Some((price_delta, is_up)) if we should play, None otherwise
-fn check_price_change(coin: &CoinData) -> Option<(f64, bool)> {
-    let mut prices_guard = LAST_TONE_PRICES.lock().unwrap();
-    let prices = prices_guard.get_or_insert_with(HashMap::new);
-
-    // Round to display precision - tone plays only when visible price changes
-    let current_rounded = round_to_display(coin.price);
-    let symbol = &coin.symbol;
-
-    // Get the last rounded price we played a tone for
-    let last_tone_price = prices.get(symbol).copied();
-
-    match last_tone_price {
-        Some(ltp) => {
-            if current_rounded != ltp {
-                // Visible price changed - update and signal to play
-                let delta = current_rounded - ltp;
-                prices.insert(symbol.clone(), current_rounded);
-                Some((delta, delta > 0.0))
-            } else {
-                // Visible price hasn't changed
-                None
-            }
-        }
-        None => {
-            // First time seeing this coin - store price but don't play
-            prices.insert(symbol.clone(), current_rounded);
-            None
-        }
+    /// Visible price change for this coin since its last tone, if any.
+    /// The first sighting only records the price.
+    fn take_price_change(&mut self, coin: &CoinData) -> Option<f64> {
+        // Round to display precision - tone plays only when visible price changes
+        let current_rounded = round_to_display(coin.price);
+        let last = self
+            .last_tone_prices
+            .insert(coin.symbol.clone(), current_rounded)?;
+        (current_rounded != last).then_some(current_rounded - last)
     }
 }
 
-/// Calculate and play a ticker tone based on price change
-fn play_tone_for_change(
-    price_delta: f64,
-    is_up: bool,
-    avg_change: f64,
-    config: &TickerTonesConfig,
-) {
-    let abs_change = price_delta.abs();
-
-    // Calculate magnitude ratio (1.0 = average change, up to 4.0 = big move)
-    let ratio = if avg_change > 0.0 {
-        (abs_change / avg_change).min(4.0)
+/// Pitch for a price change of `change` against the average change `avg`.
+///
+/// Up moves ascend from `base_freq_up` towards `max_freq`, down moves descend
+/// from `base_freq_down` towards `min_freq`. An average-sized move plays the
+/// base frequency and moves of 4x average or more reach the limit; with a
+/// musical `config.scale` the range is bucketed into the scale's degrees.
+pub fn frequency_for_change(change: f64, avg: f64, config: &TickerTonesConfig) -> f32 {
+    // Magnitude position: 0.0 at ratio=1, 1.0 at ratio=MAX_CHANGE_RATIO
+    let ratio = if avg > 0.0 {
+        (change.abs() / avg).min(MAX_CHANGE_RATIO)
     } else {
         1.0
     };
-    let ratio = ratio as f32;
-
-    // Scale factor: 0.0 at ratio=1, 1.0 at ratio=4
-    let scale = (ratio - 1.0) / 3.0;
+    let position = ((ratio - 1.0) / (MAX_CHANGE_RATIO - 1.0)) as f32;
 
-    // Use logarithmic scaling for natural pitch perception
-    let frequency = if is_up {
-        // UP: logarithmic scale from base_freq_up to max_freq
-        // At scale=0: base_freq_up, at scale=1: max_freq
-        let freq_ratio = config.max_freq / config.base_freq_up;
-        config.base_freq_up * freq_ratio.powf(scale)
+    let (base, limit) = if change > 0.0 {
+        (config.base_freq_up, config.max_freq)
     } else {
-        // DOWN: logarithmic scale from base_freq_down to min_freq
-        // At scale=0: base_freq_down, at scale=1: min_freq
-        let freq_ratio = config.min_freq / config.base_freq_down;
-        config.base_freq_down * freq_ratio.powf(scale)
+        (config.base_freq_down, config.min_freq)
     };
+    if base <= 0.0 || limit <= 0.0 {
+        return base;
+    }
+    let freq_ratio = limit / base;
+
+    // Logarithmic interpolation for natural pitch perception
+    let semitones = match config.scale {
+        ToneScale::Linear => return base * freq_ratio.powf(position),
+        ToneScale::Chromatic => scale_degrees(freq_ratio, |_| true),
+        ToneScale::Pentatonic => scale_degrees(freq_ratio, |step| {
+            PENTATONIC_DEGREES.contains(&step.rem_euclid(12))
+        }),
+    };
+    // Below-average moves stay on the base note rather than leaving the scale
+    let index = (position.max(0.0) * (semitones.len() - 1) as f32).round() as usize;
+    base * 2f32.powf(semitones[index] as f32 / 12.0)
+}
 
-    audio::play_tone(frequency, config.duration_ms);
-    record_tone_played();
+/// Semitone offsets from the base (signed towards the limit) that fit within
+/// `freq_ratio` and belong to the scale; always starts with the base itself
+fn scale_degrees(freq_ratio: f32, in_scale: impl Fn(i32) -> bool) -> Vec<i32> {
+    let span = (12.0 * freq_ratio.log2()).trunc() as i32;
+    let direction = span.signum();
+    (0..=span.abs())
+        .map(|step| step * direction)
+        .filter(|&step| in_scale(step))
+        .collect()
 }
 
-/// Process ticker tone for checked coins only.
-/// Only plays one tone per actual price change.
-pub fn process_ticker_tones(coins: &[CoinData], checked: &[bool], config: &TickerTonesConfig) {
-    if !config.enabled {
+/// Process ticker tones for checked coins only.
+/// Only plays one tone per actual price change; when several checked coins
+/// change in the same frame, a single tone plays for the largest relative move.
+pub fn process_ticker_tones(
+    state: &mut TickerTonesState,
+    coins: &[CoinData],
+    checked: &[bool],
+    config: &TickerTonesConfig,
+) {
+    if !config.enabled || !state.tone_finished(config.duration_ms) {
         return;
     }
 
-    // Rate limit check
-    if !can_play_tone(config.duration_ms) {
-        return;
-    }
+    let mut changed: Vec<&str> = Vec::new();
+    // (price delta, average change, delta relative to average)
+    let mut largest: Option<(f64, f64, f64)> = None;
 
-    // Find a checked coin with an actual price change
     for (i, coin) in coins.iter().enumerate() {
         // Skip unchecked coins
         if !checked.get(i).copied().unwrap_or(false) {
@@ -143,11 +146,29 @@ pub fn process_ticker_tones(coins: &[CoinData], checked: &[bool], config: &Ticke
             continue;
         }
 
+        // Debounced coins keep their pending change until the interval passes
+        if !state.symbol_ready(&coin.symbol, config.min_tone_interval_ms) {
+            continue;
+        }
+
         // Check if price actually changed since last tone
-        if let Some((price_delta, is_up)) = check_price_change(coin) {
-            play_tone_for_change(price_delta, is_up, avg_change, config);
-            // Only play one tone per tick
-            break;
+        if let Some(price_delta) = state.take_price_change(coin) {
+            let relative = price_delta.abs() / avg_change;
+            if largest.is_none_or(|(_, _, r)| relative > r) {
+                largest = Some((price_delta, avg_change, relative));
+            }
+            changed.push(&coin.symbol);
+        }
+    }
+
+    if let Some((price_delta, avg_change, _)) = largest {
+        let frequency = frequency_for_change(price_delta, avg_change, config);
+        audio::play_tone(frequency, config.duration_ms);
+
+        let now = Instant::now();
+        state.last_tone = Some(now);
+        for symbol in changed {
+            state.last_tone_times.insert(symbol.to_string(), now);
         }
     }
 }
@@ -0,0 +1,99 @@
+//! Webhook delivery - forward notifications as JSON over HTTP POST
+//!
+//! The body is the notification itself (id, timestamp, symbol, message,
+//! rule type, severity, ...) plus `text` and `content` summary fields, which
+//! Slack and Discord incoming webhooks display as the message.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::runtime::Runtime;
+
+use super::notification::{Notification, Severity};
+
+/// Per-attempt request timeout so a slow endpoint never holds a task for long
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Attempts per notification (one retry on failure)
+const ATTEMPTS: u32 = 2;
+
+/// Shared client (connection pool reused across posts)
+static CLIENT: OnceLock<Option<reqwest::Client>> = OnceLock::new();
+
+#[derive(Serialize)]
+struct Payload<'a> {
+    #[serde(flatten)]
+    notification: &'a Notification,
+    /// Slack message text
+    text: String,
+    /// Discord message content
+    content: String,
+}
+
+/// POST `notif` to `url` from a task spawned on `rt`; returns immediately
+pub fn post(rt: &Runtime, url: &str, notif: &Notification) {
+    let Some(client) = client() else {
+        return;
+    };
+    let summary = format!(
+        "[{}] {}: {}",
+        severity_label(notif.severity),
+        notif.symbol,
+        notif.message
+    );
+    let payload = Payload {
+        notification: notif,
+        text: summary.clone(),
+        content: summary,
+    };
+    let body = match serde_json::to_vec(&payload) {
+        Ok(body) => body,
+        Err(e) => {
+            eprintln!("Failed to serialize webhook payload: {}", e);
+            return;
+        }
+    };
+    let url = url.to_string();
+
+    rt.spawn(async move {
+        for attempt in 1..=ATTEMPTS {
+            let result = client
+                .post(&url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.clone())
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            match result {
+                Ok(_) => return,
+                Err(e) if attempt == ATTEMPTS => {
+                    eprintln!("Webhook delivery failed: {}", e);
+                }
+                Err(_) => {}
+            }
+        }
+    });
+}
+
+fn client() -> Option<reqwest::Client> {
+    CLIENT
+        .get_or_init(
+            || match reqwest::Client::builder().timeout(TIMEOUT).build() {
+                Ok(client) => Some(client),
+                Err(e) => {
+                    eprintln!("Failed to create webhook client: {}", e);
+                    None
+                }
+            },
+        )
+        .clone()
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "INFO",
+        Severity::Warning => "WARNING",
+        Severity::Critical => "CRITICAL",
+    }
+}
@@ -0,0 +1,121 @@
+//! Session persistence - the coins, view and chart settings restored on the next launch
+
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::app::{App, ChartType, View, MAX_CHECKED_COINS};
+
+const SESSION_FILE: &str = "session.json";
+
+/// UI state saved on quit
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SessionState {
+    /// Checked coins by symbol, so the selection survives pair-list changes
+    #[serde(default)]
+    pub checked: Vec<String>,
+    /// Highlighted coin symbol in the overview
+    #[serde(default)]
+    pub selected: Option<String>,
+    #[serde(default)]
+    pub view: Option<View>,
+    #[serde(default)]
+    pub chart_type: Option<ChartType>,
+    /// Candle interval, e.g. "1h"
+    #[serde(default)]
+    pub time_window: Option<String>,
+}
+
+impl SessionState {
+    /// Capture the current session from the app
+    pub fn from_app(app: &App) -> Self {
+        Self {
+            checked: app
+                .selected_coins_with_index()
+                .into_iter()
+                .map(|(_, coin)| coin.symbol.clone())
+                .collect(),
+            selected: app.coins.get(app.selected_index).map(|c| c.symbol.clone()),
+            view: Some(app.view),
+            chart_type: Some(app.chart_type),
+            time_window: Some(app.time_window().interval().to_string()),
+        }
+    }
+
+    /// Apply the saved state to the app; symbols and intervals that no longer
+    /// exist are ignored, and at most `MAX_CHECKED_COINS` coins are checked
+    pub fn restore(&self, app: &mut App) {
+        let mut restored = 0;
+        for (i, coin) in app.coins.iter().enumerate() {
+            if restored < MAX_CHECKED_COINS && self.checked.contains(&coin.symbol) {
+                app.checked[i] = true;
+                restored += 1;
+            }
+        }
+
+        if let Some(symbol) = &self.selected {
+            if let Some(i) = app.coins.iter().position(|c| &c.symbol == symbol) {
+                app.selected_index = i;
+            }
+        }
+        if let Some(view) = self.view {
+            app.enter_view(view);
+        }
+        if let Some(chart_type) = self.chart_type {
+            app.chart_type = chart_type;
+        }
+        if let Some(interval) = &self.time_window {
+            app.select_time_window(interval);
+        }
+    }
+}
+
+/// Session file path (next to the executable, like config.json)
+fn session_path() -> PathBuf {
+    if let Ok(exe_path) = env::current_exe() {
+        if let Some(exe_dir) = exe_path.parent() {
+            return exe_dir.join(SESSION_FILE);
+        }
+    }
+
+    PathBuf::from(SESSION_FILE)
+}
+
+/// Load the saved session; None if there is none or it can't be parsed
+pub fn load_session() -> Option<SessionState> {
+    let path = session_path();
+    if !path.exists() {
+        return None;
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(session) => Some(session),
+            Err(e) => {
+                eprintln!("Failed to parse session file: {}", e);
+                None
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to read session file: {}", e);
+            None
+        }
+    }
+}
+
+/// Save the current session for the next launch
+pub fn save_session(app: &App) {
+    let path = session_path();
+
+    match serde_json::to_string_pretty(&SessionState::from_app(app)) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                eprintln!("Failed to write session file: {}", e);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to serialize session: {}", e);
+        }
+    }
+}
@@ -1,18 +1,20 @@
 //! Details view - price charts and indicators for selected coins
 
-use std::time::{SystemTime, UNIX_EPOCH};
-
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
-use crate::app::{App, ChartType, TimeWindow};
+use super::build_header;
+use crate::api::binance::candle_open_offset;
+use crate::app::{App, ChartType};
 use crate::base::view::ViewSpacing;
+use crate::clock::unix_now;
+use crate::config::SparklineStyle;
 use crate::mock::CoinData;
 use crate::widgets::{
     control_footer::build_details_footer,
-    indicator_panel::build_indicator_panel,
-    price_panel::build_price_panel,
-    status_header::build_status_header,
+    indicator_panel::{build_indicator_panel, build_indicator_strip},
+    price_panel::{build_price_panel, PriceStyle},
+    sparkline::sparkline_text,
     theme::GlTheme,
     titled_panel::{titled_panel, titled_panel_with_badge},
 };
@@ -20,6 +22,12 @@ use crate::widgets::{
 /// Prefix for chart panel marker IDs
 pub const CHART_PANEL_PREFIX: &str = "chart_";
 
+/// Columns narrower than this show indicators as a compact single-line strip
+const COMPACT_INDICATOR_WIDTH: f32 = 420.0;
+
+/// Candles shown in the trade count sparkline
+const TRADES_SPARKLINE_CANDLES: usize = 40;
+
 /// Represents a chart area that needs to be rendered separately
 #[derive(Clone, Debug)]
 pub struct ChartArea {
@@ -43,9 +51,36 @@ pub fn build_details_view(
     let active_coins = app.active_coins();
     let count = active_coins.len();
     let spacing = ViewSpacing::new(theme);
+    let responsive = app.breakpoints.resolve(width, height);
 
     let mut chart_areas = Vec::new();
 
+    // Narrow displays stack coins vertically instead of side by side
+    let (direction, across) = if responsive.narrow {
+        (FlexDirection::Column, 1)
+    } else {
+        (FlexDirection::Row, count)
+    };
+    // Stacked columns are too short to also fit the indicator panel
+    let stacked = responsive.narrow && count > 1;
+    let layout = ColumnLayout {
+        show_indicators: !(responsive.short || stacked),
+        show_range: !responsive.narrow,
+    };
+
+    // Switch to the compact indicator strip when configured or when columns get narrow
+    let column_width = (width
+        - spacing.outer_padding * 2.0
+        - spacing.column_gap * across.saturating_sub(1) as f32)
+        / across.max(1) as f32;
+    let indicator_strip_width = if app.compact_indicators || column_width < COMPACT_INDICATOR_WIDTH
+    {
+        // Titled panel chrome: border plus padding on both sides
+        Some((column_width - theme.panel_padding * 2.0 - 2.0).max(0.0))
+    } else {
+        None
+    };
+
     // Build coin columns
     let columns: Vec<PanelBuilder> = active_coins
         .iter()
@@ -54,9 +89,9 @@ pub fn build_details_view(
             chart_areas.push(ChartArea::new(*coin_idx));
             build_coin_column(
                 coin,
-                count,
-                app.time_window,
-                app.chart_type,
+                indicator_strip_width,
+                layout,
+                app,
                 chart_idx,
                 theme,
                 &spacing,
@@ -64,67 +99,98 @@ pub fn build_details_view(
         })
         .collect();
 
-    let view =
-        panel()
-            .width(length(width))
-            .height(length(height))
-            .flex_direction(FlexDirection::Column)
-            .gap(spacing.section_gap)
-            .padding_all(spacing.outer_padding)
-            .background(theme.background)
-            // Header
-            .child(build_status_header(
-                app.view,
-                &app.provider,
-                app.time_window,
-                app.chart_type,
-                app.connection_status,
-                app.notification_manager.unread_count,
-                theme,
-            ))
-            // Coin columns (horizontal layout)
-            .child(
-                panel()
-                    .flex_grow(1.0)
-                    .flex_direction(FlexDirection::Row)
-                    .gap(spacing.column_gap)
-                    .children(columns),
-            )
-            // Footer
-            .child(
-                build_details_footer(app.time_window, app.chart_type, app.ticker_muted, theme)
-                    .margin(spacing.footer_margin(), 0.0, 0.0, 0.0),
-            );
+    let footer = (!responsive.short).then(|| {
+        build_details_footer(
+            app.time_window(),
+            app.chart_type,
+            app.ticker_muted,
+            app.percent_grid,
+            &app.keymap,
+            theme,
+        )
+        .margin(spacing.footer_margin(), 0.0, 0.0, 0.0)
+    });
+
+    let view = panel()
+        .width(length(width))
+        .height(length(height))
+        .flex_direction(FlexDirection::Column)
+        .gap(spacing.section_gap)
+        .padding_all(spacing.outer_padding)
+        .background(theme.background)
+        // Header
+        .children(build_header(app, theme))
+        // Coin columns (horizontal layout, stacked when narrow)
+        .child(
+            panel()
+                .flex_grow(1.0)
+                .flex_direction(direction)
+                .gap(spacing.column_gap)
+                .children(columns),
+        )
+        // Footer (hidden on short displays)
+        .children(footer);
 
     (view, chart_areas)
 }
 
+/// Which optional panels a coin column shows at the current breakpoint
+#[derive(Clone, Copy)]
+struct ColumnLayout {
+    show_indicators: bool,
+    show_range: bool,
+}
+
 fn build_coin_column(
     coin: &CoinData,
-    _total_columns: usize,
-    time_window: TimeWindow,
-    chart_type: ChartType,
+    indicator_strip_width: Option<f32>,
+    layout: ColumnLayout,
+    app: &App,
     chart_idx: usize,
     theme: &GlTheme,
     spacing: &ViewSpacing,
 ) -> PanelBuilder {
     let gap = spacing.section_gap;
     let symbol = &coin.symbol;
+    let time_window = app.time_window();
+
+    // Lock indicator when the Y-axis scale is pinned
+    let chart_title = if coin.locked_price_range.is_some() {
+        "Chart [LOCK]"
+    } else {
+        "Chart"
+    };
 
     // Build chart panel with countdown badge for candlestick mode
-    let chart_panel = match chart_type {
+    let chart_panel = match app.chart_type {
         ChartType::Candlestick => {
-            let countdown = candle_countdown(time_window.granularity() as u64);
+            let countdown = candle_countdown(time_window.granularity() as u64, unix_now());
             titled_panel_with_badge(
-                "Chart",
+                chart_title,
                 Some((&countdown, theme.accent_secondary)),
                 theme,
                 build_chart_placeholder(chart_idx),
             )
         }
-        ChartType::Polygonal => titled_panel("Chart", theme, build_chart_placeholder(chart_idx)),
+        ChartType::Polygonal => {
+            titled_panel(chart_title, theme, build_chart_placeholder(chart_idx))
+        }
     };
 
+    let indicators = layout.show_indicators.then(|| {
+        let content = match indicator_strip_width {
+            Some(strip_width) => {
+                build_indicator_strip(&coin.indicators, coin.price, strip_width, theme)
+            }
+            None => build_indicator_panel(symbol, &coin.indicators, coin.price, theme),
+        };
+        titled_panel("Indicators", theme, content)
+    });
+
+    let trades = (app.trades_sparkline && layout.show_indicators)
+        .then(|| build_trades_sparkline(coin, app.sparkline_style, theme))
+        .flatten();
+
     panel()
         .flex_basis(length(0.0)) // Force equal width distribution
         .flex_grow(1.0)
@@ -132,18 +198,63 @@ fn build_coin_column(
         .gap(gap)
         // Price panel with title
         .child(titled_panel(
-            &format!("{}/USD ({})", symbol, time_window.as_str()),
+            &price_title(coin, time_window.as_str()),
             theme,
-            build_price_panel(coin, time_window, theme),
+            build_price_panel(
+                coin,
+                time_window,
+                coin.tick_pulse(app.price_pulse),
+                PriceStyle {
+                    compact: app.compact_price,
+                    group_thousands: app.group_thousands,
+                    neutral_zone_pct: app.neutral_zone_pct,
+                },
+                layout.show_range,
+                theme,
+            ),
         ))
+        // Trade count sparkline (when enabled and the candles carry trade counts)
+        .children(trades)
         // Chart area (grows to fill, placeholder for ChartRenderer)
         .child(chart_panel.flex_grow(1.0))
-        // Indicator panel with title
-        .child(titled_panel(
-            "Indicators",
-            theme,
-            build_indicator_panel(&coin.indicators, theme),
-        ))
+        // Indicator panel with title (hidden on short or stacked layouts)
+        .children(indicators)
+}
+
+/// Per-candle trade counts of the latest candles as a one-line sparkline;
+/// None when no candle has a trade count (stream-only candles, non-Binance sources)
+fn build_trades_sparkline(
+    coin: &CoinData,
+    style: SparklineStyle,
+    theme: &GlTheme,
+) -> Option<PanelBuilder> {
+    let trades: Vec<u64> = coin
+        .candles
+        .iter()
+        .map(|c| c.trades)
+        .filter(|&t| t > 0)
+        .collect();
+    let recent = &trades[trades.len().saturating_sub(TRADES_SPARKLINE_CANDLES)..];
+    let last = *recent.last()?;
+
+    Some(titled_panel(
+        &format!("Trades ({})", last),
+        theme,
+        panel().text(
+            sparkline_text(recent, style),
+            theme.accent,
+            theme.font_normal,
+        ),
+    ))
+}
+
+/// Price panel title: pair, display name (when it differs from the symbol) and window
+fn price_title(coin: &CoinData, window: &str) -> String {
+    if coin.name == coin.symbol {
+        format!("{}/USD ({})", coin.symbol, window)
+    } else {
+        format!("{}/USD {} ({})", coin.symbol, coin.name, window)
+    }
 }
 
 fn build_chart_placeholder(chart_idx: usize) -> PanelBuilder {
@@ -155,15 +266,11 @@ fn build_chart_placeholder(chart_idx: usize) -> PanelBuilder {
         .marker_id(format!("{}{}", CHART_PANEL_PREFIX, chart_idx))
 }
 
-/// Calculate time remaining until current candle closes
-fn candle_countdown(granularity_secs: u64) -> String {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-
+/// Calculate time remaining at `now` (Unix seconds) until the current candle closes
+fn candle_countdown(granularity_secs: u64, now: u64) -> String {
     // Calculate seconds until next candle boundary
-    let elapsed_in_candle = now % granularity_secs;
+    let offset = candle_open_offset(granularity_secs as u32) as u64;
+    let elapsed_in_candle = (now + granularity_secs - offset % granularity_secs) % granularity_secs;
     let remaining = granularity_secs - elapsed_in_candle;
 
     // Format as HH:MM:SS or MM:SS depending on duration
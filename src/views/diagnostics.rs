@@ -0,0 +1,121 @@
+//! Diagnostics view - connection event history for debugging flaky feeds
+
+use crate::base::{panel, taffy, PanelBuilder};
+use taffy::prelude::*;
+
+use super::build_header;
+use crate::app::App;
+use crate::base::layout::{HAlign, VAlign};
+use crate::base::view::ViewSpacing;
+use crate::connection_log::{ConnectionEventKind, ConnectionLog};
+use crate::widgets::{
+    control_footer::build_diagnostics_footer,
+    table::{build_table, estimate_column_widths, CellBuilder, ColumnConfig, TableRow},
+    theme::GlTheme,
+    titled_panel::titled_panel,
+};
+
+/// Build the diagnostics view
+pub fn build_diagnostics_view(app: &App, theme: &GlTheme, width: f32, height: f32) -> PanelBuilder {
+    let spacing = ViewSpacing::new(theme);
+
+    panel()
+        .width(length(width))
+        .height(length(height))
+        .flex_direction(FlexDirection::Column)
+        .gap(spacing.section_gap)
+        .padding_all(spacing.outer_padding)
+        .background(theme.background)
+        // Header
+        .children(build_header(app, theme))
+        // Connection history (newest first)
+        .child(
+            titled_panel(
+                &format!("Connection History ({})", app.connection_log.count()),
+                theme,
+                panel()
+                    .flex_grow(1.0)
+                    .child(build_connection_table(&app.connection_log, theme)),
+            )
+            .flex_grow(1.0),
+        )
+        // Text renderer health
+        .child(build_glyph_stats(app, theme))
+        // Border style samples, only with --diagnostics-samples
+        .children(app.diagnostics_samples.then(|| build_border_samples(theme)))
+        // Footer
+        .child(build_diagnostics_footer(&app.keymap, theme).margin(
+            spacing.footer_margin(),
+            0.0,
+            0.0,
+            0.0,
+        ))
+}
+
+fn build_connection_table(log: &ConnectionLog, theme: &GlTheme) -> PanelBuilder {
+    let columns = vec![
+        ColumnConfig::auto("TIME", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("EVENT", 0.0).with_align(HAlign::Left),
+        ColumnConfig::flex("DETAIL", 1.0).with_align(HAlign::Left),
+    ];
+
+    let rows: Vec<TableRow> = log
+        .newest_first()
+        .map(|event| {
+            let color = match event.kind {
+                ConnectionEventKind::Connected => theme.positive,
+                ConnectionEventKind::Disconnected | ConnectionEventKind::Error => theme.negative,
+                ConnectionEventKind::Reconnecting => theme.status_connecting,
+            };
+            vec![
+                CellBuilder::text(event.time_str(), theme.foreground_muted),
+                CellBuilder::text(event.kind.label(), color),
+                CellBuilder::text(&event.detail, theme.foreground),
+            ]
+        })
+        .collect();
+
+    let columns = estimate_column_widths(
+        &columns,
+        &rows,
+        theme.font_size,
+        theme.font_normal,
+        theme.panel_gap * 2.0,
+    );
+
+    build_table(&columns, &rows, false, theme)
+}
+
+/// Count of characters drawn without a glyph (missing from the font atlas)
+fn build_glyph_stats(app: &App, theme: &GlTheme) -> PanelBuilder {
+    let color = if app.dropped_glyphs > 0 {
+        theme.neutral
+    } else {
+        theme.foreground_muted
+    };
+    panel()
+        .flex_direction(FlexDirection::Row)
+        .gap(theme.panel_gap)
+        .child(panel().text("Missing glyphs", theme.accent_secondary, theme.font_normal))
+        .child(panel().text(app.dropped_glyphs.to_string(), color, theme.font_normal))
+}
+
+/// One panel per layout border style, to check the renderer draws each
+/// (not focusable, so keyboard navigation skips them)
+fn build_border_samples(theme: &GlTheme) -> PanelBuilder {
+    let sample = |name: &str| {
+        panel()
+            .flex_grow(1.0)
+            .padding_all(theme.panel_padding)
+            .text(name, theme.foreground_muted, theme.font_small)
+            .text_align(HAlign::Center, VAlign::Center)
+    };
+
+    panel()
+        .width(percent(1.0))
+        .flex_direction(FlexDirection::Row)
+        .gap(theme.panel_gap * 2.0)
+        .child(sample("solid").border_solid(2.0, theme.border))
+        .child(sample("dashed").border_dashed(2.0, theme.border))
+        .child(sample("dotted").border_dotted(2.0, theme.border))
+}
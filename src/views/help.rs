@@ -0,0 +1,133 @@
+//! Help dialog - key bindings, shown as a modal over the current view
+
+use crate::base::{panel, taffy, PanelBuilder};
+use taffy::prelude::*;
+
+use crate::keymap::{Action, Keymap};
+use crate::widgets::{modal::modal, theme::GlTheme};
+
+/// Keys of a help row: every key of one action, or the first keys of an opposite pair
+#[derive(Clone, Copy)]
+enum Keys {
+    One(Action),
+    Pair(Action, Action),
+}
+
+use Action::*;
+use Keys::{One, Pair};
+
+/// Key binding groups: (section, [(keys, description)])
+const SECTIONS: [(&str, &[(Keys, &str)]); 5] = [
+    (
+        "General",
+        &[
+            (One(NextView), "Switch view"),
+            (One(ToggleMute), "Mute ticker tones"),
+            (One(Export), "Export snapshot"),
+            (Pair(GammaDown, GammaUp), "Adjust gamma"),
+            (Pair(VolumeDown, VolumeUp), "Adjust volume"),
+            (One(ToggleHeader), "Toggle header"),
+            (One(ToggleFps), "FPS overlay"),
+            (One(ReloadConfig), "Reload config"),
+            (One(Search), "Search coins"),
+            (One(Help), "Toggle help"),
+            (One(Quit), "Quit"),
+        ],
+    ),
+    (
+        "Overview",
+        &[
+            (Pair(MoveUp, MoveDown), "Move selection"),
+            (Pair(CycleSort, ReverseSort), "Sort column / reverse"),
+            (One(Select), "Check coin"),
+            (One(Copy), "Copy price"),
+            (One(AddAlert), "Add price alert"),
+        ],
+    ),
+    (
+        "Details",
+        &[
+            (
+                Pair(ScrollLeft, ScrollRight),
+                "Scroll candles / move crosshair",
+            ),
+            (One(Crosshair), "Crosshair"),
+            (One(Macd), "MACD panel"),
+            (Pair(ZoomIn, ZoomOut), "Zoom"),
+            (One(FitCandles), "Fit all candles"),
+            (One(CycleWindow), "Cycle interval"),
+            (One(ChartType), "Chart type"),
+            (One(PercentGrid), "Percent grid"),
+            (One(ToggleScale), "Log/linear scale"),
+            (One(Ema), "EMA lines"),
+            (Pair(LockScale, UnlockScale), "Lock/unlock scale"),
+            (One(FocusIndicators), "Focus indicators"),
+        ],
+    ),
+    (
+        "Notifications",
+        &[
+            (Pair(MoveUp, MoveDown), "Select rule"),
+            (One(Select), "Toggle rule"),
+            (One(SeverityFilter), "Severity filter"),
+            (Pair(PrevAlert, NextAlert), "Select alert"),
+            (One(DismissAlert), "Dismiss alert"),
+            (One(ClearAlerts), "Clear all alerts"),
+        ],
+    ),
+    (
+        "News",
+        &[
+            (Pair(MoveUp, MoveDown), "Select article"),
+            (Pair(ArticleUp, ArticleDown), "Scroll article"),
+            (One(NewsFilter), "Checked coins only"),
+            (One(Refresh), "Refresh"),
+        ],
+    ),
+];
+
+/// Build the help modal; each section is focusable so Tab cycles through them
+pub fn build_help_modal(keymap: &Keymap, theme: &GlTheme) -> PanelBuilder {
+    let sections = SECTIONS
+        .iter()
+        .map(|(title, bindings)| build_section(title, bindings, keymap, theme));
+
+    let body = panel()
+        .flex_direction(FlexDirection::Row)
+        .gap(theme.panel_gap * 2.0)
+        .children(sections);
+
+    modal("Help", body, theme)
+}
+
+fn build_section(
+    title: &str,
+    bindings: &[(Keys, &str)],
+    keymap: &Keymap,
+    theme: &GlTheme,
+) -> PanelBuilder {
+    let rows = bindings.iter().map(|&(keys, description)| {
+        let keys = match keys {
+            One(action) => keymap.label(action),
+            Pair(first, second) => keymap.pair_label(first, second),
+        };
+        panel()
+            .flex_direction(FlexDirection::Row)
+            .gap(theme.panel_gap)
+            .child(panel().width(length(theme.font_size * 5.0)).text(
+                format!("[{}]", keys),
+                theme.accent_secondary,
+                theme.font_normal,
+            ))
+            .child(panel().text(description, theme.foreground, theme.font_normal))
+    });
+
+    panel()
+        .focusable(format!("help_{}", title.to_lowercase()))
+        .focus_border(theme.border_focus)
+        .flex_direction(FlexDirection::Column)
+        .gap(theme.panel_gap / 2.0)
+        .padding_all(theme.panel_padding)
+        .child(panel().text(title, theme.accent, theme.font_small))
+        .children(rows)
+}
@@ -1,13 +1,39 @@
 //! OpenGL view compositions for the crypto dashboard
 
 pub mod details;
+pub mod diagnostics;
+pub mod help;
 pub mod news;
 pub mod notifications;
 pub mod overview;
 pub mod positions;
+pub mod search;
 
 pub use details::{build_details_view, ChartArea, CHART_PANEL_PREFIX};
-pub use news::build_news_view;
+pub use diagnostics::build_diagnostics_view;
+pub use help::build_help_modal;
+pub use news::{build_news_view, NEWS_CONTENT_ID};
 pub use notifications::build_notifications_view;
 pub use overview::build_overview_view;
 pub use positions::build_positions_view;
+pub use search::build_search_overlay;
+
+use crate::app::App;
+use crate::base::PanelBuilder;
+use crate::widgets::{status_header::build_status_header, theme::GlTheme};
+
+/// Status header shared by all views; None when hidden ('f' or `display.show_header`)
+fn build_header(app: &App, theme: &GlTheme) -> Option<PanelBuilder> {
+    app.show_header.then(|| {
+        build_status_header(
+            app.view,
+            &app.provider,
+            app.connection_status,
+            app.connection_is_stale(app.stale_after_secs),
+            app.notification_manager
+                .unread_count_filtered(app.notification_filter),
+            &app.keymap,
+            theme,
+        )
+    })
+}
@@ -1,25 +1,28 @@
 //! News view - cryptocurrency news from NewsData.io
 
-use crate::api::news::{format_relative_time, has_api_keys};
+use super::build_header;
+use crate::api::news::{format_relative_time, has_api_keys, NewsArticle};
 use crate::app::App;
 use crate::base::{
     panel,
+    scrollable::{ScrollState, SCROLLBAR_GAP, SCROLLBAR_WIDTH},
     view::{ViewMetrics, ViewSpacing},
     PanelBuilder,
 };
+use crate::keymap::Action;
 use crate::widgets::{
-    control_footer::build_news_footer,
-    status_header::build_status_header,
-    text_box::{build_text_box, char_width_px},
-    theme::GlTheme,
+    control_footer::build_news_footer, text_box::build_text_box, theme::GlTheme,
     titled_panel::titled_panel,
 };
 use taffy::prelude::*;
 
+/// Marker id of the article body text box (its wrapped length bounds the scroll)
+pub const NEWS_CONTENT_ID: &str = "news_content";
+
 /// Build the news view
 pub fn build_news_view(app: &App, theme: &GlTheme, width: f32, height: f32) -> PanelBuilder {
     let spacing = ViewSpacing::new(theme);
-    let metrics = ViewMetrics::new(width, height, &spacing, theme);
+    let metrics = ViewMetrics::new(width, height, app.show_header, &spacing, theme);
 
 
     panel()
@@ -30,39 +33,31 @@ pub fn build_news_view(app: &App, theme: &GlTheme, width: f32, height: f32) -> P
         .padding_all(spacing.outer_padding)
         .background(theme.background)
         // Header - fixed height
-        .child(build_status_header(
-            app.view,
-            &app.provider,
-            app.time_window,
-            app.chart_type,
-            app.connection_status,
-            app.notification_manager.unread_count,
+        .children(build_header(app, theme))
+        // Main content: headlines + article content
+        .child(build_news_content(
+            app,
             theme,
+            metrics.content_height,
+            &spacing,
         ))
-        // Main content: headlines + article content
+        // Footer - fixed height with extra top margin
         .child(
-            build_news_content(
-                app,
+            build_news_footer(
+                app.news_loading,
+                app.news_refresh_in(),
+                app.news_filter_selected,
+                &app.keymap,
                 theme,
-                metrics.inner_width,
-                metrics.content_height,
-                &spacing,
             )
+            .margin(spacing.footer_margin(), 0.0, 0.0, 0.0),
         )
-        // Footer - fixed height with extra top margin
-        .child(build_news_footer(app.news_loading, theme).margin(
-            spacing.footer_margin(),
-            0.0,
-            0.0,
-            0.0,
-        ))
 }
 
 /// Build the main news content with 30/70 split layout
 fn build_news_content(
     app: &App,
     theme: &GlTheme,
-    width: f32,
     available_height: f32,
     spacing: &ViewSpacing,
 ) -> PanelBuilder {
@@ -89,8 +84,8 @@ fn build_news_content(
             ));
     }
 
-    // Show loading state
-    if app.news_loading {
+    // Show loading state (a background refresh keeps the current articles up)
+    if app.news_loading && app.news_articles.is_empty() {
         return panel()
             .flex_grow(1.0)
             .justify_content(JustifyContent::Center)
@@ -99,23 +94,27 @@ fn build_news_content(
     }
 
     // Show empty state
-    if app.news_articles.is_empty() {
+    let articles = app.visible_news();
+    if articles.is_empty() {
+        let (message, action, hint) = if app.news_articles.is_empty() {
+            ("No news articles", Action::Refresh, "refresh")
+        } else {
+            (
+                "No articles mention the checked coins",
+                Action::NewsFilter,
+                "show all",
+            )
+        };
+        let hint = format!("Press [{}] to {}", app.keymap.label(action), hint);
+
         return panel()
             .flex_grow(1.0)
             .flex_direction(FlexDirection::Column)
             .gap(gap)
             .justify_content(JustifyContent::Center)
             .align_items(AlignItems::Center)
-            .child(panel().text(
-                "No news articles",
-                theme.foreground_muted,
-                theme.font_normal,
-            ))
-            .child(panel().text(
-                "Press [r] to refresh",
-                theme.foreground_muted,
-                theme.font_small,
-            ));
+            .child(panel().text(message, theme.foreground_muted, theme.font_normal))
+            .child(panel().text(&hint, theme.foreground_muted, theme.font_small));
     }
 
     // Split layout: headlines (30%) and content (70%)
@@ -133,30 +132,29 @@ fn build_news_content(
             titled_panel(
                 "Headlines",
                 theme,
-                build_headlines_list(app, theme, width, headlines_height),
+                build_headlines_list(app, &articles, theme, headlines_height),
             )
                 .height(length(headlines_height))
                 .flex_shrink(0.0),
         )
         // Content panel (70%)
         .child(
-            build_content_panel(app, theme)
+            build_content_panel(app, &articles, theme)
                 .height(length(content_height))
                 .flex_shrink(0.0),
         )
 }
 
 /// Build the headlines list (titles only, compact)
-fn build_headlines_list(app: &App, theme: &GlTheme, width: f32, available_height: f32) -> PanelBuilder {
+fn build_headlines_list(
+    app: &App,
+    articles: &[&NewsArticle],
+    theme: &GlTheme,
+    available_height: f32,
+) -> PanelBuilder {
     let gap = theme.panel_gap;
 
-    // Calculate max characters for headline truncation
-    // Account for panel chrome: border, padding, titled_panel overhead
-    let usable_width = width - theme.panel_padding * 4.0 - 4.0;
-    let char_width = char_width_px('M', theme.font_size * theme.font_small).max(1.0);
-    let max_chars = ((usable_width / char_width).floor() as usize).clamp(5, 150);
-
-    let total = app.news_articles.len();
+    let total = articles.len();
     let mut container = panel()
         .flex_direction(FlexDirection::Column)
         .gap(2.0)
@@ -180,9 +178,14 @@ fn build_headlines_list(app: &App, theme: &GlTheme, width: f32, available_height
     };
 
     let end = (scroll_offset + visible_count).min(total);
+    let scroll = ScrollState::rows(scroll_offset, visible_count, total);
+    if scroll.can_scroll() {
+        // Keep full-width rows clear of the scrollbar
+        container = container.padding(0.0, SCROLLBAR_WIDTH + SCROLLBAR_GAP, 0.0, 0.0);
+    }
+    container = container.scrollbar(scroll, theme.border, theme.foreground_muted);
 
-    for (idx, article) in app
-        .news_articles
+    for (idx, article) in articles
         .iter()
         .enumerate()
         .skip(scroll_offset)
@@ -202,13 +205,15 @@ fn build_headlines_list(app: &App, theme: &GlTheme, width: f32, available_height
             theme.foreground_muted
         };
 
-        let title = truncate_text(&article.title, max_chars);
-
         container = container.child(
             panel()
                 .padding(2.0, gap / 2.0, 2.0, gap / 2.0)
                 .background(bg_color)
-                .child(panel().text(&title, text_color, theme.font_small)),
+                .child(panel().width(percent(1.0)).ellipsize(true).text(
+                    &article.title,
+                    text_color,
+                    theme.font_small,
+                )),
         );
     }
 
@@ -225,15 +230,12 @@ fn build_headlines_list(app: &App, theme: &GlTheme, width: f32, available_height
 }
 
 /// Build the content panel with article body (70% of space)
-fn build_content_panel(
-    app: &App,
-    theme: &GlTheme,
-) -> PanelBuilder {
+fn build_content_panel(app: &App, articles: &[&NewsArticle], theme: &GlTheme) -> PanelBuilder {
     let gap = theme.panel_gap;
 
     // Get selected article
-    let article = match app.news_articles.get(app.news_selected) {
-        Some(a) => a,
+    let article = match articles.get(app.news_selected) {
+        Some(a) => *a,
         None => {
             return panel()
                 .background(theme.background_panel)
@@ -283,24 +285,8 @@ fn build_content_panel(
         )
         // Article body with text reflow and scrolling
         .child(
-            build_text_box(
-                content,
-                app.news_content_scroll,
-                theme,
-            )
-            .flex_grow(1.0),
+            build_text_box(content, app.news_content_offset(), theme)
+                .marker_id(NEWS_CONTENT_ID)
+                .flex_grow(1.0),
         )
 }
-
-/// Truncate text to fit within max characters
-fn truncate_text(text: &str, max_chars: usize) -> String {
-    if max_chars < 4 {
-        return text.chars().take(max_chars).collect();
-    }
-    if text.chars().count() <= max_chars {
-        text.to_string()
-    } else {
-        let truncated: String = text.chars().take(max_chars - 3).collect();
-        format!("{}...", truncated)
-    }
-}
@@ -3,14 +3,18 @@
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
-use crate::app::App;
+use super::build_header;
+use crate::app::{App, NOTIFICATION_VISIBLE_ROWS};
+use crate::base::scrollable::{ScrollState, SCROLLBAR_GAP, SCROLLBAR_WIDTH};
 use crate::base::view::ViewSpacing;
 use crate::notifications::{NotificationRule, Severity};
 use crate::widgets::{
-    control_footer::build_notifications_footer, status_header::build_status_header, theme::GlTheme,
-    titled_panel::titled_panel,
+    control_footer::build_notifications_footer, theme::GlTheme, titled_panel::titled_panel,
 };
 
+/// Alert row height in font sizes; fixed so a smooth scroll can move rows by part of a row
+const ROW_HEIGHT_EM: f32 = 1.4;
+
 /// Build the notifications view
 pub fn build_notifications_view(
     app: &App,
@@ -20,6 +24,7 @@ pub fn build_notifications_view(
 ) -> PanelBuilder {
     let spacing = ViewSpacing::new(theme);
     let gap = spacing.section_gap;
+    let footer = build_notifications_footer(app.notification_filter, &app.keymap, theme);
 
     panel()
         .width(length(width))
@@ -29,15 +34,7 @@ pub fn build_notifications_view(
         .padding_all(spacing.outer_padding)
         .background(theme.background)
         // Header
-        .child(build_status_header(
-            app.view,
-            &app.provider,
-            app.time_window,
-            app.chart_type,
-            app.connection_status,
-            app.notification_manager.unread_count,
-            theme,
-        ))
+        .children(build_header(app, theme))
         // Main content: two columns
         .child(
             panel()
@@ -64,7 +61,7 @@ pub fn build_notifications_view(
                 ),
         )
         // Footer with controls
-        .child(build_notifications_footer(theme).margin(spacing.footer_margin(), 0.0, 0.0, 0.0))
+        .child(footer.margin(spacing.footer_margin(), 0.0, 0.0, 0.0))
 }
 
 /// Build the list of notification rules with toggle checkboxes
@@ -118,8 +115,13 @@ fn build_rules_list(rules: &[NotificationRule], selected: usize, theme: &GlTheme
 /// Build the notification history list
 fn build_notification_list(app: &App, theme: &GlTheme) -> PanelBuilder {
     let gap = theme.panel_gap;
-    let notifications = app.notification_manager.get_notifications();
-    let scroll_offset = app.notification_scroll;
+    let notifications = app
+        .notification_manager
+        .notifications_filtered(app.notification_filter);
+    // The filtered list can shrink below the stored offset
+    let scroll_offset = app
+        .notification_offset()
+        .min(notifications.len().saturating_sub(1) as f32);
 
     let mut container = panel()
         .flex_direction(FlexDirection::Column)
@@ -127,19 +129,41 @@ fn build_notification_list(app: &App, theme: &GlTheme) -> PanelBuilder {
         .padding_all(gap / 2.0);
 
     if notifications.is_empty() {
-        container = container.child(panel().text(
-            "No alerts yet",
-            theme.foreground_muted,
-            theme.font_normal,
-        ));
+        let empty = if app.notification_manager.notifications.is_empty() {
+            "No alerts yet"
+        } else {
+            "No alerts match the filter"
+        };
+        container = container.child(panel().text(empty, theme.foreground_muted, theme.font_normal));
     } else {
         // Show most recent first, apply scroll offset
-        let visible_count = 15; // Show up to 15 notifications
-        let start = scroll_offset;
+        let visible_count = NOTIFICATION_VISIBLE_ROWS;
+        let start = scroll_offset.floor() as usize;
         let end = (start + visible_count).min(notifications.len());
+        let row_height = theme.font_size * theme.font_small * ROW_HEIGHT_EM;
+        // Mid-scroll the rows sit part of a row higher, with one more coming in below
+        let shift = (scroll_offset - start as f32) * (row_height + gap);
+        let drawn = (end + usize::from(shift > 0.0)).min(notifications.len());
+        let mut scroll = ScrollState::rows(start, visible_count, notifications.len());
+        scroll.scroll_to(scroll_offset);
+        if scroll.can_scroll() {
+            // Keep full-width rows clear of the scrollbar
+            let right = gap / 2.0 + SCROLLBAR_WIDTH + SCROLLBAR_GAP;
+            container = container.padding(gap / 2.0, right, gap / 2.0, gap / 2.0);
+        }
+        container = container.scrollbar(scroll, theme.border, theme.foreground_muted);
+
+        let window_rows = visible_count.min(notifications.len()) as f32;
+        let mut window = panel()
+            .flex_direction(FlexDirection::Column)
+            .gap(gap)
+            .height(length(window_rows * (row_height + gap) - gap))
+            .clip(true)
+            .scroll_offset(shift);
 
         // Iterate in reverse (newest first)
-        for notif in notifications.iter().rev().skip(start).take(end - start) {
+        let rows = notifications.iter().rev().enumerate();
+        for (position, notif) in rows.skip(start).take(drawn - start) {
             let severity_color = match notif.severity {
                 Severity::Info => theme.foreground_muted,
                 Severity::Warning => theme.accent,
@@ -148,16 +172,32 @@ fn build_notification_list(app: &App, theme: &GlTheme) -> PanelBuilder {
 
             let read_indicator = if notif.read { " " } else { "*" };
             let time_str = notif.time_str();
+            let message = if notif.count > 1 {
+                format!("{} (×{})", notif.message, notif.count)
+            } else {
+                notif.message.clone()
+            };
 
-            container = container.child(
+            let bg_color = if position == app.notification_selected {
+                theme.selection_bg
+            } else {
+                theme.background_panel
+            };
+
+            window = window.child(
                 panel()
+                    .height(length(row_height))
+                    .flex_shrink(0.0)
                     .flex_direction(FlexDirection::Row)
+                    .align_items(AlignItems::Center)
                     .gap(gap / 2.0)
+                    .background(bg_color)
                     .child(panel().text(read_indicator, theme.negative, theme.font_small))
                     .child(panel().text(&time_str, theme.foreground_muted, theme.font_small))
-                    .child(panel().text(&notif.message, severity_color, theme.font_small)),
+                    .child(panel().text(&message, severity_color, theme.font_small)),
             );
         }
+        container = container.child(window);
 
         // Show scroll indicator if there are more
         if notifications.len() > visible_count {
@@ -3,17 +3,31 @@
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
+use super::build_header;
 use crate::app::App;
 use crate::base::view::ViewSpacing;
 use crate::widgets::{
-    coin_table::build_coin_table, control_footer::build_overview_footer,
-    status_header::build_status_header, theme::GlTheme, titled_panel::titled_panel,
+    coin_table::{build_coin_table, CoinTableColumns},
+    control_footer::build_overview_footer,
+    theme::GlTheme,
+    titled_panel::titled_panel,
 };
 
 pub fn build_overview_view(app: &App, theme: &GlTheme, width: f32, height: f32) -> PanelBuilder {
     let selected_count = app.selected_count();
     let total_count = app.coins.len();
     let spacing = ViewSpacing::new(theme);
+    let responsive = app.breakpoints.resolve(width, height);
+
+    // Keybinding hints are the first thing to go on short displays
+    let footer = (!responsive.short).then(|| {
+        build_overview_footer(selected_count, total_count, &app.keymap, theme).margin(
+            spacing.footer_margin(),
+            0.0,
+            0.0,
+            0.0,
+        )
+    });
 
     panel()
         .width(length(width))
@@ -23,15 +37,7 @@ pub fn build_overview_view(app: &App, theme: &GlTheme, width: f32, height: f32)
         .padding_all(spacing.outer_padding)
         .background(theme.background)
         // Header - fixed height
-        .child(build_status_header(
-            app.view,
-            &app.provider,
-            app.time_window,
-            app.chart_type,
-            app.connection_status,
-            app.notification_manager.unread_count,
-            theme,
-        ))
+        .children(build_header(app, theme))
         // Coin table - grows to fill space, wrapped in titled panel
         .child(
             titled_panel(
@@ -39,20 +45,22 @@ pub fn build_overview_view(app: &App, theme: &GlTheme, width: f32, height: f32)
                 theme,
                 panel().flex_grow(1.0).child(build_coin_table(
                     &app.coins,
+                    &app.sorted_coin_indices(),
                     app.selected_index,
                     &app.checked,
+                    &app.volume_config,
+                    CoinTableColumns {
+                        compact: responsive.narrow,
+                        sparkline: app.sparkline_style,
+                        sort_key: app.sort_key,
+                        sort_descending: app.sort_descending,
+                        pulse: app.price_pulse,
+                    },
                     theme,
                 )),
             )
             .flex_grow(1.0),
         )
-        // Footer - fixed height
-        .child(
-            build_overview_footer(selected_count, total_count, theme).margin(
-                spacing.footer_margin(),
-                0.0,
-                0.0,
-                0.0,
-            ),
-        )
+        // Footer - fixed height (hidden on short displays)
+        .children(footer)
 }
@@ -3,15 +3,19 @@
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
-use crate::api::margin::MarginAccount;
+use crate::api::margin::{LiquidationRisk, MarginAccount};
 use crate::app::App;
 use crate::base::view::ViewSpacing;
 use crate::widgets::{
-    control_footer::build_positions_footer, format::format_price,
-    positions_table::build_positions_table, status_header::build_status_header,
-    theme::GlTheme, titled_panel::titled_panel,
+    control_footer::build_positions_footer,
+    format::{format_price, format_signed_price, ValueColor},
+    positions_table::build_positions_table,
+    theme::GlTheme,
+    titled_panel::titled_panel,
 };
 
+use super::build_header;
+
 pub fn build_positions_view(
     app: &App,
     theme: &GlTheme,
@@ -39,21 +43,14 @@ pub fn build_positions_view(
         .padding_all(spacing.outer_padding)
         .background(theme.background)
         // Header - fixed height
-        .child(build_status_header(
-            app.view,
-            &app.provider,
-            app.time_window,
-            app.chart_type,
-            app.connection_status,
-            app.notification_manager.unread_count,
-            theme,
-        ))
+        .children(build_header(app, theme))
         // Content - grows to fill space
         .child(content.flex_grow(1.0))
         // Footer - fixed height
         .child(
             build_positions_footer(
                 app.margin_account.as_ref().map(|a| a.margin_level),
+                &app.keymap,
                 theme,
             )
             .margin(spacing.footer_margin(), 0.0, 0.0, 0.0),
@@ -86,6 +83,7 @@ fn build_positions_content(
         .gap(theme.panel_gap)
         .child(summary)
         .child(table)
+        .child(build_portfolio_bar(account, theme))
 }
 
 fn build_account_summary(account: &MarginAccount, theme: &GlTheme) -> PanelBuilder {
@@ -99,13 +97,7 @@ fn build_account_summary(account: &MarginAccount, theme: &GlTheme) -> PanelBuild
     };
 
     // Color for net equity
-    let net_color = if account.total_net_usd > 0.0 {
-        theme.positive
-    } else if account.total_net_usd < 0.0 {
-        theme.negative
-    } else {
-        theme.foreground
-    };
+    let net_color = ValueColor::Sign.color(account.total_net_usd, theme);
 
     titled_panel(
         &format!("{} - Account Summary", account.account_type),
@@ -184,6 +176,64 @@ fn build_account_summary(account: &MarginAccount, theme: &GlTheme) -> PanelBuild
     )
 }
 
+/// Bottom-line bar under the positions table: session PnL, exposure, equity and risk
+///
+/// Session PnL is left out until every position has a session entry.
+fn build_portfolio_bar(account: &MarginAccount, theme: &GlTheme) -> PanelBuilder {
+    let pnl = account.total_session_pnl();
+    let risk = account.liquidation_risk();
+    let risk_color = match risk {
+        LiquidationRisk::None | LiquidationRisk::Low => theme.positive,
+        LiquidationRisk::Elevated => theme.neutral,
+        LiquidationRisk::High => theme.negative,
+    };
+
+    let stat = |label: &str, value: String, color: [f32; 4]| {
+        panel()
+            .flex_direction(FlexDirection::Row)
+            .gap(theme.panel_gap / 2.0)
+            .child(panel().text(label, theme.accent_secondary, theme.font_normal))
+            .child(panel().text(&value, color, theme.font_normal))
+    };
+
+    panel()
+        .width(percent(1.0))
+        .background(theme.background_panel)
+        .border_solid(1.0, theme.border)
+        .padding_all(theme.panel_padding)
+        .flex_direction(FlexDirection::Row)
+        .justify_content(JustifyContent::SpaceAround)
+        .align_items(AlignItems::Center)
+        .gap(theme.panel_gap * 2.0)
+        .children(pnl.map(|pnl| {
+            stat(
+                "Session PnL",
+                format_signed_price(pnl),
+                ValueColor::Sign.color(pnl, theme),
+            )
+        }))
+        .child(stat(
+            "Notional",
+            format_price(account.total_notional()),
+            theme.foreground,
+        ))
+        .child(stat(
+            "Equity",
+            format_price(account.equity()),
+            theme.foreground,
+        ))
+        .child(stat(
+            "Margin Ratio",
+            format!("{:.1}%", account.margin_ratio() * 100.0),
+            theme.foreground,
+        ))
+        .child(stat(
+            "Liquidation Risk",
+            risk.label().to_string(),
+            risk_color,
+        ))
+}
+
 fn build_loading_state(theme: &GlTheme) -> PanelBuilder {
     titled_panel(
         "Positions",
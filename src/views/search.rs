@@ -0,0 +1,136 @@
+//! Coin search overlay - query box and matches in a modal over the current view
+//!
+//! Built as the overlay root with `modal()`, so it renders after the charts;
+//! the overview table behind the backdrop filters live as the query changes.
+
+use crate::base::layout::{HAlign, VAlign};
+use crate::base::{panel, taffy, PanelBuilder};
+use taffy::prelude::*;
+
+use crate::app::App;
+use crate::widgets::format::{format_change, format_price, ValueColor};
+use crate::widgets::modal::modal;
+use crate::widgets::theme::GlTheme;
+
+/// Overlay width, shrunk to fit narrow screens
+const SEARCH_WIDTH: f32 = 420.0;
+
+/// Matches listed below the query
+const MAX_RESULTS: usize = 8;
+
+/// Build the search overlay: a modal dialog centered on the screen
+///
+/// The match list keeps room for `MAX_RESULTS` rows, so the dialog doesn't
+/// move while the query narrows the matches.
+pub fn build_search_overlay(app: &App, theme: &GlTheme, width: f32) -> PanelBuilder {
+    let Some(search) = &app.search else {
+        return panel();
+    };
+
+    let box_width = SEARCH_WIDTH.min(width - theme.panel_gap * 4.0);
+    let matches = app.sorted_coin_indices();
+    // Keep the highlighted match in the listed window
+    let first = search.selected.saturating_sub(MAX_RESULTS - 1);
+    let rows = matches
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(MAX_RESULTS)
+        .map(|(row, &index)| build_result_row(app, index, row == search.selected, theme));
+
+    let results = if matches.is_empty() {
+        panel().child(
+            panel()
+                .text("No matches", theme.foreground_muted, theme.font_normal)
+                .text_align(HAlign::Left, VAlign::Center),
+        )
+    } else {
+        panel().flex_direction(FlexDirection::Column).children(rows)
+    }
+    .height(length(result_row_height(theme) * MAX_RESULTS as f32));
+
+    let query = panel()
+        .width(percent(1.0))
+        .text(
+            format!("/ {}_", search.query),
+            theme.foreground,
+            theme.font_normal,
+        )
+        .text_align(HAlign::Left, VAlign::Center);
+
+    let hint = panel()
+        .text(
+            format!(
+                "{}/{}  [Up/Down] Select  [Enter] Go",
+                matches.len(),
+                app.coins.len()
+            ),
+            theme.foreground_muted,
+            theme.font_small,
+        )
+        .text_align(HAlign::Left, VAlign::Center);
+
+    let body = panel()
+        .width(length(box_width))
+        .flex_direction(FlexDirection::Column)
+        .gap(theme.panel_gap)
+        .child(query)
+        .child(results)
+        .child(hint);
+
+    modal("Search", body, theme)
+}
+
+fn result_row_height(theme: &GlTheme) -> f32 {
+    theme.font_size * 2.0
+}
+
+fn build_result_row(app: &App, index: usize, is_selected: bool, theme: &GlTheme) -> PanelBuilder {
+    let coin = &app.coins[index];
+    let background = if is_selected {
+        theme.selection_bg
+    } else {
+        [0.0, 0.0, 0.0, 0.0]
+    };
+    let gap = theme.panel_gap;
+
+    panel()
+        .width(percent(1.0))
+        .height(length(result_row_height(theme)))
+        .padding(gap / 2.0, gap, gap / 2.0, gap)
+        .flex_direction(FlexDirection::Row)
+        .align_items(AlignItems::Center)
+        .background(background)
+        .child(
+            panel()
+                .width(length(80.0))
+                .text(&coin.symbol, theme.accent, theme.font_normal)
+                .text_align(HAlign::Left, VAlign::Center),
+        )
+        .child(
+            panel()
+                .flex_grow(1.0)
+                .text(&coin.name, theme.foreground, theme.font_normal)
+                .text_align(HAlign::Left, VAlign::Center),
+        )
+        .child(
+            panel()
+                .width(length(110.0))
+                .text(
+                    format_price(coin.price),
+                    theme.foreground,
+                    theme.font_normal,
+                )
+                .text_align(HAlign::Right, VAlign::Center),
+        )
+        .child(
+            panel()
+                .width(length(80.0))
+                .text(
+                    format_change(coin.change_24h),
+                    ValueColor::Sign.color(coin.change_24h, theme),
+                    theme.font_normal,
+                )
+                .text_align(HAlign::Right, VAlign::Center),
+        )
+}
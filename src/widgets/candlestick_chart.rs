@@ -1,77 +1,115 @@
-//! Candlestick chart widget with RSI overlay, EMA lines, and volume bars
+//! Candlestick chart widget with RSI overlay, EMA lines, volume bars and a MACD subpanel
 
 use crate::api::Candle;
 use crate::widgets::chart_renderer::{
     calculate_visible_range, ChartBounds, ChartRenderer, PixelRect,
 };
 use crate::widgets::chart_utils::{
-    calculate_price_bounds, calculate_volume_bounds, render_grid, render_volume_bars, ChartLayout,
+    calculate_volume_bounds, candle_widths, render_price_grid, render_volume_bars,
+    resolve_price_bounds, split_macd_panel, ChartLabel, ChartLayout, ChartOptions,
 };
-use crate::widgets::indicators::CandleIndicators;
+use crate::widgets::format::{format_price, format_utc_time};
+use crate::widgets::indicators::{bollinger_bands, CandleIndicators};
+use crate::widgets::text_box::char_width_px;
 use crate::widgets::theme::GlTheme;
 
+/// Padding inside the crosshair readout box, and its margin from the chart edge
+const READOUT_PADDING: f32 = 4.0;
+const READOUT_MARGIN: f32 = 6.0;
+
 /// Render a complete candlestick chart with overlays
-/// Uses pre-calculated indicators from CoinData to avoid per-frame recalculation
+/// Uses pre-calculated indicators from CoinData to avoid per-frame recalculation.
+/// Returns text labels (e.g. percent gridlines) for the caller to draw over the chart.
 pub fn render_candlestick_chart(
     renderer: &mut ChartRenderer,
     candles: &[Candle],
     indicators: &CandleIndicators,
-    scroll_offset: isize,
-    visible_candles: usize,
-    price_margin: f64,
+    options: &ChartOptions,
     rect: PixelRect,
     theme: &GlTheme,
-) {
+) -> Vec<ChartLabel> {
     if candles.is_empty() || rect.width <= 0.0 || rect.height <= 0.0 {
-        return;
+        return Vec::new();
     }
 
     // 1. Calculate visible range
-    let visible = calculate_visible_range(candles.len(), visible_candles, scroll_offset);
+    let visible = calculate_visible_range(
+        candles.len(),
+        options.visible_candles,
+        options.scroll_offset,
+    );
 
     let visible_slice = &candles[visible.start_idx..visible.end_idx];
     if visible_slice.is_empty() {
-        return;
+        return Vec::new();
     }
 
     // 2. Calculate bounds
-    let price_bounds = calculate_price_bounds(visible_slice, price_margin);
-    let volume_bounds = calculate_volume_bounds(visible_slice);
+    let price_bounds = resolve_price_bounds(visible_slice, options, false);
+    let volume_bounds = calculate_volume_bounds(visible_slice, options.volume_unit);
 
-    // 3. Calculate layout
-    let layout = ChartLayout::new(&rect, visible_candles);
+    // 3. Calculate layout (MACD subpanel, if shown, takes the bottom of the rect)
+    let (main_rect, macd_rect) = split_macd_panel(&rect, options.show_macd);
+    let layout = ChartLayout::new(&main_rect, options.visible_candles);
 
-    // 4. Calculate candle dimensions (no horizontal gaps)
-    let body_width = layout.slot_width * 0.95;
-    let wick_width = (body_width * 0.1).max(1.0);
+    // 4. Calculate candle dimensions (no horizontal gaps, clamped to stay visible)
+    let (body_width, wick_width) = candle_widths(layout.slot_width, options.min_body_width);
 
-    // 5. Draw grid
-    render_grid(renderer, &layout.price_area, 4, 6, theme);
+    // 5. Draw grid (anchored at the leftmost visible close in percent mode)
+    let mut labels = render_price_grid(
+        renderer,
+        options.grid,
+        visible_slice[0].close,
+        &price_bounds,
+        &layout.price_area,
+        theme,
+    );
 
     // 6. Draw volume bars
     render_volume_bars(
         renderer,
         visible_slice,
         &volume_bounds,
-        &layout.volume_area,
-        layout.slot_width,
+        &layout,
+        options.volume_unit,
         0.4,
         theme,
     );
 
-    // 7. Draw EMA lines (using cached indicators)
-    render_ema_lines(
-        renderer,
-        &indicators,
-        visible.start_idx,
-        visible.end_idx,
-        &price_bounds,
-        &layout.price_area,
-        layout.slot_width,
-        theme,
-    );
+    // 7. Draw Bollinger Bands (computed over the visible range plus warmup candles)
+    if let Some(bands) = options.bollinger {
+        let warmup = visible.start_idx.min(bands.period.saturating_sub(1));
+        let closes: Vec<f64> = candles[visible.start_idx - warmup..visible.end_idx]
+            .iter()
+            .map(|c| c.close)
+            .collect();
+        let (upper, _, lower) = bollinger_bands(&closes, bands.period, bands.std_mult);
+        render_bollinger_bands(
+            renderer,
+            &upper[warmup..],
+            &lower[warmup..],
+            &price_bounds,
+            &layout.price_area,
+            layout.slot_width,
+            theme,
+        );
+    }
+
+    // 8. Draw EMA lines (using cached indicators)
+    if options.show_ema {
+        render_ema_lines(
+            renderer,
+            &indicators,
+            visible.start_idx,
+            visible.end_idx,
+            &price_bounds,
+            &layout.price_area,
+            layout.slot_width,
+            theme,
+        );
+    }
 
-    // 8. Draw candlesticks
+    // 9. Draw candlesticks
     render_candles(
         renderer,
         visible_slice,
@@ -83,7 +121,7 @@ pub fn render_candlestick_chart(
         theme,
     );
 
-    // 9. Draw RSI overlay
+    // 10. Draw RSI overlay
     render_rsi_overlay(
         renderer,
         &indicators.rsi,
@@ -93,6 +131,35 @@ pub fn render_candlestick_chart(
         layout.slot_width,
         theme,
     );
+
+    // 11. Draw MACD subpanel
+    if let Some(macd_rect) = macd_rect {
+        labels.extend(render_macd_panel(
+            renderer,
+            indicators
+                .macd
+                .get(visible.start_idx..visible.end_idx)
+                .unwrap_or_default(),
+            &macd_rect,
+            layout.slot_width,
+            body_width,
+            theme,
+        ));
+    }
+
+    // 12. Draw crosshair with OHLC/time readout
+    if let Some(slot) = options.crosshair {
+        labels.extend(render_crosshair(
+            renderer,
+            visible_slice,
+            slot,
+            &price_bounds,
+            &layout,
+            theme,
+        ));
+    }
+
+    labels
 }
 
 /// Render candlesticks
@@ -163,6 +230,164 @@ fn render_ema_lines(
     }
 }
 
+/// Render Bollinger Bands as a shaded region with outlined edges
+///
+/// `upper`/`lower` are indexed from the first visible candle; 0.0 entries (not
+/// enough history yet) are skipped.
+fn render_bollinger_bands(
+    renderer: &mut ChartRenderer,
+    upper: &[f64],
+    lower: &[f64],
+    bounds: &ChartBounds,
+    rect: &PixelRect,
+    slot_width: f32,
+    theme: &GlTheme,
+) {
+    let mut upper_points = Vec::with_capacity(upper.len());
+    let mut lower_points = Vec::with_capacity(lower.len());
+    for (i, (&up, &low)) in upper.iter().zip(lower).enumerate() {
+        if up <= 0.0 {
+            continue;
+        }
+        let x = rect.x + (i as f32 + 0.5) * slot_width;
+        upper_points.push((x, bounds.to_pixel(0.0, up, rect).1));
+        lower_points.push((x, bounds.to_pixel(0.0, low, rect).1));
+    }
+
+    let mut fill = theme.indicator_band;
+    fill[3] = 0.12;
+    let mut edge = theme.indicator_band;
+    edge[3] = 0.6;
+
+    renderer.draw_band(&upper_points, &lower_points, fill);
+    renderer.draw_polyline(&upper_points, 1.0, edge);
+    renderer.draw_polyline(&lower_points, 1.0, edge);
+}
+
+/// Render a dashed crosshair through the close of the candle in `slot`, plus a
+/// readout box in the top corner away from it
+///
+/// Returns the readout lines as labels; nothing is drawn for an empty slot.
+fn render_crosshair(
+    renderer: &mut ChartRenderer,
+    candles: &[Candle],
+    slot: usize,
+    bounds: &ChartBounds,
+    layout: &ChartLayout,
+    theme: &GlTheme,
+) -> Vec<ChartLabel> {
+    let Some(candle) = candles.get(slot) else {
+        return Vec::new();
+    };
+    let area = &layout.price_area;
+    let x = area.x + (slot as f32 + 0.5) * layout.slot_width;
+    let (_, y) = bounds.to_pixel(0.0, candle.close, area);
+    let full_height = layout.volume_area.y + layout.volume_area.height - area.y;
+
+    let mut line_color = theme.foreground_muted;
+    line_color[3] = 0.8;
+    renderer.draw_dashed_line_v(x, area.y, full_height, 1.0, 4.0, 3.0, line_color);
+    renderer.draw_dashed_line_h(area.x, y, area.width, 1.0, 4.0, 3.0, line_color);
+
+    let lines = [
+        format!(
+            "O {}  H {}  L {}  C {}",
+            format_price(candle.open),
+            format_price(candle.high),
+            format_price(candle.low),
+            format_price(candle.close)
+        ),
+        format_utc_time(candle.time),
+    ];
+    let font_px = theme.font_size * theme.font_small;
+    let line_height = font_px * 1.4;
+    let text_width = lines
+        .iter()
+        .map(|line| line.chars().map(|c| char_width_px(c, font_px)).sum::<f32>())
+        .fold(0.0, f32::max);
+    let box_width = text_width + READOUT_PADDING * 2.0;
+    let box_height = line_height * lines.len() as f32 + READOUT_PADDING * 2.0;
+
+    let box_x = if x < area.x + area.width / 2.0 {
+        area.x + area.width - box_width - READOUT_MARGIN
+    } else {
+        area.x + READOUT_MARGIN
+    };
+    let box_y = area.y + READOUT_MARGIN;
+
+    let mut background = theme.background_panel;
+    background[3] = 0.9;
+    renderer.draw_rect(box_x, box_y, box_width, box_height, background);
+
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, text)| ChartLabel {
+            x: box_x + READOUT_PADDING,
+            y: box_y + READOUT_PADDING + line_height * i as f32 + font_px,
+            text,
+        })
+        .collect()
+}
+
+/// Render the MACD subpanel: histogram bars around a zero baseline plus the MACD
+/// and signal lines, scaled symmetrically to the largest visible magnitude
+///
+/// `macd` is indexed from the first visible candle. Returns the panel title label.
+fn render_macd_panel(
+    renderer: &mut ChartRenderer,
+    macd: &[(f64, f64, f64)],
+    rect: &PixelRect,
+    slot_width: f32,
+    bar_width: f32,
+    theme: &GlTheme,
+) -> Option<ChartLabel> {
+    // Separator between the main chart and the subpanel
+    renderer.draw_line_h(rect.x, rect.y, rect.width, 1.0, theme.border);
+
+    let magnitude = macd
+        .iter()
+        .map(|&(line, signal, histogram)| line.abs().max(signal.abs()).max(histogram.abs()))
+        .fold(0.0, f64::max);
+    if magnitude <= 0.0 {
+        return None;
+    }
+    let bounds = ChartBounds::new(0.0, 1.0, -magnitude, magnitude);
+
+    // Zero baseline
+    let (_, zero_y) = bounds.to_pixel(0.0, 0.0, rect);
+    let mut baseline_color = theme.foreground_muted;
+    baseline_color[3] = 0.5;
+    renderer.draw_line_h(rect.x, zero_y, rect.width, 1.0, baseline_color);
+
+    // Histogram bars (drawn up from the baseline, or down for negative values)
+    let mut line_points = Vec::with_capacity(macd.len());
+    let mut signal_points = Vec::with_capacity(macd.len());
+    for (i, &(line, signal, histogram)) in macd.iter().enumerate() {
+        let x = rect.x + (i as f32 + 0.5) * slot_width;
+        let (_, bar_y) = bounds.to_pixel(0.0, histogram, rect);
+        if histogram >= 0.0 {
+            renderer.draw_volume_bar(x, zero_y, zero_y - bar_y, bar_width, theme.candle_bullish);
+        } else {
+            renderer.draw_volume_bar(x, bar_y, bar_y - zero_y, bar_width, theme.candle_bearish);
+        }
+
+        line_points.push((x, bounds.to_pixel(0.0, line, rect).1));
+        signal_points.push((x, bounds.to_pixel(0.0, signal, rect).1));
+    }
+
+    if line_points.len() >= 2 {
+        renderer.draw_polyline(&line_points, 1.2, theme.indicator_primary);
+        renderer.draw_polyline(&signal_points, 1.2, theme.indicator_secondary);
+    }
+
+    Some(ChartLabel {
+        x: rect.x + 4.0,
+        y: rect.y + theme.font_size * theme.font_small + 2.0,
+        text: "MACD(12,26,9)".to_string(),
+    })
+}
+
 /// Render RSI as an overlay with its own 0-100 Y-axis scale
 fn render_rsi_overlay(
     renderer: &mut ChartRenderer,
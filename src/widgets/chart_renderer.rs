@@ -216,12 +216,12 @@ impl ChartRenderer {
         wick_width: f32,
         color: [f32; 4],
     ) {
-        // Draw wick (vertical line from low to high)
+        // Draw wick (vertical line from low to high, at least 1px so it stays visible)
         self.draw_rect(
             x - wick_width * 0.5,
             high.min(low),
             wick_width,
-            (high - low).abs(),
+            (high - low).abs().max(1.0),
             color,
         );
 
@@ -356,6 +356,26 @@ impl ChartRenderer {
         }
     }
 
+    /// Fill the region between two polylines sharing x coordinates (e.g. indicator bands)
+    pub fn draw_band(&mut self, upper: &[(f32, f32)], lower: &[(f32, f32)], color: [f32; 4]) {
+        for (top, bottom) in upper.windows(2).zip(lower.windows(2)) {
+            let ((x1, t1), (x2, t2)) = (top[0], top[1]);
+            let ((_, b1), (_, b2)) = (bottom[0], bottom[1]);
+
+            // Triangle 1
+            self.push_vertex(x1, t1, &color);
+            self.push_vertex(x2, t2, &color);
+            self.push_vertex(x1, b1, &color);
+
+            // Triangle 2
+            self.push_vertex(x2, t2, &color);
+            self.push_vertex(x2, b2, &color);
+            self.push_vertex(x1, b1, &color);
+
+            self.vertex_count += 6;
+        }
+    }
+
     /// Draw a filled area under a polyline with vertical gradient normalized to chart area
     /// Color is interpolated based on Y position: chart_top_y gets top_color, chart_bottom_y gets bottom_color
     /// This creates a uniform gradient across the entire chart regardless of line height
@@ -509,12 +529,45 @@ fn ortho_projection(left: f32, right: f32, bottom: f32, top: f32) -> [f32; 16] {
     ]
 }
 
+/// Smallest value placed on a log scale; non-positive values are clamped to it
+const LOG_SCALE_EPSILON: f64 = 1e-9;
+
+/// Y-axis scale of a chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScaleMode {
+    #[default]
+    Linear,
+    /// log10 axis, so equal percent moves take equal height
+    Log,
+}
+
+impl ScaleMode {
+    /// Map a data value onto the axis (log10 in log mode, never NaN)
+    pub fn apply(self, value: f64) -> f64 {
+        match self {
+            ScaleMode::Linear => value,
+            ScaleMode::Log => value.max(LOG_SCALE_EPSILON).log10(),
+        }
+    }
+
+    /// Map an axis value back to data space (inverse of `apply`)
+    pub fn invert(self, value: f64) -> f64 {
+        match self {
+            ScaleMode::Linear => value,
+            ScaleMode::Log => 10f64.powf(value),
+        }
+    }
+}
+
 /// Chart coordinate system helper
+///
+/// Bounds are in data units; `scale` only changes how Y values are spread across pixels.
 pub struct ChartBounds {
     pub x_min: f64,
     pub x_max: f64,
     pub y_min: f64,
     pub y_max: f64,
+    pub scale: ScaleMode,
 }
 
 impl ChartBounds {
@@ -524,9 +577,16 @@ impl ChartBounds {
             x_max,
             y_min,
             y_max,
+            scale: ScaleMode::Linear,
         }
     }
 
+    /// Use the given Y-axis scale
+    pub fn with_scale(mut self, scale: ScaleMode) -> Self {
+        self.scale = scale;
+        self
+    }
+
     /// Create bounds from a slice of (x, y) points
     pub fn from_points(points: &[(f64, f64)]) -> Option<Self> {
         if points.is_empty() {
@@ -545,12 +605,7 @@ impl ChartBounds {
             y_max = y_max.max(y);
         }
 
-        Some(Self {
-            x_min,
-            x_max,
-            y_min,
-            y_max,
-        })
+        Some(Self::new(x_min, x_max, y_min, y_max))
     }
 
     /// Add padding to bounds (as a fraction, e.g., 0.05 for 5%)
@@ -564,10 +619,11 @@ impl ChartBounds {
         self
     }
 
-    /// Map a data point to pixel coordinates
+    /// Map a data point to pixel coordinates (Y through the bounds' scale)
     pub fn to_pixel(&self, data_x: f64, data_y: f64, pixel_rect: &PixelRect) -> (f32, f32) {
         let x_range = self.x_max - self.x_min;
-        let y_range = self.y_max - self.y_min;
+        let y_min = self.scale.apply(self.y_min);
+        let y_range = self.scale.apply(self.y_max) - y_min;
 
         let x_norm = if x_range > 0.0 {
             (data_x - self.x_min) / x_range
@@ -576,7 +632,7 @@ impl ChartBounds {
         };
 
         let y_norm = if y_range > 0.0 {
-            (data_y - self.y_min) / y_range
+            (self.scale.apply(data_y) - y_min) / y_range
         } else {
             0.5
         };
@@ -594,7 +650,9 @@ impl ChartBounds {
         let y_norm = 1.0 - (py - pixel_rect.y) / pixel_rect.height;
 
         let data_x = self.x_min + (x_norm as f64) * (self.x_max - self.x_min);
-        let data_y = self.y_min + (y_norm as f64) * (self.y_max - self.y_min);
+        let y_min = self.scale.apply(self.y_min);
+        let y_max = self.scale.apply(self.y_max);
+        let data_y = self.scale.invert(y_min + (y_norm as f64) * (y_max - y_min));
 
         (data_x, data_y)
     }
@@ -1,9 +1,90 @@
 //! Shared chart utilities for candlestick and polygonal charts
 
 use crate::api::Candle;
-use crate::widgets::chart_renderer::{ChartBounds, ChartRenderer, PixelRect};
+use crate::config::{BollingerConfig, VolumeConfig, VolumeUnit};
+use crate::widgets::chart_renderer::{
+    calculate_visible_range, ChartBounds, ChartRenderer, PixelRect, ScaleMode,
+};
+use crate::widgets::format::format_volume_full;
 use crate::widgets::theme::GlTheme;
 
+/// Horizontal gridline mode for the price area
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridMode {
+    /// Evenly spaced gridlines across the price range
+    Price,
+    /// Gridlines every `step` percent from the leftmost visible close
+    Percent(f64),
+}
+
+/// View options shared by the chart renderers
+pub struct ChartOptions {
+    /// Candles scrolled back from the latest (0 = latest at right edge)
+    pub scroll_offset: isize,
+    /// Number of candle slots across the chart (zoom level)
+    pub visible_candles: usize,
+    /// Fraction of the price range added above and below
+    pub price_margin: f64,
+    pub grid: GridMode,
+    /// Pinned price range (min, max) used instead of autoscaling
+    pub price_range: Option<(f64, f64)>,
+    /// Units of the volume bars (quote scales bars by dollar volume)
+    pub volume_unit: VolumeUnit,
+    /// Minimum candle body width in pixels, so candles don't vanish when zoomed out
+    pub min_body_width: f32,
+    /// Draw EMA lines over candlesticks
+    pub show_ema: bool,
+    /// Bollinger Bands overlay on candlesticks (None = off)
+    pub bollinger: Option<BollingerConfig>,
+    /// Crosshair slot from the left of the chart (None = off); also selects the
+    /// candle shown in the volume readout
+    pub crosshair: Option<usize>,
+    /// Reserve the bottom of the chart for the MACD subpanel
+    pub show_macd: bool,
+    /// Price axis scale (margins are applied in this scale's space)
+    pub scale_mode: ScaleMode,
+}
+
+/// Text label to draw over a chart after the chart batch (pixel coords, baseline y)
+pub struct ChartLabel {
+    pub x: f32,
+    pub y: f32,
+    pub text: String,
+}
+
+/// Fraction of the chart height taken by the MACD subpanel
+const MACD_PANEL_RATIO: f32 = 0.25;
+
+/// Split a chart rect into the main chart area and, when shown, the MACD subpanel below it
+pub fn split_macd_panel(rect: &PixelRect, show_macd: bool) -> (PixelRect, Option<PixelRect>) {
+    if !show_macd {
+        return (*rect, None);
+    }
+
+    let macd_height = rect.height * MACD_PANEL_RATIO;
+    let main = PixelRect::new(rect.x, rect.y, rect.width, rect.height - macd_height);
+    let macd = PixelRect::new(rect.x, main.y + main.height, rect.width, macd_height);
+    (main, Some(macd))
+}
+
+/// Maximum percent gridlines drawn; the step is doubled until the count fits
+const MAX_PERCENT_GRID_LINES: usize = 12;
+
+/// Candle body and wick widths for a slot
+///
+/// The body fills 95% of the slot but never drops below `min_body_width`, and is
+/// capped at the slot width so neighbouring candles don't overlap. Both body and
+/// wick are at least 1px wide so candles stay visible at extreme zoom-out.
+pub fn candle_widths(slot_width: f32, min_body_width: f32) -> (f32, f32) {
+    let slot_cap = slot_width.max(1.0);
+    let body_width = (slot_width * 0.95)
+        .max(min_body_width)
+        .min(slot_cap)
+        .max(1.0);
+    let wick_width = (body_width * 0.1).max(1.0).min(body_width);
+    (body_width, wick_width)
+}
+
 /// Common chart layout areas
 pub struct ChartLayout {
     pub price_area: PixelRect,
@@ -31,7 +112,7 @@ impl ChartLayout {
 }
 
 /// Calculate price bounds from visible candles (high/low) with margin
-pub fn calculate_price_bounds(candles: &[Candle], margin: f64) -> ChartBounds {
+pub fn calculate_price_bounds(candles: &[Candle], margin: f64, scale: ScaleMode) -> ChartBounds {
     let mut min_price = f64::MAX;
     let mut max_price = f64::MIN;
 
@@ -40,19 +121,15 @@ pub fn calculate_price_bounds(candles: &[Candle], margin: f64) -> ChartBounds {
         max_price = max_price.max(candle.high);
     }
 
-    let range = max_price - min_price;
-    let margin_amount = range * margin;
-
-    ChartBounds::new(
-        0.0,
-        candles.len() as f64,
-        min_price - margin_amount,
-        max_price + margin_amount,
-    )
+    price_bounds_with_margin(candles.len(), min_price, max_price, margin, scale)
 }
 
 /// Calculate price bounds from candle closes only (for polygonal chart)
-pub fn calculate_price_bounds_from_closes(candles: &[Candle], margin: f64) -> ChartBounds {
+pub fn calculate_price_bounds_from_closes(
+    candles: &[Candle],
+    margin: f64,
+    scale: ScaleMode,
+) -> ChartBounds {
     let mut min_price = f64::MAX;
     let mut max_price = f64::MIN;
 
@@ -61,22 +138,73 @@ pub fn calculate_price_bounds_from_closes(candles: &[Candle], margin: f64) -> Ch
         max_price = max_price.max(candle.close);
     }
 
-    let range = max_price - min_price;
-    let margin_amount = range * margin;
+    price_bounds_with_margin(candles.len(), min_price, max_price, margin, scale)
+}
+
+/// Bounds for a price range widened by `margin` of its span, measured on the axis
+/// (so on a log scale the margin is a ratio and never pushes the minimum below zero)
+fn price_bounds_with_margin(
+    count: usize,
+    min_price: f64,
+    max_price: f64,
+    margin: f64,
+    scale: ScaleMode,
+) -> ChartBounds {
+    let (min_axis, max_axis) = (scale.apply(min_price), scale.apply(max_price));
+    let margin_amount = (max_axis - min_axis) * margin;
 
     ChartBounds::new(
         0.0,
-        candles.len() as f64,
-        min_price - margin_amount,
-        max_price + margin_amount,
+        count as f64,
+        scale.invert(min_axis - margin_amount),
+        scale.invert(max_axis + margin_amount),
     )
+    .with_scale(scale)
+}
+
+/// Price bounds for the visible candles: the pinned range if set, otherwise autoscaled
+///
+/// `closes_only` selects close-based bounds (polygonal chart) over high/low (candlestick).
+pub fn resolve_price_bounds(
+    candles: &[Candle],
+    options: &ChartOptions,
+    closes_only: bool,
+) -> ChartBounds {
+    let scale = options.scale_mode;
+    match options.price_range {
+        Some((min, max)) => ChartBounds::new(0.0, candles.len() as f64, min, max).with_scale(scale),
+        None if closes_only => {
+            calculate_price_bounds_from_closes(candles, options.price_margin, scale)
+        }
+        None => calculate_price_bounds(candles, options.price_margin, scale),
+    }
+}
+
+/// Current visible price range (min, max) as the chart would draw it, for pinning the scale
+pub fn visible_price_range(
+    candles: &[Candle],
+    options: &ChartOptions,
+    closes_only: bool,
+) -> Option<(f64, f64)> {
+    let visible = calculate_visible_range(
+        candles.len(),
+        options.visible_candles,
+        options.scroll_offset,
+    );
+    let visible_slice = &candles[visible.start_idx..visible.end_idx];
+    if visible_slice.is_empty() {
+        return None;
+    }
+
+    let bounds = resolve_price_bounds(visible_slice, options, closes_only);
+    Some((bounds.y_min, bounds.y_max))
 }
 
 /// Calculate volume bounds from visible candles
-pub fn calculate_volume_bounds(candles: &[Candle]) -> ChartBounds {
+pub fn calculate_volume_bounds(candles: &[Candle], unit: VolumeUnit) -> ChartBounds {
     let max_volume = candles
         .iter()
-        .map(|c| c.volume)
+        .map(|c| candle_volume(c, unit))
         .fold(0.0f64, |a, b| a.max(b));
 
     ChartBounds::new(0.0, candles.len() as f64, 0.0, max_volume)
@@ -105,23 +233,140 @@ pub fn render_grid(
     );
 }
 
+/// Percent levels (relative to `anchor`) that fall inside the bounds' price range
+///
+/// Returns (price, percent) pairs from lowest to highest, every `step` percent.
+pub fn percent_grid_levels(anchor: f64, bounds: &ChartBounds, step: f64) -> Vec<(f64, f64)> {
+    if anchor <= 0.0 || step <= 0.0 || bounds.y_max <= bounds.y_min {
+        return Vec::new();
+    }
+
+    let min_pct = (bounds.y_min / anchor - 1.0) * 100.0;
+    let max_pct = (bounds.y_max / anchor - 1.0) * 100.0;
+
+    let mut step = step;
+    while (max_pct - min_pct) / step > MAX_PERCENT_GRID_LINES as f64 {
+        step *= 2.0;
+    }
+
+    let first = (min_pct / step).ceil() as i64;
+    let last = (max_pct / step).floor() as i64;
+
+    (first..=last)
+        .map(|k| {
+            let pct = k as f64 * step;
+            (anchor * (1.0 + pct / 100.0), pct)
+        })
+        .collect()
+}
+
+/// Pixel y and percent of each percent gridline within `rect`, from lowest to highest
+fn percent_grid_rows(
+    anchor: f64,
+    bounds: &ChartBounds,
+    rect: &PixelRect,
+    step: f64,
+) -> Vec<(f32, f64)> {
+    percent_grid_levels(anchor, bounds, step)
+        .into_iter()
+        .map(|(price, pct)| (bounds.to_pixel(0.0, price, rect).1, pct))
+        .collect()
+}
+
+/// Format a percent gridline label, e.g. "+4%", "-2%", "0%"
+pub fn format_percent_label(pct: f64) -> String {
+    if pct.abs() < 1e-9 {
+        "0%".to_string()
+    } else if pct.fract().abs() < 1e-9 {
+        format!("{:+.0}%", pct)
+    } else {
+        format!("{:+.1}%", pct)
+    }
+}
+
+/// Render percent gridlines (plus vertical grid) and return their labels
+///
+/// The anchor is the close of the leftmost visible candle, so levels move as the chart scrolls.
+pub fn render_percent_grid(
+    renderer: &mut ChartRenderer,
+    anchor: f64,
+    bounds: &ChartBounds,
+    rect: &PixelRect,
+    step: f64,
+    theme: &GlTheme,
+) -> Vec<ChartLabel> {
+    let mut grid_color = theme.border;
+    grid_color[3] = 0.3;
+
+    // Vertical lines only; horizontal lines come from the percent levels
+    renderer.draw_grid(
+        rect.x,
+        rect.y,
+        rect.width,
+        rect.height,
+        0,
+        6,
+        1.0,
+        grid_color,
+    );
+
+    let mut anchor_color = theme.border;
+    anchor_color[3] = 0.6;
+
+    percent_grid_rows(anchor, bounds, rect, step)
+        .into_iter()
+        .map(|(y, pct)| {
+            if pct.abs() < 1e-9 {
+                renderer.draw_line_h(rect.x, y, rect.width, 1.0, anchor_color);
+            } else {
+                renderer.draw_dashed_line_h(rect.x, y, rect.width, 1.0, 4.0, 4.0, grid_color);
+            }
+            ChartLabel {
+                x: rect.x + 4.0,
+                y: y - 3.0,
+                text: format_percent_label(pct),
+            }
+        })
+        .collect()
+}
+
+/// Render the price-area grid for the given mode, returning any labels to draw
+pub fn render_price_grid(
+    renderer: &mut ChartRenderer,
+    mode: GridMode,
+    anchor: f64,
+    bounds: &ChartBounds,
+    rect: &PixelRect,
+    theme: &GlTheme,
+) -> Vec<ChartLabel> {
+    match mode {
+        GridMode::Price => {
+            render_grid(renderer, rect, 4, 6, theme);
+            Vec::new()
+        }
+        GridMode::Percent(step) => render_percent_grid(renderer, anchor, bounds, rect, step, theme),
+    }
+}
+
 /// Render volume bars at the bottom of the chart
 pub fn render_volume_bars(
     renderer: &mut ChartRenderer,
     candles: &[Candle],
     volume_bounds: &ChartBounds,
-    rect: &PixelRect,
-    slot_width: f32,
+    layout: &ChartLayout,
+    unit: VolumeUnit,
     opacity: f32,
     theme: &GlTheme,
 ) {
+    let rect = &layout.volume_area;
+    let slot_width = layout.slot_width;
     let bar_width = slot_width * 0.6;
 
     for (i, candle) in candles.iter().enumerate() {
         let x = rect.x + (i as f32 + 0.5) * slot_width;
 
         let vol_ratio = if volume_bounds.y_max > 0.0 {
-            (candle.volume / volume_bounds.y_max).min(1.0) as f32
+            (candle_volume(candle, unit) / volume_bounds.y_max).min(1.0) as f32
         } else {
             0.0
         };
@@ -137,3 +382,191 @@ pub fn render_volume_bars(
         renderer.draw_volume_bar(x, rect.y + rect.height, bar_height, bar_width, color);
     }
 }
+
+/// Approximate quote (dollar) volume of a candle: volume x typical price (H+L+C)/3
+pub fn dollar_volume(candle: &Candle) -> f64 {
+    candle.volume * (candle.high + candle.low + candle.close) / 3.0
+}
+
+/// Volume of a candle in the given units (base volume unless quote is selected)
+pub fn candle_volume(candle: &Candle, unit: VolumeUnit) -> f64 {
+    match unit {
+        VolumeUnit::Quote => dollar_volume(candle),
+        VolumeUnit::Base | VolumeUnit::Both => candle.volume,
+    }
+}
+
+/// Volume readout for the candle at the right edge of the view (moves with scrolling)
+///
+/// Placed at the top-left of the volume area. The latest candle is still forming,
+/// so its volume is labeled as partial.
+pub fn volume_readout(
+    candles: &[Candle],
+    options: &ChartOptions,
+    volume: &VolumeConfig,
+    rect: &PixelRect,
+) -> Option<ChartLabel> {
+    let visible = calculate_visible_range(
+        candles.len(),
+        options.visible_candles,
+        options.scroll_offset,
+    );
+    if visible.end_idx == 0 || visible.start_idx == visible.end_idx {
+        return None;
+    }
+
+    let idx = options
+        .crosshair
+        .map(|slot| visible.start_idx + slot)
+        .filter(|&idx| idx < visible.end_idx)
+        .unwrap_or(visible.end_idx - 1);
+    let candle = &candles[idx];
+    let partial = if idx == candles.len() - 1 {
+        " (partial)"
+    } else {
+        ""
+    };
+
+    let (main_rect, _) = split_macd_panel(rect, options.show_macd);
+    let layout = ChartLayout::new(&main_rect, options.visible_candles);
+    Some(ChartLabel {
+        x: layout.volume_area.x + 4.0,
+        y: layout.volume_area.y - 3.0,
+        text: format!("Vol {}{}", volume_readout_text(candle, volume), partial),
+    })
+}
+
+fn volume_readout_text(candle: &Candle, volume: &VolumeConfig) -> String {
+    let base = format_volume_full(candle.volume);
+    let quote = format!("${}", format_volume_full(dollar_volume(candle)));
+    match volume.unit {
+        VolumeUnit::Both => format!("{} | {}", base, quote),
+        VolumeUnit::Quote => quote,
+        VolumeUnit::Base => base,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(volume: f64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            volume,
+            high,
+            low,
+            close,
+            ..Candle::default()
+        }
+    }
+
+    fn options(scroll_offset: isize, crosshair: Option<usize>) -> ChartOptions {
+        ChartOptions {
+            scroll_offset,
+            visible_candles: 3,
+            price_margin: 0.0,
+            grid: GridMode::Price,
+            price_range: None,
+            volume_unit: VolumeUnit::Both,
+            min_body_width: 1.0,
+            show_ema: false,
+            bollinger: None,
+            crosshair,
+            show_macd: false,
+            scale_mode: ScaleMode::Linear,
+        }
+    }
+
+    #[test]
+    fn candle_widths_at_extreme_zoom() {
+        // Zoomed in: 95% of the slot, wick 10% of the body
+        let (body, wick) = candle_widths(20.0, 2.0);
+        assert_eq!((body, wick), (19.0, 1.9));
+
+        // Zoomed out past the minimum: capped at the slot so neighbours don't overlap
+        let (body, wick) = candle_widths(1.5, 3.0);
+        assert_eq!((body, wick), (1.5, 1.0));
+
+        // Sub-pixel slots still draw 1px bodies and wicks
+        let (body, wick) = candle_widths(0.2, 3.0);
+        assert_eq!((body, wick), (1.0, 1.0));
+
+        // Minimum applies when the slot has room for it
+        let (body, wick) = candle_widths(4.0, 3.9);
+        assert_eq!((body, wick), (3.9, 1.0));
+    }
+
+    #[test]
+    fn dollar_volume_uses_typical_price() {
+        // typical price (12 + 8 + 10) / 3 = 10
+        assert_eq!(dollar_volume(&candle(1_500.0, 12.0, 8.0, 10.0)), 15_000.0);
+        assert_eq!(
+            candle_volume(&candle(1_500.0, 12.0, 8.0, 10.0), VolumeUnit::Base),
+            1_500.0
+        );
+    }
+
+    #[test]
+    fn volume_readout_follows_view_and_marks_partial() {
+        let candles = vec![
+            candle(2_000.0, 3.0, 3.0, 3.0),
+            candle(0.5, 100.0, 100.0, 100.0),
+            candle(1_234.0, 2.0, 2.0, 2.0),
+        ];
+        let rect = PixelRect::new(0.0, 0.0, 300.0, 200.0);
+        let volume = VolumeConfig::default();
+        let text =
+            |opts: &ChartOptions| volume_readout(&candles, opts, &volume, &rect).unwrap().text;
+
+        // Latest candle at the right edge is still forming
+        assert_eq!(text(&options(0, None)), "Vol 1,234 | $2,468 (partial)");
+        // Scrolled back one candle
+        assert_eq!(text(&options(1, None)), "Vol 0.50 | $50.00");
+        // Crosshair on the first slot
+        assert_eq!(text(&options(0, Some(0))), "Vol 2,000 | $6,000");
+
+        let quote_only = VolumeConfig {
+            unit: VolumeUnit::Quote,
+            ..VolumeConfig::default()
+        };
+        let label = volume_readout(&candles, &options(1, None), &quote_only, &rect).unwrap();
+        assert_eq!(label.text, "Vol $50.00");
+        assert!(volume_readout(&[], &options(0, None), &volume, &rect).is_none());
+    }
+
+    #[test]
+    fn percent_levels_map_to_pixel_rows() {
+        // Anchor 100, range 95..110 over a 150px tall rect: 1% = 10px
+        let bounds = ChartBounds::new(0.0, 10.0, 95.0, 110.0);
+        let rect = PixelRect::new(0.0, 0.0, 100.0, 150.0);
+        let rows = percent_grid_rows(100.0, &bounds, &rect, 2.0);
+
+        let labels: Vec<String> = rows
+            .iter()
+            .map(|&(_, pct)| format_percent_label(pct))
+            .collect();
+        assert_eq!(
+            labels,
+            vec!["-4%", "-2%", "0%", "+2%", "+4%", "+6%", "+8%", "+10%"]
+        );
+
+        let ys: Vec<f32> = rows.iter().map(|&(y, _)| y.round()).collect();
+        assert_eq!(ys, vec![140.0, 120.0, 100.0, 80.0, 60.0, 40.0, 20.0, 0.0]);
+    }
+
+    #[test]
+    fn percent_step_widens_when_range_is_large() {
+        let bounds = ChartBounds::new(0.0, 10.0, 50.0, 150.0);
+        let levels = percent_grid_levels(100.0, &bounds, 1.0);
+        assert!(levels.len() <= MAX_PERCENT_GRID_LINES + 1);
+        assert_eq!(levels.first().map(|l| l.1), Some(-48.0));
+        assert!(percent_grid_levels(0.0, &bounds, 1.0).is_empty());
+    }
+
+    #[test]
+    fn percent_labels_show_sign_and_fraction() {
+        assert_eq!(format_percent_label(0.0), "0%");
+        assert_eq!(format_percent_label(-2.0), "-2%");
+        assert_eq!(format_percent_label(2.5), "+2.5%");
+    }
+}
@@ -4,28 +4,64 @@ use crate::base::layout::{HAlign, VAlign};
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
-use super::format::{format_change, format_price, format_price_short, format_volume_short};
+use super::format::{
+    format_change, format_price, format_range_labels, format_volume_short, pulse_color, ValueColor,
+};
+use super::sparkline::sparkline_text;
+use super::sparkline_chart::SPARKLINE_PANEL_PREFIX;
 use super::theme::GlTheme;
+use crate::app::CoinSortKey;
+use crate::config::{SparklineStyle, VolumeConfig};
 use crate::mock::CoinData;
+use std::time::Duration;
+
+/// Display name column width
+const NAME_WIDTH: f32 = 160.0;
+
+/// Trend column width (fits 20 sparkline glyphs)
+const SPARKLINE_WIDTH: f32 = 200.0;
+
+/// Marker id of the table panel (clips the drawn sparklines to the visible rows)
+pub const COIN_TABLE_ID: &str = "coin_table";
+
+/// Coin table column and display options
+#[derive(Clone, Copy)]
+pub struct CoinTableColumns {
+    /// Drop the name, trend, volume and high/low columns for narrow displays
+    pub compact: bool,
+    /// Glyph set for the trend column
+    pub sparkline: SparklineStyle,
+    /// Sorted column, marked in the header with its direction
+    pub sort_key: CoinSortKey,
+    pub sort_descending: bool,
+    /// Price pulse duration after a tick (None = off)
+    pub pulse: Option<Duration>,
+}
 
 /// Build the coin table widget
+///
+/// `order` lists indices into `coins` in display order; `selected_index` and
+/// `checked` refer to `coins`, so the highlight follows the coin, not the row.
 pub fn build_coin_table(
     coins: &[CoinData],
+    order: &[usize],
     selected_index: usize,
     checked: &[bool],
+    volume: &VolumeConfig,
+    columns: CoinTableColumns,
     theme: &GlTheme,
 ) -> PanelBuilder {
     // Build header row
-    let header = build_header_row(theme);
+    let header = build_header_row(columns, theme);
 
     // Build data rows
-    let rows: Vec<PanelBuilder> = coins
+    let rows: Vec<PanelBuilder> = order
         .iter()
-        .enumerate()
+        .filter_map(|&i| coins.get(i).map(|coin| (i, coin)))
         .map(|(i, coin)| {
             let is_selected = i == selected_index;
             let is_checked = checked.get(i).copied().unwrap_or(false);
-            build_coin_row(coin, is_selected, is_checked, theme)
+            build_coin_row(i, coin, is_selected, is_checked, volume, columns, theme)
         })
         .collect();
 
@@ -35,15 +71,28 @@ pub fn build_coin_table(
         .flex_direction(FlexDirection::Column)
         .overflow_scroll()
         .clip(true)
+        .marker_id(COIN_TABLE_ID)
         .child(header)
         .children(rows)
 }
 
-fn build_header_row(theme: &GlTheme) -> PanelBuilder {
+fn build_header_row(columns: CoinTableColumns, theme: &GlTheme) -> PanelBuilder {
     let row_height = theme.font_size * 2.0;
     let gap = theme.panel_gap;
+    let arrow = if columns.sort_descending {
+        "▼"
+    } else {
+        "▲"
+    };
+    let title = |label: &str, key: CoinSortKey| {
+        if columns.sort_key == key {
+            format!("{} {}", label, arrow)
+        } else {
+            label.to_string()
+        }
+    };
 
-    panel()
+    let row = panel()
         .width(percent(1.0))
         .height(length(row_height))
         .padding(gap / 2.0, gap, gap / 2.0, gap)
@@ -59,59 +108,89 @@ fn build_header_row(theme: &GlTheme) -> PanelBuilder {
         .child(
             panel()
                 .width(length(100.0))
-                .text("PAIR", theme.accent_secondary, theme.font_normal)
+                .text(
+                    title("PAIR", CoinSortKey::Symbol),
+                    theme.accent_secondary,
+                    theme.font_normal,
+                )
                 .text_align(HAlign::Left, VAlign::Center),
         )
         .child(
             panel()
                 .width(length(140.0))
-                .text("PRICE", theme.accent_secondary, theme.font_normal)
+                .text(
+                    title("PRICE", CoinSortKey::Price),
+                    theme.accent_secondary,
+                    theme.font_normal,
+                )
                 .text_align(HAlign::Left, VAlign::Center),
         )
         .child(
             panel()
                 .width(length(100.0))
-                .text("24h %", theme.accent_secondary, theme.font_normal)
-                .text_align(HAlign::Left, VAlign::Center),
-        )
-        .child(
-            panel()
-                .width(length(160.0))
-                .text("24h VOL", theme.accent_secondary, theme.font_normal)
-                .text_align(HAlign::Left, VAlign::Center),
-        )
-        .child(
-            panel()
-                .flex_grow(1.0)
-                .text("24h H/L", theme.accent_secondary, theme.font_normal)
+                .text(
+                    title("24h %", CoinSortKey::Change24h),
+                    theme.accent_secondary,
+                    theme.font_normal,
+                )
                 .text_align(HAlign::Left, VAlign::Center),
-        )
+        );
+
+    if columns.compact {
+        return row;
+    }
+
+    row.child(
+        panel()
+            .width(length(NAME_WIDTH))
+            .text("NAME", theme.accent_secondary, theme.font_normal)
+            .text_align(HAlign::Left, VAlign::Center),
+    )
+    .child(
+        panel()
+            .width(length(SPARKLINE_WIDTH))
+            .text("TREND", theme.accent_secondary, theme.font_normal)
+            .text_align(HAlign::Left, VAlign::Center),
+    )
+    .child(
+        panel()
+            .width(length(160.0))
+            .text(
+                title("24h VOL", CoinSortKey::Volume),
+                theme.accent_secondary,
+                theme.font_normal,
+            )
+            .text_align(HAlign::Left, VAlign::Center),
+    )
+    .child(
+        panel()
+            .flex_grow(1.0)
+            .text("24h H/L", theme.accent_secondary, theme.font_normal)
+            .text_align(HAlign::Left, VAlign::Center),
+    )
 }
 
 fn build_coin_row(
+    coin_index: usize,
     coin: &CoinData,
     is_selected: bool,
     is_checked: bool,
+    volume_config: &VolumeConfig,
+    columns: CoinTableColumns,
     theme: &GlTheme,
 ) -> PanelBuilder {
+    let pulse = coin.tick_pulse(columns.pulse);
     let checkbox = if is_checked { "[x]" } else { "[ ]" };
     let checkbox_text = checkbox.to_string();
 
     let pair = format!("{}/USD", coin.symbol);
     let price = format_price(coin.price);
     let change = format_change(coin.change_24h);
-    let volume = format_volume_short(coin.volume_usd, coin.volume_base);
-    let high_low = format!(
-        "{} / {}",
-        format_price_short(coin.high_24h),
-        format_price_short(coin.low_24h)
-    );
-
-    let change_color = if coin.change_24h >= 0.0 {
-        theme.positive
-    } else {
-        theme.negative
-    };
+    let volume = format_volume_short(coin.volume_usd, coin.volume_base, volume_config);
+    let (low_text, high_text) = format_range_labels(coin.low_24h, coin.high_24h);
+    let high_low = format!("{} / {}", high_text, low_text);
+
+    let change_color = ValueColor::Sign.color(coin.change_24h, theme);
 
     let bg_color = if is_selected {
         theme.selection_bg
@@ -122,7 +201,7 @@ fn build_coin_row(
     let row_height = theme.font_size * 2.0;
     let gap = theme.panel_gap;
 
-    panel()
+    let row = panel()
         .width(percent(1.0))
         .height(length(row_height))
         .padding(gap / 2.0, gap, gap / 2.0, gap)
@@ -147,7 +226,11 @@ fn build_coin_row(
         .child(
             panel()
                 .width(length(140.0))
-                .text(&price, theme.foreground, theme.font_normal)
+                .text(
+                    &price,
+                    pulse_color(theme.foreground, theme.accent, pulse),
+                    theme.font_normal,
+                )
                 .text_align(HAlign::Left, VAlign::Center),
         )
         // Change column
@@ -156,7 +239,36 @@ fn build_coin_row(
                 .width(length(100.0))
                 .text(&change, change_color, theme.font_normal)
                 .text_align(HAlign::Left, VAlign::Center),
+        );
+
+    if columns.compact {
+        return row;
+    }
+
+    // The line style is drawn over an empty, tagged cell after layout
+    let trend_cell = panel().width(length(SPARKLINE_WIDTH));
+    let trend_cell = if columns.sparkline == SparklineStyle::Line {
+        trend_cell
+            .height(percent(1.0))
+            .marker_id(format!("{}{}", SPARKLINE_PANEL_PREFIX, coin_index))
+    } else {
+        let trend = sparkline_text(&coin.sparkline, columns.sparkline);
+        trend_cell
+            .text(&trend, change_color, theme.font_normal)
+            .text_align(HAlign::Left, VAlign::Center)
+    };
+
+    row
+        // Name column
+        .child(
+            panel()
+                .width(length(NAME_WIDTH))
+                .ellipsize(true)
+                .text(&coin.name, theme.foreground_muted, theme.font_normal)
+                .text_align(HAlign::Left, VAlign::Center),
         )
+        // Trend column
+        .child(trend_cell)
         // Volume column
         .child(
             panel()
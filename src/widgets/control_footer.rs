@@ -2,15 +2,28 @@
 
 use crate::app::{ChartType, TimeWindow};
 use crate::base::{panel, taffy, PanelBuilder};
+use crate::notifications::Severity;
+use std::time::Duration;
 use taffy::prelude::*;
 
 use super::theme::GlTheme;
 use crate::base::view::footer_height;
+use crate::keymap::{Action, Keymap};
+
+/// Key hint such as "[Tab/Enter]", in the accent color
+fn key_hint(keys: String, theme: &GlTheme) -> PanelBuilder {
+    panel().text(
+        format!("[{}]", keys),
+        theme.accent_secondary,
+        theme.font_normal,
+    )
+}
 
 /// Build the control footer panel for Overview view
 pub fn build_overview_footer(
     selected_count: usize,
     total_count: usize,
+    keymap: &Keymap,
     theme: &GlTheme,
 ) -> PanelBuilder {
     let gap = theme.panel_gap;
@@ -36,7 +49,7 @@ pub fn build_overview_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[Space]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::Select), theme))
                 .child(panel().text("Toggle", theme.foreground, theme.font_normal)),
         )
         // Enter for details
@@ -44,7 +57,7 @@ pub fn build_overview_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[Enter]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::NextView), theme))
                 .child(panel().text("View Details", theme.foreground, theme.font_normal)),
         )
         // Arrow keys
@@ -52,7 +65,10 @@ pub fn build_overview_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[▲▼]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::MoveUp, Action::MoveDown),
+                    theme,
+                ))
                 .child(panel().text("Navigate", theme.foreground, theme.font_normal)),
         )
         // Quit
@@ -60,17 +76,29 @@ pub fn build_overview_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[q]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::Quit), theme))
                 .child(panel().text("Quit", theme.foreground, theme.font_normal)),
         )
 }
 
 /// Build the control footer panel for News view
-pub fn build_news_footer(loading: bool, theme: &GlTheme) -> PanelBuilder {
+pub fn build_news_footer(
+    loading: bool,
+    refresh_in: Option<Duration>,
+    filter_selected: bool,
+    keymap: &Keymap,
+    theme: &GlTheme,
+) -> PanelBuilder {
     let gap = theme.panel_gap;
     let footer_height = footer_height(theme); // Match other tabs
 
     let refresh_text = if loading { "Loading..." } else { "Refresh" };
+    // Countdown to the automatic refresh, e.g. "in 04:59"
+    let countdown = refresh_in.filter(|_| !loading).map(|left| {
+        let secs = left.as_secs();
+        format!("in {:02}:{:02}", secs / 60, secs % 60)
+    });
+    let filter_text = if filter_selected { "Checked" } else { "All" };
 
     panel()
         .width(percent(1.0))
@@ -86,15 +114,22 @@ pub fn build_news_footer(loading: bool, theme: &GlTheme) -> PanelBuilder {
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[r]", theme.accent_secondary, theme.font_normal))
-                .child(panel().text(refresh_text, theme.foreground, theme.font_normal)),
+                .child(key_hint(keymap.label(Action::Refresh), theme))
+                .child(panel().text(refresh_text, theme.foreground, theme.font_normal))
+                .children(
+                    countdown
+                        .map(|text| panel().text(&text, theme.foreground_muted, theme.font_small)),
+                ),
         )
         // Navigate headlines
         .child(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[j/k]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::MoveUp, Action::MoveDown),
+                    theme,
+                ))
                 .child(panel().text("Select", theme.foreground, theme.font_normal)),
         )
         // Scroll content
@@ -102,15 +137,27 @@ pub fn build_news_footer(loading: bool, theme: &GlTheme) -> PanelBuilder {
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[PgUp/Dn]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::ArticleUp, Action::ArticleDown),
+                    theme,
+                ))
                 .child(panel().text("Scroll", theme.foreground, theme.font_normal)),
         )
+        // Coin filter
+        .child(
+            panel()
+                .flex_direction(FlexDirection::Row)
+                .gap(gap / 2.0)
+                .child(key_hint(keymap.label(Action::NewsFilter), theme))
+                .child(panel().text("Show:", theme.foreground, theme.font_normal))
+                .child(panel().text(filter_text, theme.accent, theme.font_normal)),
+        )
         // View switch
         .child(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[Tab]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::NextView), theme))
                 .child(panel().text("View", theme.foreground, theme.font_normal)),
         )
         // Quit
@@ -118,13 +165,17 @@ pub fn build_news_footer(loading: bool, theme: &GlTheme) -> PanelBuilder {
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[q]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::Quit), theme))
                 .child(panel().text("Quit", theme.foreground, theme.font_normal)),
         )
 }
 
 /// Build the control footer panel for Notifications view
-pub fn build_notifications_footer(theme: &GlTheme) -> PanelBuilder {
+pub fn build_notifications_footer(
+    filter: Severity,
+    keymap: &Keymap,
+    theme: &GlTheme,
+) -> PanelBuilder {
     let gap = theme.panel_gap;
     let footer_height = footer_height(theme); // Align with other tabs
 
@@ -142,7 +193,7 @@ pub fn build_notifications_footer(theme: &GlTheme) -> PanelBuilder {
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[Tab]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::NextView), theme))
                 .child(panel().text("Switch view", theme.foreground, theme.font_normal)),
         )
         // Toggle rule
@@ -150,7 +201,7 @@ pub fn build_notifications_footer(theme: &GlTheme) -> PanelBuilder {
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[Space]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::Select), theme))
                 .child(panel().text("Toggle rule", theme.foreground, theme.font_normal)),
         )
         // Scroll
@@ -158,16 +209,30 @@ pub fn build_notifications_footer(theme: &GlTheme) -> PanelBuilder {
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[j/k]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::MoveUp, Action::MoveDown),
+                    theme,
+                ))
                 .child(panel().text("Navigate", theme.foreground, theme.font_normal)),
         )
+        // Severity filter
+        .child(
+            panel()
+                .flex_direction(FlexDirection::Row)
+                .gap(gap / 2.0)
+                .child(key_hint(keymap.label(Action::SeverityFilter), theme))
+                .child(panel().text("Show:", theme.foreground, theme.font_normal))
+                .child(panel().text(filter.filter_label(), theme.accent, theme.font_normal)),
+        )
 }
 
 /// Build the control footer panel for Details view
 pub fn build_details_footer(
-    time_window: TimeWindow,
+    time_window: &TimeWindow,
     chart_type: ChartType,
     ticker_muted: bool,
+    percent_grid: bool,
+    keymap: &Keymap,
     theme: &GlTheme,
 ) -> PanelBuilder {
     let gap = theme.panel_gap;
@@ -178,6 +243,7 @@ pub fn build_details_footer(
         ChartType::Polygonal => "Poly",
         ChartType::Candlestick => "Candle",
     };
+    let grid_display = if percent_grid { "%" } else { "$" };
     let mute_display = if ticker_muted { "Muted" } else { "On" };
     let mute_color = if ticker_muted {
         theme.foreground_muted
@@ -199,7 +265,10 @@ pub fn build_details_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[◄►]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::ScrollLeft, Action::ScrollRight),
+                    theme,
+                ))
                 .child(panel().text("Scroll Chart", theme.foreground, theme.font_normal)),
         )
         // Zoom
@@ -207,7 +276,10 @@ pub fn build_details_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[▲▼]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::ZoomIn, Action::ZoomOut),
+                    theme,
+                ))
                 .child(panel().text("Zoom", theme.foreground, theme.font_normal)),
         )
         // Window change with current value
@@ -215,7 +287,7 @@ pub fn build_details_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[w]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::CycleWindow), theme))
                 .child(panel().text("Window:", theme.foreground_muted, theme.font_normal))
                 .child(panel().text(window_display, theme.accent, theme.font_normal)),
         )
@@ -224,23 +296,44 @@ pub fn build_details_footer(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[c]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::ChartType), theme))
                 .child(panel().text("Chart:", theme.foreground_muted, theme.font_normal))
                 .child(panel().text(chart_display, theme.accent, theme.font_normal)),
         )
+        // Grid mode with current value
+        .child(
+            panel()
+                .flex_direction(FlexDirection::Row)
+                .gap(gap / 2.0)
+                .child(key_hint(keymap.label(Action::PercentGrid), theme))
+                .child(panel().text("Grid:", theme.foreground_muted, theme.font_normal))
+                .child(panel().text(grid_display, theme.accent, theme.font_normal)),
+        )
         // Mute toggle with current state
         .child(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[m]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::ToggleMute), theme))
                 .child(panel().text("Sound:", theme.foreground_muted, theme.font_normal))
                 .child(panel().text(mute_display, mute_color, theme.font_normal)),
         )
+        // Focus indicators for tooltips
+        .child(
+            panel()
+                .flex_direction(FlexDirection::Row)
+                .gap(gap / 2.0)
+                .child(key_hint(keymap.label(Action::FocusIndicators), theme))
+                .child(panel().text("Info", theme.foreground, theme.font_normal)),
+        )
 }
 
 /// Build the control footer panel for Positions view
-pub fn build_positions_footer(margin_level: Option<f64>, theme: &GlTheme) -> PanelBuilder {
+pub fn build_positions_footer(
+    margin_level: Option<f64>,
+    keymap: &Keymap,
+    theme: &GlTheme,
+) -> PanelBuilder {
     let gap = theme.panel_gap;
     let footer_height = footer_height(theme);
 
@@ -257,14 +350,45 @@ pub fn build_positions_footer(margin_level: Option<f64>, theme: &GlTheme) -> Pan
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[r]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(keymap.label(Action::Refresh), theme))
                 .child(panel().text("Refresh", theme.foreground, theme.font_normal)),
         )
         .child(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[▲▼]", theme.accent_secondary, theme.font_normal))
+                .child(key_hint(
+                    keymap.pair_label(Action::MoveUp, Action::MoveDown),
+                    theme,
+                ))
                 .child(panel().text("Select", theme.foreground, theme.font_normal)),
         )
 }
+
+/// Build the control footer panel for Diagnostics view
+pub fn build_diagnostics_footer(keymap: &Keymap, theme: &GlTheme) -> PanelBuilder {
+    let gap = theme.panel_gap;
+    let footer_height = footer_height(theme);
+
+    panel()
+        .width(percent(1.0))
+        .height(length(footer_height))
+        .flex_direction(FlexDirection::Row)
+        .align_items(AlignItems::Center)
+        .gap(gap * 2.0)
+        .padding_all(theme.panel_padding)
+        .background(theme.background_panel)
+        .border_solid(1.0, theme.border)
+        .child(
+            panel()
+                .flex_direction(FlexDirection::Row)
+                .gap(gap / 2.0)
+                .child(key_hint(keymap.label(Action::NextView), theme))
+                .child(panel().text("Switch view", theme.foreground, theme.font_normal)),
+        )
+        .child(panel().text(
+            "Connection events, newest first",
+            theme.foreground_muted,
+            theme.font_normal,
+        ))
+}
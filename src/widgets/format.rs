@@ -1,6 +1,7 @@
 //! Formatting utilities for displaying prices, volumes, and percentages
 
 use super::theme::{Color, GlTheme};
+use crate::config::{VolumeConfig, VolumeUnit};
 
 /// Round price to match the display format precision.
 /// This ensures consistent change detection between UI display and audio tones.
@@ -18,10 +19,19 @@ pub fn round_to_display(price: f64) -> f64 {
 
 /// Format price with appropriate precision and commas
 pub fn format_price(price: f64) -> String {
+    format_price_grouped(price, true)
+}
+
+/// Format price, with or without thousands separators ("$67,432.10" vs "$67432.10")
+pub fn format_price_grouped(price: f64, group_thousands: bool) -> String {
     if price >= 1000.0 {
         let whole = price as u64;
         let frac = ((price - whole as f64) * 100.0).round() as u64;
-        let formatted = format_with_commas(whole);
+        let formatted = if group_thousands {
+            format_with_commas(whole)
+        } else {
+            whole.to_string()
+        };
         format!("${}.{:02}", formatted, frac)
     } else if price >= 1.0 {
         format!("${:.2}", price)
@@ -32,6 +42,12 @@ pub fn format_price(price: f64) -> String {
     }
 }
 
+/// Format a signed USD amount ("+$1,204.50", "-$88.20")
+pub fn format_signed_price(value: f64) -> String {
+    let sign = if value < 0.0 { "-" } else { "+" };
+    format!("{}{}", sign, format_price(value.abs()))
+}
+
 /// Format price in short form (e.g., "$67k")
 pub fn format_price_short(price: f64) -> String {
     if price >= 1000.0 {
@@ -43,6 +59,48 @@ pub fn format_price_short(price: f64) -> String {
     }
 }
 
+/// Upper bound for range label precision
+const MAX_RANGE_DECIMALS: i32 = 8;
+
+/// Decimal places needed to tell `low` and `high` apart
+///
+/// Derived from the size of the range rather than the price, so a tight window
+/// range (e.g. a stablecoin at $0.9998-$1.0003) still gets distinct labels.
+pub fn precision_for_range(low: f64, high: f64) -> usize {
+    let range = (high - low).abs();
+    if !range.is_finite() || range <= 0.0 {
+        return 2;
+    }
+    ((-range.log10()).ceil() as i32).clamp(0, MAX_RANGE_DECIMALS) as usize
+}
+
+/// Format low/high range labels, short form unless that makes them identical
+pub fn format_range_labels(low: f64, high: f64) -> (String, String) {
+    let (low_text, high_text) = (format_price_short(low), format_price_short(high));
+    if low_text != high_text || low == high {
+        return (low_text, high_text);
+    }
+
+    let decimals = precision_for_range(low, high);
+    (
+        format!("${:.*}", decimals, low),
+        format!("${:.*}", decimals, high),
+    )
+}
+
+/// Format price in compact form for the big price display (e.g., "$67.4K", "$1.25M")
+pub fn format_price_compact(price: f64) -> String {
+    if price >= 1_000_000_000.0 {
+        format!("${:.2}B", price / 1_000_000_000.0)
+    } else if price >= 1_000_000.0 {
+        format!("${:.2}M", price / 1_000_000.0)
+    } else if price >= 1_000.0 {
+        format!("${:.1}K", price / 1_000.0)
+    } else {
+        format_price(price)
+    }
+}
+
 fn format_with_commas(n: u64) -> String {
     let s = n.to_string();
     let mut result = String::new();
@@ -60,25 +118,43 @@ pub fn format_change(change: f64) -> String {
     format!("{:+.2}%", change)
 }
 
-/// Format volume in short form
-pub fn format_volume_short(volume_usd: f64, volume_base: f64) -> String {
-    let usd = if volume_usd >= 1_000_000_000.0 {
-        format!("${:.1}B", volume_usd / 1_000_000_000.0)
-    } else if volume_usd >= 1_000_000.0 {
-        format!("${:.0}M", volume_usd / 1_000_000.0)
+/// Format a volume with K/M/B suffixes using the configured thresholds and precision
+///
+/// Each threshold is also the size of its unit. Without a configured precision,
+/// B (and base-unit M) volumes get one decimal and smaller ones none.
+pub fn format_volume(volume: f64, is_quote: bool, config: &VolumeConfig) -> String {
+    let (unit, suffix, default_decimals) = if volume >= config.billion {
+        (config.billion, "B", 1)
+    } else if volume >= config.million {
+        (config.million, "M", if is_quote { 0 } else { 1 })
+    } else if volume >= config.thousand {
+        (config.thousand, "K", 0)
     } else {
-        format!("${:.0}K", volume_usd / 1_000.0)
+        (1.0, "", 0)
     };
+    let decimals = config.decimals.unwrap_or(default_decimals);
+    format!("{:.*}{}", decimals, volume / unit, suffix)
+}
 
-    let base = if volume_base >= 1_000_000.0 {
-        format!("{:.1}M", volume_base / 1_000_000.0)
-    } else if volume_base >= 1_000.0 {
-        format!("{:.0}K", volume_base / 1_000.0)
-    } else {
-        format!("{:.0}", volume_base)
-    };
+/// Format volume in short form, in the configured units (e.g., "$28.4B / 421K")
+pub fn format_volume_short(volume_usd: f64, volume_base: f64, config: &VolumeConfig) -> String {
+    let usd = format!("${}", format_volume(volume_usd, true, config));
+    let base = format_volume(volume_base, false, config);
+
+    match config.unit {
+        VolumeUnit::Both => format!("{} / {}", usd, base),
+        VolumeUnit::Quote => usd,
+        VolumeUnit::Base => base,
+    }
+}
 
-    format!("{} / {}", usd, base)
+/// Format volume in full with thousands separators (e.g., "1,234,567")
+pub fn format_volume_full(volume: f64) -> String {
+    if volume >= 1_000.0 {
+        format_with_commas(volume.round() as u64)
+    } else {
+        format!("{:.2}", volume)
+    }
 }
 
 /// Capitalize first letter
@@ -90,15 +166,84 @@ pub fn capitalize(s: &str) -> String {
     }
 }
 
+/// Blend a color toward the accent for the live-tick pulse (`amount` 0..1, kept subtle)
+pub fn pulse_color(base: Color, accent: Color, amount: f32) -> Color {
+    const MAX_BLEND: f32 = 0.5;
+    let t = amount.clamp(0.0, 1.0) * MAX_BLEND;
+    [
+        base[0] + (accent[0] - base[0]) * t,
+        base[1] + (accent[1] - base[1]) * t,
+        base[2] + (accent[2] - base[2]) * t,
+        base[3],
+    ]
+}
+
+/// Value-driven text color for numeric values (change %, PnL, oscillators)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueColor {
+    /// Positive above zero, negative below, muted at exactly zero
+    Sign,
+    /// Oscillator bands: positive below `low` (oversold), negative above `high`
+    /// (overbought), foreground in between
+    Bands { low: f64, high: f64 },
+}
+
+/// RSI bands: <30 oversold, >70 overbought
+pub const RSI_BANDS: ValueColor = ValueColor::Bands {
+    low: 30.0,
+    high: 70.0,
+};
+
+impl ValueColor {
+    pub fn color(self, value: f64, theme: &GlTheme) -> Color {
+        match self {
+            Self::Sign if value > 0.0 => theme.positive,
+            Self::Sign if value < 0.0 => theme.negative,
+            Self::Sign => theme.foreground_muted,
+            Self::Bands { low, .. } if value < low => theme.positive,
+            Self::Bands { high, .. } if value > high => theme.negative,
+            Self::Bands { .. } => theme.foreground,
+        }
+    }
+
+    /// Color for a formatted cell ("+2.34%", "$-1,204.50"); None when it holds no number
+    pub fn color_for_text(self, text: &str, theme: &GlTheme) -> Option<Color> {
+        parse_display_number(text).map(|value| self.color(value, theme))
+    }
+}
+
+/// Numeric value of formatted text, ignoring currency signs, separators and units
+fn parse_display_number(text: &str) -> Option<f64> {
+    let digits: String = text
+        .chars()
+        .filter(|c| c.is_ascii_digit() || matches!(c, '.' | '-'))
+        .collect();
+    digits.parse().ok()
+}
+
+/// Whether a tick from `previous` to `current` moved less than `neutral_pct` percent
+///
+/// A percentage rather than an absolute amount so one threshold fits coins of any price.
+/// 0.0 disables the zone.
+pub fn in_neutral_zone(current: f64, previous: f64, neutral_pct: f64) -> bool {
+    previous > 0.0 && ((current - previous) / previous * 100.0).abs() < neutral_pct
+}
+
 /// Calculate color for price based on change compared to historical average
-/// Uses rounded prices to match display precision
-pub fn price_change_color(current: f64, previous: f64, avg_change: f64, theme: &GlTheme) -> Color {
+/// Uses rounded prices to match display precision; ticks inside the neutral zone stay neutral
+pub fn price_change_color(
+    current: f64,
+    previous: f64,
+    avg_change: f64,
+    neutral_pct: f64,
+    theme: &GlTheme,
+) -> Color {
     // Round to display precision so colors match visible price changes
     let current_rounded = round_to_display(current);
     let previous_rounded = round_to_display(previous);
     let change = current_rounded - previous_rounded;
 
-    if change == 0.0 {
+    if change == 0.0 || in_neutral_zone(current, previous, neutral_pct) {
         return theme.neutral;
     }
 
@@ -135,3 +280,192 @@ pub fn price_change_color(current: f64, previous: f64, avg_change: f64, theme: &
         }
     }
 }
+
+/// Format a Unix timestamp as UTC date and time ("2024-03-09 16:00 UTC")
+pub fn format_utc_time(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86400);
+    let secs = timestamp.rem_euclid(86400);
+
+    // Civil date from days since 1970-01-01 (proleptic Gregorian calendar)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        secs / 3600,
+        (secs % 3600) / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_unit(unit: VolumeUnit) -> VolumeConfig {
+        VolumeConfig {
+            unit,
+            ..VolumeConfig::default()
+        }
+    }
+
+    #[test]
+    fn grouping_adds_one_separator_per_thousand() {
+        let cases = [
+            (999.5, "$999.50", "$999.50"),
+            (1_234.5, "$1,234.50", "$1234.50"),
+            (67_432.1, "$67,432.10", "$67432.10"),
+            (1_234_567.0, "$1,234,567.00", "$1234567.00"),
+        ];
+        for (price, grouped, plain) in cases {
+            assert_eq!(format_price_grouped(price, true), grouped);
+            assert_eq!(format_price_grouped(price, false), plain);
+            let separators = (price as u64).to_string().len().saturating_sub(1) / 3;
+            assert_eq!(grouped.chars().count(), plain.chars().count() + separators);
+        }
+        assert_eq!(format_price(67_432.1), format_price_grouped(67_432.1, true));
+    }
+
+    #[test]
+    fn neutral_zone_boundary() {
+        // 1% zone around 100: moves under 1% are inside, exactly 1% is not
+        assert!(in_neutral_zone(100.99, 100.0, 1.0));
+        assert!(in_neutral_zone(99.01, 100.0, 1.0));
+        assert!(!in_neutral_zone(101.0, 100.0, 1.0));
+        assert!(!in_neutral_zone(99.0, 100.0, 1.0));
+        // Disabled zone and missing previous price
+        assert!(!in_neutral_zone(100.01, 100.0, 0.0));
+        assert!(!in_neutral_zone(100.0, 0.0, 0.1));
+    }
+
+    #[test]
+    fn ticks_inside_the_neutral_zone_keep_the_neutral_color() {
+        let theme = GlTheme::default();
+        assert_eq!(
+            price_change_color(100.05, 100.0, 0.0, 0.1, &theme),
+            theme.neutral
+        );
+        assert_eq!(
+            price_change_color(99.95, 100.0, 0.0, 0.1, &theme),
+            theme.neutral
+        );
+        assert_eq!(
+            price_change_color(100.2, 100.0, 0.0, 0.1, &theme),
+            theme.price_up_low
+        );
+        assert_eq!(
+            price_change_color(99.8, 100.0, 0.0, 0.1, &theme),
+            theme.price_down_low
+        );
+        // Default 0.0 keeps coloring every visible change
+        assert_eq!(
+            price_change_color(100.01, 100.0, 0.0, 0.0, &theme),
+            theme.price_up_low
+        );
+        assert_eq!(
+            price_change_color(100.0, 100.0, 0.0, 0.0, &theme),
+            theme.neutral
+        );
+    }
+
+    #[test]
+    fn compact_price_abbreviates_large_values() {
+        assert_eq!(format_price_compact(999.99), "$999.99");
+        assert_eq!(format_price_compact(1_000.0), "$1.0K");
+        assert_eq!(format_price_compact(67_432.10), "$67.4K");
+        assert_eq!(format_price_compact(1_250_000.0), "$1.25M");
+        assert_eq!(format_price_compact(3_400_000_000.0), "$3.40B");
+        assert_eq!(format_price_compact(0.5), "$0.5000");
+    }
+
+    #[test]
+    fn range_precision_follows_range_size() {
+        assert_eq!(precision_for_range(65_000.0, 68_000.0), 0);
+        assert_eq!(precision_for_range(1.0, 1.5), 1);
+        assert_eq!(precision_for_range(0.9998, 1.0003), 4);
+        assert_eq!(precision_for_range(1.0003, 0.9998), 4);
+        assert_eq!(precision_for_range(1e-12, 2e-12), 8);
+        // Empty or invalid ranges fall back to cents
+        assert_eq!(precision_for_range(1.0, 1.0), 2);
+        assert_eq!(precision_for_range(f64::NAN, 1.0), 2);
+    }
+
+    #[test]
+    fn range_labels_stay_distinct_for_tight_ranges() {
+        assert_eq!(
+            format_range_labels(65_000.0, 68_000.0),
+            ("$65k".to_string(), "$68k".to_string())
+        );
+        assert_eq!(
+            format_range_labels(1.0001, 1.0004),
+            ("$1.0001".to_string(), "$1.0004".to_string())
+        );
+    }
+
+    #[test]
+    fn default_volume_matches_previous_format() {
+        let config = VolumeConfig::default();
+        assert_eq!(
+            format_volume_short(28_400_000_000.0, 421_234.0, &config),
+            "$28.4B / 421K"
+        );
+        assert_eq!(
+            format_volume_short(421_300_000.0, 1_234_567.0, &config),
+            "$421M / 1.2M"
+        );
+        assert_eq!(format_volume_short(42_400.0, 999.0, &config), "$42K / 999");
+    }
+
+    #[test]
+    fn volume_suffix_switches_at_each_threshold() {
+        let config = VolumeConfig::default();
+        assert_eq!(format_volume(999.0, true, &config), "999");
+        assert_eq!(format_volume(1_000.0, true, &config), "1K");
+        assert_eq!(format_volume(999_999.0, false, &config), "1000K");
+        assert_eq!(format_volume(1_000_000.0, true, &config), "1M");
+        assert_eq!(format_volume(1_000_000.0, false, &config), "1.0M");
+        assert_eq!(format_volume(999_999_999.0, true, &config), "1000M");
+        assert_eq!(format_volume(1_000_000_000.0, false, &config), "1.0B");
+    }
+
+    #[test]
+    fn volume_divisors_follow_configured_thresholds() {
+        // "Lakh/crore" style units with fixed precision
+        let config = VolumeConfig {
+            decimals: Some(2),
+            thousand: 100_000.0,
+            million: 10_000_000.0,
+            billion: f64::INFINITY,
+            ..VolumeConfig::default()
+        };
+        assert_eq!(format_volume(99_999.0, true, &config), "99999.00");
+        assert_eq!(format_volume(150_000.0, true, &config), "1.50K");
+        assert_eq!(format_volume(25_000_000.0, false, &config), "2.50M");
+    }
+
+    #[test]
+    fn volume_unit_selects_sides() {
+        let (usd, base) = (2_500_000_000.0, 52_000.0);
+        assert_eq!(
+            format_volume_short(usd, base, &with_unit(VolumeUnit::Both)),
+            "$2.5B / 52K"
+        );
+        assert_eq!(
+            format_volume_short(usd, base, &with_unit(VolumeUnit::Quote)),
+            "$2.5B"
+        );
+        assert_eq!(
+            format_volume_short(usd, base, &with_unit(VolumeUnit::Base)),
+            "52K"
+        );
+    }
+}
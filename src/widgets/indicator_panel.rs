@@ -1,14 +1,30 @@
-//! Indicator panel widget displaying RSI and EMA values in aligned columns
+//! Indicator panel widget displaying RSI, EMA and VWAP values in aligned columns
+//! (or as a compact single-line strip for narrow columns)
 
+use crate::base::grapheme::truncate_graphemes;
 use crate::base::layout::{HAlign, VAlign};
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
+use super::format::{format_price, ValueColor, RSI_BANDS};
+use super::text_box::char_width_px;
 use super::theme::GlTheme;
 use crate::mock::IndicatorData;
 
+/// Separator between segments of the compact indicator strip
+const STRIP_SEPARATOR: &str = " | ";
+
 /// Build the indicator panel displaying technical indicators
-pub fn build_indicator_panel(indicators: &IndicatorData, theme: &GlTheme) -> PanelBuilder {
+///
+/// Each value is focusable (id `ind_{symbol}_{prefix}_{period}`, `ind_{symbol}_vwap`)
+/// and carries a tooltip explaining the indicator. VWAP is colored by whether `price`
+/// is above or below it.
+pub fn build_indicator_panel(
+    symbol: &str,
+    indicators: &IndicatorData,
+    price: f64,
+    theme: &GlTheme,
+) -> PanelBuilder {
     let gap = theme.panel_gap;
     let freq_colors = [
         theme.indicator_primary,
@@ -21,6 +37,7 @@ pub fn build_indicator_panel(indicators: &IndicatorData, theme: &GlTheme) -> Pan
         .flex_direction(FlexDirection::Column)
         .gap(gap / 2.0)
         .child(build_three_column_row(
+            symbol,
             "RSI",
             [
                 ("6", indicators.rsi_6),
@@ -28,9 +45,11 @@ pub fn build_indicator_panel(indicators: &IndicatorData, theme: &GlTheme) -> Pan
                 ("24", indicators.rsi_24),
             ],
             freq_colors,
+            Some(RSI_BANDS),
             theme,
         ))
         .child(build_three_column_row(
+            symbol,
             "EMA",
             [
                 ("7", indicators.ema_7),
@@ -38,14 +57,75 @@ pub fn build_indicator_panel(indicators: &IndicatorData, theme: &GlTheme) -> Pan
                 ("99", indicators.ema_99),
             ],
             freq_colors,
+            None,
             theme,
         ))
+        .child(build_vwap_row(symbol, indicators.vwap, price, theme))
+}
+
+/// Build the indicators as a single line, e.g.
+/// `RSI6/12/24 62/58/55 | EMA7>25>99 | MACD+ | VWAP+`
+///
+/// `width` is the available panel width; the line is cut with an ellipsis when it
+/// doesn't fit.
+pub fn build_indicator_strip(
+    indicators: &IndicatorData,
+    price: f64,
+    width: f32,
+    theme: &GlTheme,
+) -> PanelBuilder {
+    let char_width = char_width_px('M', theme.font_size * theme.font_medium).max(1.0);
+    let max_chars = ((width / char_width).floor() as usize).max(1);
+    let line = truncate_graphemes(&indicator_strip_text(indicators, price), max_chars);
+
+    panel()
+        .width(percent(1.0))
+        .text(&line, theme.indicator_primary, theme.font_medium)
+        .text_align(HAlign::Left, VAlign::Center)
+}
+
+fn indicator_strip_text(indicators: &IndicatorData, price: f64) -> String {
+    let rsi = format!(
+        "RSI6/12/24 {:.0}/{:.0}/{:.0}",
+        indicators.rsi_6, indicators.rsi_12, indicators.rsi_24
+    );
+    let ema = format!(
+        "EMA7{}25{}99",
+        ema_relation(indicators.ema_7, indicators.ema_25),
+        ema_relation(indicators.ema_25, indicators.ema_99)
+    );
+    let macd = if indicators.macd_histogram >= 0.0 {
+        "MACD+"
+    } else {
+        "MACD-"
+    };
+
+    let mut segments = vec![rsi.as_str(), ema.as_str(), macd];
+    if indicators.vwap > 0.0 {
+        segments.push(if price >= indicators.vwap {
+            "VWAP+"
+        } else {
+            "VWAP-"
+        });
+    }
+
+    segments.join(STRIP_SEPARATOR)
+}
+
+fn ema_relation(fast: f64, slow: f64) -> char {
+    if fast >= slow {
+        '>'
+    } else {
+        '<'
+    }
 }
 
 fn build_three_column_row(
+    symbol: &str,
     prefix: &str,
     values: [(&str, f64); 3],
     freq_colors: [[f32; 4]; 3],
+    value_color: Option<ValueColor>,
     theme: &GlTheme,
 ) -> PanelBuilder {
     let gap = theme.panel_gap;
@@ -59,22 +139,42 @@ fn build_three_column_row(
                 .iter()
                 .zip(freq_colors.iter())
                 .map(|((label, value), color)| {
-                    build_indicator_column(prefix, label, *value, *color, theme)
+                    // Values take the column color unless colored by their own level
+                    let value_color = value_color.map_or(*color, |vc| vc.color(*value, theme));
+                    build_indicator_column(
+                        symbol,
+                        prefix,
+                        label,
+                        *value,
+                        *color,
+                        value_color,
+                        theme,
+                    )
                 })
                 .collect::<Vec<_>>(),
         )
 }
 
 fn build_indicator_column(
+    symbol: &str,
     prefix: &str,
     label: &str,
     value: f64,
     column_color: [f32; 4],
+    value_color: [f32; 4],
     theme: &GlTheme,
 ) -> PanelBuilder {
     let value_text = format!("{:.1}", value);
     panel()
         .flex_grow(1.0)
+        .focusable(format!(
+            "ind_{}_{}_{}",
+            symbol,
+            prefix.to_lowercase(),
+            label
+        ))
+        .focus_border(theme.border_focus)
+        .tooltip(indicator_tooltip(prefix, label))
         .flex_direction(FlexDirection::Row)
         .gap(theme.panel_gap / 4.0)
         .child(
@@ -88,7 +188,55 @@ fn build_indicator_column(
         )
         .child(
             panel()
-                .text(&format!(" {}", value_text), column_color, theme.font_medium)
+                .text(&format!(" {}", value_text), value_color, theme.font_medium)
                 .text_align(HAlign::Left, VAlign::Center),
         )
 }
+
+/// VWAP row: green when the price is at or above VWAP, red below, muted until calculated
+fn build_vwap_row(symbol: &str, vwap: f64, price: f64, theme: &GlTheme) -> PanelBuilder {
+    let (value_text, color) = if vwap > 0.0 {
+        let color = if price >= vwap {
+            theme.positive
+        } else {
+            theme.negative
+        };
+        (format_price(vwap), color)
+    } else {
+        ("--".to_string(), theme.foreground_muted)
+    };
+
+    panel()
+        .width(percent(1.0))
+        .focusable(format!("ind_{}_vwap", symbol))
+        .focus_border(theme.border_focus)
+        .tooltip(indicator_tooltip("VWAP", ""))
+        .flex_direction(FlexDirection::Row)
+        .gap(theme.panel_gap / 4.0)
+        .child(
+            panel()
+                .text("VWAP:", color, theme.font_medium)
+                .text_align(HAlign::Left, VAlign::Center),
+        )
+        .child(
+            panel()
+                .text(format!(" {}", value_text), color, theme.font_medium)
+                .text_align(HAlign::Left, VAlign::Center),
+        )
+}
+
+/// Short explanation shown when an indicator value is focused
+fn indicator_tooltip(prefix: &str, label: &str) -> String {
+    match prefix {
+        "RSI" => format!(
+            "RSI({}): {}-candle momentum, 0-100. >70 overbought, <30 oversold",
+            label, label
+        ),
+        "EMA" => format!(
+            "EMA({}): exponential moving average of the last {} closes",
+            label, label
+        ),
+        "VWAP" => "VWAP: average price weighted by volume over the loaded candles".to_string(),
+        _ => format!("{}({})", prefix, label),
+    }
+}
@@ -12,6 +12,8 @@ pub struct CandleIndicators {
     pub ema_25: Vec<f64>,
     /// EMA 99 values per candle
     pub ema_99: Vec<f64>,
+    /// MACD (12, 26, 9) per candle as (line, signal, histogram)
+    pub macd: Vec<(f64, f64, f64)>,
 }
 
 impl Default for CandleIndicators {
@@ -21,6 +23,7 @@ impl Default for CandleIndicators {
             ema_7: Vec::new(),
             ema_25: Vec::new(),
             ema_99: Vec::new(),
+            macd: Vec::new(),
         }
     }
 }
@@ -34,6 +37,7 @@ impl CandleIndicators {
                 ema_7: Vec::new(),
                 ema_25: Vec::new(),
                 ema_99: Vec::new(),
+                macd: Vec::new(),
             };
         }
 
@@ -44,11 +48,12 @@ impl CandleIndicators {
             ema_7: Self::calculate_ema_series(&closes, 7),
             ema_25: Self::calculate_ema_series(&closes, 25),
             ema_99: Self::calculate_ema_series(&closes, 99),
+            macd: macd_series(&closes, 12, 26, 9),
         }
     }
 
     /// Calculate RSI for each candle (returns Vec same length as input)
-    fn calculate_rsi_series(prices: &[f64], period: usize) -> Vec<f64> {
+    pub fn calculate_rsi_series(prices: &[f64], period: usize) -> Vec<f64> {
         let mut result = vec![50.0; prices.len()]; // Default neutral RSI
 
         if prices.len() < period + 1 {
@@ -130,3 +135,143 @@ impl CandleIndicators {
         result
     }
 }
+
+/// Bollinger Bands over closes: (upper, middle, lower), each the same length as `closes`
+///
+/// The middle band is the `period` SMA and the outer bands sit `std_mult` population
+/// standard deviations away. Entries before the first full window are 0.0 (not calculated).
+pub fn bollinger_bands(
+    closes: &[f64],
+    period: usize,
+    std_mult: f64,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut upper = vec![0.0; closes.len()];
+    let mut middle = vec![0.0; closes.len()];
+    let mut lower = vec![0.0; closes.len()];
+
+    if period == 0 || closes.len() < period {
+        return (upper, middle, lower);
+    }
+
+    for (i, window) in closes.windows(period).enumerate() {
+        let idx = i + period - 1;
+        let mean = window.iter().sum::<f64>() / period as f64;
+        let variance = window.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / period as f64;
+        let offset = variance.sqrt() * std_mult;
+
+        upper[idx] = mean + offset;
+        middle[idx] = mean;
+        lower[idx] = mean - offset;
+    }
+
+    (upper, middle, lower)
+}
+
+/// Volume-weighted average price: sum of typical price (H+L+C)/3 x volume over total volume
+///
+/// Candles without volume are skipped; returns 0.0 (not calculated) when no candle has volume.
+pub fn vwap(candles: &[Candle]) -> f64 {
+    let (weighted, volume) =
+        candles
+            .iter()
+            .filter(|c| c.volume > 0.0)
+            .fold((0.0, 0.0), |(weighted, volume), c| {
+                let typical = (c.high + c.low + c.close) / 3.0;
+                (weighted + typical * c.volume, volume + c.volume)
+            });
+
+    if volume > 0.0 {
+        weighted / volume
+    } else {
+        0.0
+    }
+}
+
+/// Simple moving average of the last `period` prices (0.0 = not enough data)
+pub fn sma(prices: &[f64], period: usize) -> f64 {
+    if period == 0 || prices.len() < period {
+        return 0.0;
+    }
+
+    prices[prices.len() - period..].iter().sum::<f64>() / period as f64
+}
+
+/// MACD per close as (line, signal, histogram)
+///
+/// The line is EMA(fast) - EMA(slow); the signal is a `signal`-period EMA run
+/// over the line series only from the first candle with a full slow EMA, so
+/// warm-up values never leak into it. Entries before that candle, and the
+/// whole series when there are fewer than `slow + signal` closes, are neutral
+/// zeros.
+pub fn macd_series(
+    closes: &[f64],
+    fast: usize,
+    slow: usize,
+    signal: usize,
+) -> Vec<(f64, f64, f64)> {
+    let mut result = vec![(0.0, 0.0, 0.0); closes.len()];
+    if fast == 0 || slow == 0 || signal == 0 || closes.len() < slow + signal {
+        return result;
+    }
+
+    let fast_ema = CandleIndicators::calculate_ema_series(closes, fast);
+    let slow_ema = CandleIndicators::calculate_ema_series(closes, slow);
+
+    // First index where the slow EMA is a real EMA rather than a running average
+    let start = slow - 1;
+    let line: Vec<f64> = (start..closes.len())
+        .map(|i| fast_ema[i] - slow_ema[i])
+        .collect();
+    let signal_ema = CandleIndicators::calculate_ema_series(&line, signal);
+
+    for (offset, (&l, &s)) in line.iter().zip(&signal_ema).enumerate() {
+        result[start + offset] = (l, s, l - s);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixed 40-candle input: an oscillating uptrend with a jump every 7th close
+    const CLOSES: [f64; 40] = [
+        103.0, 102.23, 104.34, 106.2, 107.73, 108.84, 109.48, 112.62, 109.27, 108.47, 107.29,
+        105.8, 104.13, 102.38, 103.69, 99.18, 97.95, 97.09, 96.68, 96.76, 97.33, 101.38, 99.86,
+        101.68, 103.76, 105.98, 108.22, 110.35, 115.26, 113.83, 115.0, 115.71, 115.91, 115.63,
+        114.89, 116.75, 112.3, 110.64, 108.9, 107.19,
+    ];
+
+    #[test]
+    fn macd_matches_tradingview_reference() {
+        // TradingView ta.macd(close, 12, 26, 9): SMA-seeded EMAs, signal defined from bar 33
+        let expected = [
+            (2.753868, 0.837928, 1.915940),
+            (2.845967, 1.239536, 1.606432),
+            (3.034068, 1.598442, 1.435626),
+            (2.791879, 1.837130, 0.954750),
+            (2.437892, 1.957282, 0.480610),
+            (1.993965, 1.964619, 0.029347),
+            (1.487026, 1.869100, -0.382074),
+        ];
+        let series = macd_series(&CLOSES, 12, 26, 9);
+        assert_eq!(series.len(), CLOSES.len());
+        for (&(line, signal, hist), &(l, s, h)) in series[33..].iter().zip(&expected) {
+            assert!((line - l).abs() < 1e-5, "line {} vs {}", line, l);
+            assert!((signal - s).abs() < 1e-5, "signal {} vs {}", signal, s);
+            assert!((hist - h).abs() < 1e-5, "histogram {} vs {}", hist, h);
+        }
+        // Nothing before the slow EMA is complete
+        assert!(series[..25].iter().all(|&v| v == (0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn macd_is_neutral_without_slow_plus_signal_closes() {
+        let series = macd_series(&CLOSES[..34], 12, 26, 9);
+        assert!(series.iter().all(|&v| v == (0.0, 0.0, 0.0)));
+        assert!(macd_series(&CLOSES, 12, 0, 9)
+            .iter()
+            .all(|&v| v == (0.0, 0.0, 0.0)));
+    }
+}
@@ -10,9 +10,12 @@ pub mod control_footer;
 pub mod format;
 pub mod indicator_panel;
 pub mod indicators;
+pub mod modal;
 pub mod polygonal_chart;
 pub mod positions_table;
 pub mod price_panel;
+pub mod sparkline;
+pub mod sparkline_chart;
 pub mod status_header;
 pub mod table;
 pub mod text_box;
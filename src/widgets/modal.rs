@@ -0,0 +1,99 @@
+//! Modal dialog widget - centered, bordered panel over a dimmed backdrop
+//!
+//! A modal is built as its own layout root and rendered in an overlay pass
+//! after charts, so it sits above everything except tooltips and toasts.
+//! While one is open, keyboard focus is trapped to the modal's focusable panels.
+
+use crate::base::layout::{HAlign, VAlign};
+use crate::base::{panel, taffy, PanelBuilder};
+use taffy::prelude::*;
+
+use super::theme::GlTheme;
+
+/// Backdrop dimming over the view behind the modal
+const BACKDROP_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.6];
+
+/// Corner radius of the dialog box
+const DIALOG_RADIUS: f32 = 8.0;
+
+/// Drop shadow lifting the dialog off the backdrop (offset x, offset y, color)
+const DIALOG_SHADOW: (f32, f32, [f32; 4]) = (4.0, 4.0, [0.0, 0.0, 0.0, 0.5]);
+
+/// Build a modal dialog: full-screen backdrop with the dialog centered on it
+///
+/// The title bar shows the title and the close hint; `body` is placed below
+/// it with standard padding.
+pub fn modal(title: &str, body: PanelBuilder, theme: &GlTheme) -> PanelBuilder {
+    let padding = theme.panel_padding * 2.0;
+
+    let title_bar = panel()
+        .width(percent(1.0))
+        .flex_direction(FlexDirection::Row)
+        .justify_content(JustifyContent::SpaceBetween)
+        .align_items(AlignItems::Center)
+        .padding(0.0, 0.0, theme.panel_padding, 0.0)
+        .child(
+            panel()
+                .text(title.to_uppercase(), theme.accent, theme.font_normal)
+                .text_align(HAlign::Left, VAlign::Center),
+        )
+        .child(
+            panel()
+                .text("[Esc] Close", theme.foreground_muted, theme.font_small)
+                .text_align(HAlign::Right, VAlign::Center),
+        );
+
+    let dialog = panel()
+        .flex_direction(FlexDirection::Column)
+        .gap(theme.panel_gap)
+        .padding_all(padding)
+        // Fades from the panel color into the view background toward the bottom
+        .background_gradient(theme.background_panel, theme.background)
+        .border_solid(2.0, theme.accent)
+        .corner_radius(DIALOG_RADIUS)
+        .shadow(DIALOG_SHADOW.0, DIALOG_SHADOW.1, DIALOG_SHADOW.2)
+        .child(title_bar)
+        .child(
+            panel()
+                .width(percent(1.0))
+                .height(length(1.0))
+                .background(theme.border),
+        )
+        .child(body);
+
+    panel()
+        .width(percent(1.0))
+        .height(percent(1.0))
+        .justify_content(JustifyContent::Center)
+        .align_items(AlignItems::Center)
+        .background(BACKDROP_COLOR)
+        .child(dialog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::LayoutTree;
+
+    #[test]
+    fn dialog_is_centered_over_a_full_screen_backdrop() {
+        let theme = GlTheme::default();
+        let body = panel().width(length(200.0)).height(length(100.0));
+        let mut tree = LayoutTree::new();
+        let root = modal("Test", body, &theme).build(&mut tree);
+        tree.compute(root, 800.0, 600.0);
+
+        let backdrop = tree.get_layout(root);
+        assert_eq!((backdrop.location.x, backdrop.location.y), (0.0, 0.0));
+        assert_eq!((backdrop.size.width, backdrop.size.height), (800.0, 600.0));
+        let style = tree.get_panel_style(root).unwrap();
+        assert_eq!(style.background_color, Some(BACKDROP_COLOR));
+
+        let dialog = tree.get_layout(tree.children(root)[0]);
+        // Sized to the body plus padding, centered on both axes (to the
+        // half pixel taffy's rounding leaves)
+        assert_eq!(dialog.size.width, 200.0 + theme.panel_padding * 4.0);
+        assert!((dialog.location.x + dialog.size.width / 2.0 - 400.0).abs() <= 0.5);
+        assert!((dialog.location.y + dialog.size.height / 2.0 - 300.0).abs() <= 0.5);
+    }
+}
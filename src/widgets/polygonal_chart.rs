@@ -3,50 +3,60 @@
 use crate::api::Candle;
 use crate::widgets::chart_renderer::{calculate_visible_range, ChartRenderer, PixelRect};
 use crate::widgets::chart_utils::{
-    calculate_price_bounds_from_closes, calculate_volume_bounds, render_grid, render_volume_bars,
-    ChartLayout,
+    calculate_volume_bounds, render_price_grid, render_volume_bars, resolve_price_bounds,
+    ChartLabel, ChartLayout, ChartOptions,
 };
 use crate::widgets::theme::GlTheme;
 
-/// Render a polygonal (area) chart with gradient fill, border line, and volume bars
+/// Render a polygonal (area) chart with gradient fill, border line, and volume bars.
+/// Returns text labels (e.g. percent gridlines) for the caller to draw over the chart.
 pub fn render_polygonal_chart(
     renderer: &mut ChartRenderer,
     candles: &[Candle],
-    scroll_offset: isize,
-    visible_candles: usize,
-    price_margin: f64,
+    options: &ChartOptions,
     rect: PixelRect,
     theme: &GlTheme,
-) {
+) -> Vec<ChartLabel> {
     if candles.is_empty() || rect.width <= 0.0 || rect.height <= 0.0 {
-        return;
+        return Vec::new();
     }
 
     // 1. Calculate visible range
-    let visible = calculate_visible_range(candles.len(), visible_candles, scroll_offset);
+    let visible = calculate_visible_range(
+        candles.len(),
+        options.visible_candles,
+        options.scroll_offset,
+    );
 
     let visible_slice = &candles[visible.start_idx..visible.end_idx];
     if visible_slice.is_empty() {
-        return;
+        return Vec::new();
     }
 
     // 2. Calculate bounds
-    let price_bounds = calculate_price_bounds_from_closes(visible_slice, price_margin);
-    let volume_bounds = calculate_volume_bounds(visible_slice);
+    let price_bounds = resolve_price_bounds(visible_slice, options, true);
+    let volume_bounds = calculate_volume_bounds(visible_slice, options.volume_unit);
 
     // 3. Calculate layout
-    let layout = ChartLayout::new(&rect, visible_candles);
+    let layout = ChartLayout::new(&rect, options.visible_candles);
 
-    // 4. Draw grid
-    render_grid(renderer, &layout.price_area, 4, 6, theme);
+    // 4. Draw grid (anchored at the leftmost visible close in percent mode)
+    let labels = render_price_grid(
+        renderer,
+        options.grid,
+        visible_slice[0].close,
+        &price_bounds,
+        &layout.price_area,
+        theme,
+    );
 
     // 5. Draw volume bars
     render_volume_bars(
         renderer,
         visible_slice,
         &volume_bounds,
-        &layout.volume_area,
-        layout.slot_width,
+        &layout,
+        options.volume_unit,
         0.4,
         theme,
     );
@@ -63,7 +73,7 @@ pub fn render_polygonal_chart(
         .collect();
 
     if points.len() < 2 {
-        return;
+        return labels;
     }
 
     // 7. Draw gradient filled area (normalized across chart height)
@@ -79,4 +89,6 @@ pub fn render_polygonal_chart(
 
     // 8. Draw border line on top
     renderer.draw_polyline(&points, 2.0, theme.poly_line);
+
+    labels
 }
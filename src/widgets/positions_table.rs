@@ -1,11 +1,14 @@
 //! Positions table widget for displaying margin positions
 
-use crate::api::margin::MarginPosition;
+use crate::api::margin::{MarginPosition, PositionSide};
 use crate::base::layout::HAlign;
 use crate::base::PanelBuilder;
 
-use super::format::format_price;
-use super::table::{build_table_styled, estimate_column_widths, CellBuilder, ColumnConfig, ColumnWidth, RowStyle, TableRow};
+use super::format::{format_change, format_price, format_signed_price, ValueColor};
+use super::table::{
+    build_table_styled, estimate_column_widths, CellBuilder, CellContent, ColumnConfig,
+    ColumnWidth, RowStyle, TableRow,
+};
 use super::theme::GlTheme;
 
 /// Build the positions table widget using the reusable table component
@@ -16,42 +19,12 @@ pub fn build_positions_table(
 ) -> PanelBuilder {
     // Start with proportional column definitions that will be calculated
     // These proportions represent the relative importance/typical size of each column
-    let mut columns = vec![
-        ColumnConfig::auto("ASSET", 0.0).with_align(HAlign::Left),
-        ColumnConfig::auto("AMOUNT", 0.0).with_align(HAlign::Left),
-        ColumnConfig::auto("PRICE", 0.0).with_align(HAlign::Left),
-        ColumnConfig::auto("BORROWED VAL", 0.0).with_align(HAlign::Left),
-        ColumnConfig::auto("NET VALUE", 0.0).with_align(HAlign::Left),
-    ];
+    let mut columns = position_columns();
 
     // Convert positions to table rows
     let rows: Vec<TableRow> = positions
         .iter()
-        .map(|pos| {
-            // Color for borrowed (red if borrowed, normal if not)
-            let borrowed_color = if pos.borrowed > 0.0001 {
-                theme.negative
-            } else {
-                theme.foreground
-            };
-
-            // Color for net value (green if positive, red if negative)
-            let net_color = if pos.net_value_usd > 0.0 {
-                theme.positive
-            } else if pos.net_value_usd < 0.0 {
-                theme.negative
-            } else {
-                theme.foreground
-            };
-
-            vec![
-                CellBuilder::text(&pos.asset, theme.foreground),
-                CellBuilder::text(&format!("{:.4}", pos.free + pos.locked), theme.foreground),
-                CellBuilder::text(&format_price(pos.current_price), theme.foreground),
-                CellBuilder::text(&format_price(pos.borrowed_value_usd), borrowed_color),
-                CellBuilder::text(&format_price(pos.net_value_usd), net_color),
-            ]
-        })
+        .map(|pos| position_row(pos, theme))
         .collect();
 
     // Calculate minimum column widths based on content
@@ -88,5 +61,169 @@ pub fn build_positions_table(
         })
         .collect();
 
-    build_table_styled(&columns, &rows, &row_styles, theme)
+    // Keep the asset column in view when the rest don't fit
+    build_table_styled(&columns, &rows, &row_styles, true, theme)
+}
+
+/// Column definitions; session PnL columns are colored by sign
+///
+/// Binance reports no entry price, so PnL is measured from the mark when this
+/// session first saw the position (see `MarginPosition::session_entry`).
+fn position_columns() -> Vec<ColumnConfig> {
+    vec![
+        ColumnConfig::auto("ASSET", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("SIDE", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("AMOUNT", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("PRICE", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("BORROWED VAL", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("NET VALUE", 0.0).with_align(HAlign::Left),
+        ColumnConfig::auto("SESSION PNL", 0.0)
+            .with_align(HAlign::Left)
+            .with_value_color(ValueColor::Sign),
+        ColumnConfig::auto("SESSION PNL %", 0.0)
+            .with_align(HAlign::Left)
+            .with_value_color(ValueColor::Sign),
+    ]
+}
+
+fn position_row(pos: &MarginPosition, theme: &GlTheme) -> TableRow {
+    // Color for borrowed (red if borrowed, normal if not)
+    let borrowed_color = if pos.borrowed > 0.0001 {
+        theme.negative
+    } else {
+        theme.foreground
+    };
+
+    // Shorts gain when price falls, so their negative net value is the open short,
+    // not a loss: color by side instead of by sign
+    let side = pos.side();
+    let (side_color, net_color) = match side {
+        PositionSide::Long => (theme.positive, theme.positive),
+        PositionSide::Short => (theme.negative, theme.neutral),
+        PositionSide::Flat => (theme.foreground_muted, theme.foreground),
+    };
+
+    vec![
+        CellBuilder::text(&pos.asset, theme.foreground),
+        CellBuilder::text(side.label(), side_color),
+        CellBuilder::text(&format!("{:.4}", pos.free + pos.locked), theme.foreground),
+        CellBuilder::text(&format_price(pos.current_price), theme.foreground),
+        CellBuilder::text(&format_price(pos.borrowed_value_usd), borrowed_color),
+        CellBuilder::text(&format_price(pos.net_value_usd), net_color),
+        // Colored by the column: PnL is already signed for the side
+        pnl_cell(pos.session_pnl.map(format_signed_price), theme),
+        pnl_cell(pos.session_pnl_percent().map(format_change), theme),
+    ]
+}
+
+/// PnL cell text, or a muted dash while the session entry is unknown
+fn pnl_cell(text: Option<String>, theme: &GlTheme) -> CellContent {
+    match text {
+        Some(text) => CellBuilder::text(text, theme.foreground),
+        None => CellBuilder::text("—", theme.foreground_muted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::margin::MarginAccount;
+    use crate::widgets::table::text_color;
+
+    /// Position of `net_asset` entered at 100.0, marked to `mark`
+    fn marked(net_asset: f64, mark: f64) -> MarginPosition {
+        let mut account = MarginAccount {
+            margin_level: 999.0,
+            total_asset_usd: 0.0,
+            total_liability_usd: 0.0,
+            total_net_usd: 0.0,
+            positions: vec![MarginPosition {
+                asset: "BTC".to_string(),
+                borrowed: (-net_asset).max(0.0),
+                free: net_asset.max(0.0),
+                interest: 0.0,
+                locked: 0.0,
+                net_asset,
+                current_price: 100.0,
+                session_entry: Some(100.0),
+                total_value_usd: 0.0,
+                borrowed_value_usd: 0.0,
+                net_value_usd: 0.0,
+                session_pnl: Some(0.0),
+            }],
+            account_type: "Cross Margin".to_string(),
+        };
+        assert!(account.apply_mark_price("BTC", mark));
+        account.positions.remove(0)
+    }
+
+    /// Rendered color of each cell, after the column's value coloring
+    fn cell_colors(pos: &MarginPosition, theme: &GlTheme) -> Vec<(String, [f32; 4])> {
+        position_columns()
+            .iter()
+            .zip(position_row(pos, theme))
+            .map(|(col, cell)| match cell {
+                CellContent::Text { text, color } => {
+                    let color = text_color(col, &text, color, theme);
+                    (text, color)
+                }
+                CellContent::Panel(_) => unreachable!("positions use text cells"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn side_and_net_value_colors_follow_the_side() {
+        let theme = GlTheme::default();
+        let cases = [
+            (1.0, "LONG", theme.positive, theme.positive),
+            // An open short's negative net value is not a loss
+            (-1.0, "SHORT", theme.negative, theme.neutral),
+            (0.0, "FLAT", theme.foreground_muted, theme.foreground),
+        ];
+        for (net_asset, side, side_color, net_color) in cases {
+            let cells = cell_colors(&marked(net_asset, 100.0), &theme);
+            assert_eq!(cells[1], (side.to_string(), side_color));
+            assert_eq!(cells[5].1, net_color, "{}", side);
+        }
+    }
+
+    #[test]
+    fn pnl_colors_follow_profit_for_both_sides() {
+        let theme = GlTheme::default();
+        let cases = [
+            ("long profit", 1.0, 110.0, "LONG", theme.positive),
+            ("long loss", 1.0, 90.0, "LONG", theme.negative),
+            ("short profit", -1.0, 90.0, "SHORT", theme.positive),
+            ("short loss", -1.0, 110.0, "SHORT", theme.negative),
+        ];
+        for (name, net_asset, mark, side, expected) in cases {
+            let cells = cell_colors(&marked(net_asset, mark), &theme);
+            assert_eq!(cells[1].0, side, "{}", name);
+            // SESSION PNL and SESSION PNL % columns
+            assert_eq!(cells[6].1, expected, "{}: {}", name, cells[6].0);
+            assert_eq!(cells[7].1, expected, "{}: {}", name, cells[7].0);
+        }
+    }
+
+    #[test]
+    fn unmoved_position_has_muted_pnl() {
+        let theme = GlTheme::default();
+        let cells = cell_colors(&marked(1.0, 100.0), &theme);
+        assert_eq!(cells[6].1, theme.foreground_muted);
+        assert_eq!(cells[7].1, theme.foreground_muted);
+    }
+
+    #[test]
+    fn unknown_entry_shows_a_dash() {
+        let theme = GlTheme::default();
+        let pos = MarginPosition {
+            session_entry: None,
+            session_pnl: None,
+            ..marked(1.0, 100.0)
+        };
+        let cells = cell_colors(&pos, &theme);
+        assert_eq!(cells[6], ("—".to_string(), theme.foreground_muted));
+        assert_eq!(cells[7], ("—".to_string(), theme.foreground_muted));
+    }
 }
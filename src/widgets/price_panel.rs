@@ -5,28 +5,50 @@ use crate::app::TimeWindow;
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
-use super::format::{format_change, format_price, format_price_short, price_change_color};
+use super::format::{
+    format_change, format_price_compact, format_price_grouped, format_range_labels,
+    in_neutral_zone, price_change_color, pulse_color, ValueColor,
+};
 use super::theme::GlTheme;
 use crate::mock::CoinData;
 
+/// How the big price is formatted and colored
+#[derive(Clone, Copy)]
+pub struct PriceStyle {
+    /// Abbreviate the price, e.g. "$67.4K"
+    pub compact: bool,
+    /// Thousands separators, "$67,432.10" vs "$67432.10"
+    pub group_thousands: bool,
+    /// Ticks smaller than this percentage keep the neutral color and no arrow
+    pub neutral_zone_pct: f64,
+}
+
 /// Build the price panel - 3 inline columns: Price+Arrow, Change, High/Low
+///
+/// `show_range` = false drops the High/Low column on narrow layouts.
 pub fn build_price_panel(
     coin: &CoinData,
-    time_window: TimeWindow,
+    time_window: &TimeWindow,
+    pulse: f32,
+    style: PriceStyle,
+    show_range: bool,
     theme: &GlTheme,
 ) -> PanelBuilder {
-    let price_text = format_price(coin.price);
+    let price_text = if style.compact {
+        format_price_compact(coin.price)
+    } else {
+        format_price_grouped(coin.price, style.group_thousands)
+    };
     let gap = theme.panel_gap;
 
     // For 1d window, use Binance's actual 24h values (rolling, accurate)
     // For other windows, calculate from candles
-    let (change_pct, high, low) = match time_window {
-        TimeWindow::Day1 => (coin.change_24h, coin.high_24h, coin.low_24h),
-        _ => {
-            let change = coin.candle_change();
-            let (h, l) = coin.candle_high_low();
-            (change, h, l)
-        }
+    let (change_pct, high, low) = if time_window.is_daily() {
+        (coin.change_24h, coin.high_24h, coin.low_24h)
+    } else {
+        let change = coin.candle_change();
+        let (h, l) = coin.candle_high_low();
+        (change, h, l)
     };
 
     let change_text = format_change(change_pct);
@@ -34,23 +56,21 @@ pub fn build_price_panel(
     // Arrow and price color based on tick direction
     let price_delta = coin.price - coin.prev_price;
     let avg_change = coin.avg_change();
-    let (arrow, price_color) = if price_delta > f64::EPSILON {
-        let color = price_change_color(coin.price, coin.prev_price, avg_change, theme);
+    let neutral_zone = style.neutral_zone_pct;
+    let color = price_change_color(coin.price, coin.prev_price, avg_change, neutral_zone, theme);
+    let (arrow, price_color) = if in_neutral_zone(coin.price, coin.prev_price, neutral_zone) {
+        (" ", theme.foreground_muted)
+    } else if price_delta > f64::EPSILON {
         ("▲", color)
     } else if price_delta < -f64::EPSILON {
-        let color = price_change_color(coin.price, coin.prev_price, avg_change, theme);
         ("▼", color)
     } else {
         (" ", theme.foreground_muted) // Neutral: muted color, invisible arrow
     };
+    // Brief brighten on each tick so the feed reads as live even when the price is unchanged
+    let price_text_color = pulse_color(price_color, theme.accent, pulse);
 
-    let change_color = if change_pct > 0.0 {
-        theme.positive
-    } else if change_pct < 0.0 {
-        theme.negative
-    } else {
-        theme.foreground_muted
-    };
+    let change_color = ValueColor::Sign.color(change_pct, theme);
 
     // Range bar position (0.0 to 1.0)
     let range = high - low;
@@ -60,11 +80,12 @@ pub fn build_price_panel(
         0.5
     };
 
-    let low_text = format!("L:{}", format_price_short(low));
-    let high_text = format!("H:{}", format_price_short(high));
+    let (low_label, high_label) = format_range_labels(low, high);
+    let low_text = format!("L:{}", low_label);
+    let high_text = format!("H:{}", high_label);
 
     // Single row with 3 columns
-    panel()
+    let row = panel()
         .width(percent(1.0))
         .flex_direction(FlexDirection::Row)
         .align_items(AlignItems::Center)
@@ -75,7 +96,7 @@ pub fn build_price_panel(
                 .flex_direction(FlexDirection::Row)
                 .align_items(AlignItems::Center)
                 .gap(gap / 2.0)
-                .child(panel().text(&price_text, price_color, theme.font_big))
+                .child(panel().text(&price_text, price_text_color, theme.font_big))
                 .child(panel().text(arrow, price_color, theme.font_medium)),
         )
         // Column 2: Change
@@ -86,18 +107,23 @@ pub fn build_price_panel(
                 .gap(gap / 2.0)
                 .child(panel().text("CHANGE:", theme.foreground_muted, theme.font_medium))
                 .child(panel().text(&change_text, change_color, theme.font_medium)),
-        )
-        // Column 3: High/Low bar (grows to fill)
-        .child(
-            panel()
-                .flex_grow(1.0)
-                .flex_direction(FlexDirection::Row)
-                .align_items(AlignItems::Center)
-                .gap(gap)
-                .child(panel().text(&low_text, theme.foreground_muted, theme.font_medium))
-                .child(build_range_indicator(range_pos, theme))
-                .child(panel().text(&high_text, theme.foreground_muted, theme.font_medium)),
-        )
+        );
+
+    if !show_range {
+        return row;
+    }
+
+    // Column 3: High/Low bar (grows to fill)
+    row.child(
+        panel()
+            .flex_grow(1.0)
+            .flex_direction(FlexDirection::Row)
+            .align_items(AlignItems::Center)
+            .gap(gap)
+            .child(panel().text(&low_text, theme.foreground_muted, theme.font_medium))
+            .child(build_range_indicator(range_pos, theme))
+            .child(panel().text(&high_text, theme.foreground_muted, theme.font_medium)),
+    )
 }
 
 /// Build range indicator with dim bar and triangle marker
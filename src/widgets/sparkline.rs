@@ -0,0 +1,126 @@
+//! Text sparklines for the coin table
+//!
+//! Each style maps the series onto a different glyph set; all glyphs used here
+//! are rasterized into the font atlas (block elements and the braille range).
+
+use crate::config::SparklineStyle;
+
+/// Block bars, lowest to highest
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// First codepoint of the braille patterns block
+const BRAILLE_BASE: u32 = 0x2800;
+/// Braille dot bits per row, bottom to top, for the left and right columns
+const BRAILLE_LEFT: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+const BRAILLE_RIGHT: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+
+/// Render `values` as a one-line sparkline in the given style
+///
+/// Bars and dots use one glyph per value; the braille line packs two values
+/// per glyph, so it is half as wide at four rows of vertical resolution.
+pub fn sparkline_text(values: &[u64], style: SparklineStyle) -> String {
+    match style {
+        SparklineStyle::Bars => render_bars(values),
+        SparklineStyle::Braille | SparklineStyle::Line => render_braille_line(values),
+        SparklineStyle::Dots => render_dots(values),
+    }
+}
+
+fn render_bars(values: &[u64]) -> String {
+    levels(values, BARS.len())
+        .into_iter()
+        .map(|level| BARS[level])
+        .collect()
+}
+
+fn render_dots(values: &[u64]) -> String {
+    levels(values, BRAILLE_LEFT.len())
+        .into_iter()
+        .map(|level| braille(BRAILLE_LEFT[level]))
+        .collect()
+}
+
+/// Connected line: each column fills the rows between the previous value and its own
+fn render_braille_line(values: &[u64]) -> String {
+    let levels = levels(values, BRAILLE_LEFT.len());
+    let mut cells = vec![0u32; levels.len().div_ceil(2)];
+
+    for (i, &level) in levels.iter().enumerate() {
+        let prev = if i > 0 { levels[i - 1] } else { level };
+        let column = if i % 2 == 0 {
+            &BRAILLE_LEFT
+        } else {
+            &BRAILLE_RIGHT
+        };
+        let rows = &column[prev.min(level)..=prev.max(level)];
+        cells[i / 2] |= rows.iter().fold(0, |bits, row| bits | row);
+    }
+
+    cells.into_iter().map(braille).collect()
+}
+
+fn braille(bits: u32) -> char {
+    char::from_u32(BRAILLE_BASE + bits).unwrap_or(' ')
+}
+
+/// Scale values to 0..count-1 across the series' own min/max (flat series sit mid-height)
+fn levels(values: &[u64], count: usize) -> Vec<usize> {
+    let min = values.iter().copied().min().unwrap_or(0);
+    let max = values.iter().copied().max().unwrap_or(0);
+    let top = (count - 1) as f64;
+
+    values
+        .iter()
+        .map(|&v| {
+            if max == min {
+                count / 2
+            } else {
+                (((v - min) as f64 / (max - min) as f64) * top).round() as usize
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERIES: [u64; 7] = [10, 20, 30, 40, 30, 20, 10];
+
+    fn glyphs(style: SparklineStyle, values: &[u64]) -> usize {
+        sparkline_text(values, style).chars().count()
+    }
+
+    #[test]
+    fn each_style_has_its_expected_length() {
+        assert_eq!(glyphs(SparklineStyle::Bars, &SERIES), 7);
+        assert_eq!(glyphs(SparklineStyle::Dots, &SERIES), 7);
+        // Two values per braille cell, rounded up
+        assert_eq!(glyphs(SparklineStyle::Braille, &SERIES), 4);
+        assert_eq!(glyphs(SparklineStyle::Line, &SERIES[..6]), 3);
+        for style in [
+            SparklineStyle::Bars,
+            SparklineStyle::Braille,
+            SparklineStyle::Dots,
+        ] {
+            assert_eq!(glyphs(style, &[]), 0);
+        }
+    }
+
+    #[test]
+    fn bars_span_lowest_to_highest() {
+        assert_eq!(sparkline_text(&[0, 7, 14], SparklineStyle::Bars), "▁▅█");
+        // Flat series sit mid-height
+        assert_eq!(sparkline_text(&[5, 5], SparklineStyle::Bars), "▅▅");
+    }
+
+    #[test]
+    fn braille_line_connects_rows() {
+        // Bottom-left dot, then the right column filled bottom to top
+        assert_eq!(sparkline_text(&[0, 3], SparklineStyle::Braille), "\u{28F8}");
+        assert_eq!(
+            sparkline_text(&[0, 3], SparklineStyle::Dots),
+            "\u{2840}\u{2801}"
+        );
+    }
+}
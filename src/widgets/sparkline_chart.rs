@@ -0,0 +1,51 @@
+//! Inline sparkline charts for the coin table ("line" sparkline style)
+//!
+//! Table cells can't draw GL geometry during layout, so the trend cell is an
+//! empty panel tagged `SPARKLINE_PANEL_PREFIX` + coin index. After the layout
+//! pass the render loop looks those panels up and draws a polyline into each.
+
+use crate::widgets::chart_renderer::{ChartRenderer, PixelRect};
+
+/// Marker id prefix for sparkline cells (followed by the coin index)
+pub const SPARKLINE_PANEL_PREFIX: &str = "sparkline_";
+
+/// Fraction of the cell height left empty above and below the line
+const VERTICAL_INSET: f32 = 0.2;
+
+const LINE_THICKNESS: f32 = 1.5;
+
+/// Draw `values` as a polyline scaled to fill `rect` (a flat series sits mid-height)
+pub fn render_sparkline_chart(
+    renderer: &mut ChartRenderer,
+    values: &[u64],
+    rect: PixelRect,
+    color: [f32; 4],
+) {
+    if values.len() < 2 || rect.width <= 0.0 || rect.height <= 0.0 {
+        return;
+    }
+
+    let min = values.iter().copied().min().unwrap_or(0);
+    let max = values.iter().copied().max().unwrap_or(0);
+    let range = (max - min) as f32;
+    let inset = rect.height * VERTICAL_INSET;
+    let usable = rect.height - inset * 2.0;
+    let step = rect.width / (values.len() - 1) as f32;
+
+    let points: Vec<(f32, f32)> = values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let level = if range > 0.0 {
+                (value - min) as f32 / range
+            } else {
+                0.5
+            };
+            let x = rect.x + i as f32 * step;
+            let y = rect.y + inset + usable * (1.0 - level);
+            (x, y)
+        })
+        .collect();
+
+    renderer.draw_polyline(&points, LINE_THICKNESS, color);
+}
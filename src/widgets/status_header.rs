@@ -1,80 +1,54 @@
 //! Status header widget for displaying connection status, provider, and controls
 //!
-//! Shows: [Overview] [Details] | Provider: Binance | [w] Window: 15m | [c] Chart: Candle | ● Live
+//! Shows: [Overview] [Details] | Provider: Binance | ● Live (window and chart type are in the footer)
 
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
 use super::format::capitalize;
 use super::theme::GlTheme;
-use crate::app::{ChartType, ConnectionStatus, TimeWindow, View};
+use crate::app::{ConnectionStatus, View};
 use crate::base::view::header_height;
+use crate::keymap::{Action, Keymap};
 
 /// Build the status header panel
 pub fn build_status_header(
     view: View,
     provider: &str,
-    time_window: TimeWindow,
-    chart_type: ChartType,
     connection_status: ConnectionStatus,
+    stale: bool,
     unread_count: usize,
+    keymap: &Keymap,
     theme: &GlTheme,
 ) -> PanelBuilder {
     let gap = theme.panel_gap;
     let header_height = header_height(theme); // Derived from theme sizing
 
     // View tabs
-    let (overview_color, details_color, alerts_color, news_color, positions_color) = match view {
-        View::Overview => (
-            theme.accent,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-        ),
-        View::Details => (
-            theme.foreground_inactive,
-            theme.accent,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-        ),
-        View::Notifications => (
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.accent,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-        ),
-        View::News => (
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.accent,
-            theme.foreground_inactive,
-        ),
-        View::Positions => (
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.foreground_inactive,
-            theme.accent,
-        ),
+    let tab_color = |tab: View| {
+        if tab == view {
+            theme.accent
+        } else {
+            theme.foreground_inactive
+        }
     };
 
     // Connection status
     let (status_text, status_color) = match connection_status {
+        // Connected but silent: dim the indicator so a half-open stream stands out
+        ConnectionStatus::Connected if stale => ("● Stale", theme.foreground_muted),
         ConnectionStatus::Connected => ("● Live", theme.status_live),
         ConnectionStatus::Connecting => ("◐ Connecting", theme.status_connecting),
         ConnectionStatus::Disconnected => ("○ Disconnected", theme.status_disconnected),
         ConnectionStatus::Mock => ("◆ Mock", theme.status_mock),
     };
 
-    let provider_display = capitalize(provider);
-
-    // Suppress unused warnings - these are now shown in the footer
-    let _ = time_window;
-    let _ = chart_type;
+    // "binance+coinbase" -> "Binance+Coinbase"
+    let provider_display = provider
+        .split('+')
+        .map(capitalize)
+        .collect::<Vec<_>>()
+        .join("+");
 
     panel()
         .width(percent(1.0))
@@ -90,11 +64,16 @@ pub fn build_status_header(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[Overview]", overview_color, theme.font_normal))
-                .child(panel().text("[Details]", details_color, theme.font_normal))
-                .child(build_alerts_tab(alerts_color, unread_count, theme))
-                .child(panel().text("[News]", news_color, theme.font_normal))
-                .child(panel().text("[Positions]", positions_color, theme.font_normal)),
+                .child(panel().text("[Overview]", tab_color(View::Overview), theme.font_normal))
+                .child(panel().text("[Details]", tab_color(View::Details), theme.font_normal))
+                .child(build_alerts_tab(
+                    tab_color(View::Notifications),
+                    unread_count,
+                    theme,
+                ))
+                .child(panel().text("[News]", tab_color(View::News), theme.font_normal))
+                .child(panel().text("[Positions]", tab_color(View::Positions), theme.font_normal))
+                .child(panel().text("[Diag]", tab_color(View::Diagnostics), theme.font_normal)),
         )
         // Spacer
         .child(panel().flex_grow(1.0))
@@ -113,7 +92,11 @@ pub fn build_status_header(
             panel()
                 .flex_direction(FlexDirection::Row)
                 .gap(gap / 2.0)
-                .child(panel().text("[q]", theme.accent_secondary, theme.font_normal))
+                .child(panel().text(
+                    format!("[{}]", keymap.label(Action::Quit)),
+                    theme.accent_secondary,
+                    theme.font_normal,
+                ))
                 .child(panel().text("Quit", theme.foreground, theme.font_normal)),
         )
 }
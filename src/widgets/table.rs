@@ -7,6 +7,7 @@
 //! - Hybrid cell content (text or custom PanelBuilder)
 //! - Scrollable content with fixed header
 //! - Optional per-row styling
+//! - Optional frozen first column that stays put while the others are clipped
 //!
 //! # Examples
 //!
@@ -30,7 +31,7 @@
 //! ];
 //!
 //! let columns = estimate_column_widths(&columns, &rows, theme.font_size, theme.font_normal, theme.panel_gap);
-//! let table = build_table(&columns, &rows, theme);
+//! let table = build_table(&columns, &rows, false, theme);
 //! ```
 
 use crate::base::font_atlas::FontAtlas;
@@ -38,6 +39,7 @@ use crate::base::layout::{HAlign, VAlign};
 use crate::base::{panel, taffy, PanelBuilder};
 use taffy::prelude::*;
 
+use super::format::ValueColor;
 use super::theme::GlTheme;
 
 /// Column width specification
@@ -60,6 +62,8 @@ pub struct ColumnConfig {
     pub width: ColumnWidth,
     /// Horizontal alignment for cells
     pub align: HAlign,
+    /// Color text cells by their numeric value instead of the cell's own color
+    pub value_color: Option<ValueColor>,
 }
 
 impl ColumnConfig {
@@ -73,6 +77,7 @@ impl ColumnConfig {
             header: header.into(),
             width: ColumnWidth::Fixed(width),
             align: HAlign::Left,
+            value_color: None,
         }
     }
 
@@ -86,6 +91,7 @@ impl ColumnConfig {
             header: header.into(),
             width: ColumnWidth::Auto(width),
             align: HAlign::Left,
+            value_color: None,
         }
     }
 
@@ -99,6 +105,7 @@ impl ColumnConfig {
             header: header.into(),
             width: ColumnWidth::Flex(grow),
             align: HAlign::Left,
+            value_color: None,
         }
     }
 
@@ -107,6 +114,13 @@ impl ColumnConfig {
         self.align = align;
         self
     }
+
+    /// Color this column's text cells by value (e.g. `ValueColor::Sign` for
+    /// change and PnL, `RSI_BANDS` for RSI); non-numeric cells keep their color
+    pub fn with_value_color(mut self, value_color: ValueColor) -> Self {
+        self.value_color = Some(value_color);
+        self
+    }
 }
 
 /// Cell content - supports both strings and custom panels
@@ -263,6 +277,7 @@ pub fn estimate_column_widths(
 /// # Arguments
 /// * `columns` - Column configurations (with calculated widths)
 /// * `rows` - Row data
+/// * `freeze_first_column` - Pin the first column (see `build_frozen_table`)
 /// * `theme` - Theme for styling
 ///
 /// # Returns
@@ -270,12 +285,17 @@ pub fn estimate_column_widths(
 pub fn build_table(
     columns: &[ColumnConfig],
     rows: &[TableRow],
+    freeze_first_column: bool,
     theme: &GlTheme,
 ) -> PanelBuilder {
     if rows.is_empty() {
         return build_empty_table(theme);
     }
 
+    if freeze_first_column && columns.len() > 1 {
+        return build_frozen_table(columns, rows, &[], theme);
+    }
+
     // Build header
     let header = build_table_header(columns, theme);
 
@@ -342,7 +362,11 @@ fn build_table_row(row: &TableRow, columns: &[ColumnConfig], theme: &GlTheme) ->
 
         let mut cell = match cell_content {
             Some(CellContent::Text { text, color }) => panel()
-                .text(text, *color, theme.font_normal)
+                .text(
+                    text,
+                    text_color(col, text, *color, theme),
+                    theme.font_normal,
+                )
                 .text_align(col.align, VAlign::Center),
             Some(CellContent::Panel(builder_fn)) => {
                 // Call the builder function to create custom panel
@@ -364,6 +388,18 @@ fn build_table_row(row: &TableRow, columns: &[ColumnConfig], theme: &GlTheme) ->
     row_panel
 }
 
+/// A text cell's color: the column's value color when it applies, else the cell's own
+pub(super) fn text_color(
+    col: &ColumnConfig,
+    text: &str,
+    color: [f32; 4],
+    theme: &GlTheme,
+) -> [f32; 4] {
+    col.value_color
+        .and_then(|value_color| value_color.color_for_text(text, theme))
+        .unwrap_or(color)
+}
+
 fn build_empty_table(theme: &GlTheme) -> PanelBuilder {
     panel()
         .flex_grow(1.0)
@@ -378,6 +414,7 @@ fn build_empty_table(theme: &GlTheme) -> PanelBuilder {
 /// * `columns` - Column configurations (with calculated widths)
 /// * `rows` - Row data
 /// * `row_styles` - Per-row styling configuration
+/// * `freeze_first_column` - Pin the first column (see `build_frozen_table`)
 /// * `theme` - Theme for styling
 ///
 /// # Returns
@@ -386,12 +423,17 @@ pub fn build_table_styled(
     columns: &[ColumnConfig],
     rows: &[TableRow],
     row_styles: &[RowStyle],
+    freeze_first_column: bool,
     theme: &GlTheme,
 ) -> PanelBuilder {
     if rows.is_empty() {
         return build_empty_table(theme);
     }
 
+    if freeze_first_column && columns.len() > 1 {
+        return build_frozen_table(columns, rows, row_styles, theme);
+    }
+
     let header = build_table_header(columns, theme);
 
     let row_panels: Vec<PanelBuilder> = rows
@@ -414,7 +456,7 @@ pub fn build_table_styled(
 }
 
 fn build_table_row_styled(
-    row: &TableRow,
+    row: &[CellContent],
     columns: &[ColumnConfig],
     style: &RowStyle,
     theme: &GlTheme,
@@ -438,7 +480,11 @@ fn build_table_row_styled(
 
         let mut cell = match cell_content {
             Some(CellContent::Text { text, color }) => panel()
-                .text(text, *color, theme.font_normal)
+                .text(
+                    text,
+                    text_color(col, text, *color, theme),
+                    theme.font_normal,
+                )
                 .text_align(col.align, VAlign::Center),
             Some(CellContent::Panel(builder_fn)) => builder_fn(theme),
             None => panel(),
@@ -455,3 +501,79 @@ fn build_table_row_styled(
 
     row_panel
 }
+
+/// Build a table whose first column is pinned
+///
+/// The first column lives in its own fixed panel; the remaining columns sit
+/// beside it in a region that clips horizontally, so on a display too narrow
+/// for every column the symbol/asset column stays visible. Both halves build
+/// each header and body row at the same height (and with the same row style),
+/// which keeps them aligned row for row.
+fn build_frozen_table(
+    columns: &[ColumnConfig],
+    rows: &[TableRow],
+    row_styles: &[RowStyle],
+    theme: &GlTheme,
+) -> PanelBuilder {
+    let gap = theme.panel_gap;
+    let (frozen_columns, rest_columns) = columns.split_at(1);
+
+    // Each half keeps the outer edge of the row padding
+    let frozen_row = |row: PanelBuilder| row.padding(gap / 2.0, 0.0, gap / 2.0, gap);
+    let rest_row = |row: PanelBuilder| row.padding(gap / 2.0, gap, gap / 2.0, 0.0);
+
+    let mut frozen = panel()
+        .flex_direction(FlexDirection::Column)
+        .clip(true)
+        .child(frozen_row(build_table_header(frozen_columns, theme)));
+    let mut rest = panel()
+        .flex_direction(FlexDirection::Column)
+        .overflow_scroll()
+        .clip(true)
+        .child(rest_row(build_table_header(rest_columns, theme)));
+
+    for (idx, row) in rows.iter().enumerate() {
+        let style = row_styles.get(idx).cloned().unwrap_or_default();
+        let (frozen_cells, rest_cells) = row.split_at(row.len().min(1));
+        frozen = frozen.child(frozen_row(build_table_row_styled(
+            frozen_cells,
+            frozen_columns,
+            &style,
+            theme,
+        )));
+        rest = rest.child(rest_row(build_table_row_styled(
+            rest_cells,
+            rest_columns,
+            &style,
+            theme,
+        )));
+    }
+
+    // Split the width the way the columns would have shared it in one row
+    let rest_grow: f32 = rest_columns
+        .iter()
+        .map(|col| match col.width {
+            ColumnWidth::Flex(grow) => grow,
+            ColumnWidth::Fixed(_) | ColumnWidth::Auto(_) => 0.0,
+        })
+        .sum();
+    let (frozen, rest) = match frozen_columns[0].width {
+        ColumnWidth::Fixed(w) | ColumnWidth::Auto(w) => (
+            frozen.width(length(w + gap)).flex_shrink(0.0),
+            rest.proportion(1.0),
+        ),
+        ColumnWidth::Flex(grow) if rest_grow > 0.0 => {
+            (frozen.proportion(grow), rest.proportion(rest_grow))
+        }
+        ColumnWidth::Flex(grow) => (frozen.proportion(grow), rest.proportion(1.0)),
+    };
+
+    panel()
+        .width(percent(1.0))
+        .flex_grow(1.0)
+        .flex_direction(FlexDirection::Row)
+        .overflow_scroll()
+        .clip(true)
+        .child(frozen)
+        .child(rest)
+}
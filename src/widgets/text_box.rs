@@ -8,7 +8,7 @@ use std::sync::OnceLock;
 
 /// Build a scrollable text box panel. Wrapping and line fitting are handled in the renderer
 /// based on the panel's computed size, so callers don't need to pass dimensions.
-pub fn build_text_box(text: &str, scroll_offset: usize, theme: &GlTheme) -> PanelBuilder {
+pub fn build_text_box(text: &str, scroll_offset: f32, theme: &GlTheme) -> PanelBuilder {
     use taffy::prelude::*;
 
     let line_gap = 2.0;
@@ -44,6 +44,50 @@ pub fn build_text_box(text: &str, scroll_offset: usize, theme: &GlTheme) -> Pane
         .text_align(HAlign::Left, VAlign::Top)
 }
 
+/// Wrap text to at most `max_chars` columns per line
+///
+/// Same reflow the text box renderer applies in pixels, counted in monospace
+/// cells instead (never bytes): CJK, fullwidth forms and emoji take two cells
+/// like their glyphs do, so mixed CJK/latin text and unbroken tokens like URLs
+/// wrap at the same boundaries on a character grid. Test-only reference for
+/// the shared wrapping; the renderer measures real glyph advances.
+#[cfg(test)]
+pub fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    use crate::base::grapheme::{graphemes, wrap_by_width};
+
+    fn cell_width(cluster: &str) -> f32 {
+        let wide = cluster.chars().next().is_some_and(|ch| {
+            matches!(ch as u32,
+                0x1100..=0x115F       // Hangul Jamo
+                | 0x2E80..=0x303E     // CJK radicals, punctuation
+                | 0x3041..=0x33FF     // Kana, CJK compatibility
+                | 0x3400..=0x4DBF     // CJK Extension A
+                | 0x4E00..=0x9FFF     // CJK Unified Ideographs
+                | 0xA000..=0xA4CF     // Yi
+                | 0xAC00..=0xD7A3     // Hangul syllables
+                | 0xF900..=0xFAFF     // CJK compatibility ideographs
+                | 0xFE30..=0xFE4F     // CJK compatibility forms
+                | 0xFF00..=0xFF60     // Fullwidth forms
+                | 0xFFE0..=0xFFE6     // Fullwidth signs
+                | 0x1F1E6..=0x1F1FF   // Regional indicators (flags)
+                | 0x1F300..=0x1F64F   // Pictographs, emoticons
+                | 0x1F680..=0x1F6FF   // Transport and map symbols
+                | 0x1F900..=0x1F9FF   // Supplemental symbols and pictographs
+                | 0x20000..=0x3FFFD   // CJK Extensions B+
+            )
+        });
+        if wide {
+            2.0
+        } else {
+            1.0
+        }
+    }
+
+    wrap_by_width(text, max_chars.max(1) as f32, |run| {
+        graphemes(run).into_iter().map(cell_width).sum()
+    })
+}
+
 /// Get the advance width for a single character at the given pixel size
 pub fn char_width_px(ch: char, font_size_px: f32) -> f32 {
     let metrics = font().metrics(ch, font_size_px);
@@ -60,3 +104,38 @@ fn font() -> &'static Font {
         .expect("failed to load bundled font")
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_at_word_boundaries() {
+        assert_eq!(
+            wrap_text("BTC breaks above the range high", 12),
+            ["BTC breaks", "above the", "range high"]
+        );
+    }
+
+    #[test]
+    fn long_tokens_are_hard_broken() {
+        assert_eq!(
+            wrap_text("see https://example.com/abc now", 10),
+            ["see", "https://ex", "ample.com/", "abc now"]
+        );
+    }
+
+    #[test]
+    fn wide_characters_take_two_columns() {
+        // 8 kana/ideographs: 24 bytes, 8 chars, 16 columns
+        assert_eq!(
+            wrap_text("ビットコイン急騰 BTC up", 6),
+            ["ビット", "コイン", "急騰", "BTC up"]
+        );
+        assert_eq!(wrap_text("価格 price", 10), ["価格 price"]);
+        assert_eq!(
+            wrap_text("\u{1F680}\u{1F680}\u{1F680} moon", 4),
+            ["\u{1F680}\u{1F680}", "\u{1F680}", "moon"]
+        );
+    }
+}
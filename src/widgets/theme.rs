@@ -26,6 +26,8 @@ pub struct GlTheme {
     pub indicator_primary: Color,
     pub indicator_secondary: Color,
     pub indicator_tertiary: Color,
+    /// Bollinger Bands edges (the fill uses the same color at low alpha)
+    pub indicator_band: Color,
     // Polygonal chart colors
     pub poly_fill_top: Color,
     pub poly_fill_bottom: Color,
@@ -75,6 +77,7 @@ impl Default for GlTheme {
             indicator_primary: [1.0, 0.647, 0.0, 1.0], // Orange
             indicator_secondary: [1.0, 0.0, 1.0, 1.0], // Magenta
             indicator_tertiary: [0.392, 0.314, 0.471, 1.0],
+            indicator_band: [0.4, 0.6, 0.9, 1.0], // Soft blue
             // Polygonal chart
             poly_fill_top: [0.2, 0.6, 1.0, 0.5], // Blue-ish, 50% opacity
             poly_fill_bottom: [0.2, 0.6, 1.0, 0.0], // Same color, 0% opacity (fade out)
@@ -136,6 +139,7 @@ impl GlTheme {
                 .unwrap_or(d.indicator_secondary),
             indicator_tertiary: parse_color(config.get("indicator.tertiary"))
                 .unwrap_or(d.indicator_tertiary),
+            indicator_band: parse_color(config.get("indicator.band")).unwrap_or(d.indicator_band),
             poly_fill_top: parse_color(config.get("poly.fill.top")).unwrap_or(d.poly_fill_top),
             poly_fill_bottom: parse_color(config.get("poly.fill.bottom"))
                 .unwrap_or(d.poly_fill_bottom),
@@ -163,6 +167,81 @@ impl GlTheme {
             font_big: d.font_big,
         }
     }
+
+    /// Copy of this theme with gamma and brightness applied to every color
+    ///
+    /// Used to compensate for displays that look washed out or too dark.
+    pub fn adjusted(&self, gamma: f32, brightness: f32) -> Self {
+        let adjust = |color: Color| apply_brightness(apply_gamma(color, gamma), brightness);
+        Self {
+            foreground: adjust(self.foreground),
+            foreground_muted: adjust(self.foreground_muted),
+            foreground_inactive: adjust(self.foreground_inactive),
+            accent: adjust(self.accent),
+            accent_secondary: adjust(self.accent_secondary),
+            positive: adjust(self.positive),
+            negative: adjust(self.negative),
+            neutral: adjust(self.neutral),
+            selection_bg: adjust(self.selection_bg),
+            status_live: adjust(self.status_live),
+            status_connecting: adjust(self.status_connecting),
+            status_disconnected: adjust(self.status_disconnected),
+            status_mock: adjust(self.status_mock),
+            candle_bullish: adjust(self.candle_bullish),
+            candle_bearish: adjust(self.candle_bearish),
+            indicator_primary: adjust(self.indicator_primary),
+            indicator_secondary: adjust(self.indicator_secondary),
+            indicator_tertiary: adjust(self.indicator_tertiary),
+            indicator_band: adjust(self.indicator_band),
+            poly_fill_top: adjust(self.poly_fill_top),
+            poly_fill_bottom: adjust(self.poly_fill_bottom),
+            poly_line: adjust(self.poly_line),
+            price_up_high: adjust(self.price_up_high),
+            price_up_mid: adjust(self.price_up_mid),
+            price_up_low: adjust(self.price_up_low),
+            price_down_high: adjust(self.price_down_high),
+            price_down_mid: adjust(self.price_down_mid),
+            price_down_low: adjust(self.price_down_low),
+            background: adjust(self.background),
+            background_panel: adjust(self.background_panel),
+            border: adjust(self.border),
+            border_focus: adjust(self.border_focus),
+            panel_gap: self.panel_gap,
+            panel_padding: self.panel_padding,
+            font_size: self.font_size,
+            font_small: self.font_small,
+            font_medium: self.font_medium,
+            font_normal: self.font_normal,
+            font_big: self.font_big,
+        }
+    }
+}
+
+/// Gamma-correct a color: channels are raised to 1/gamma, so gamma > 1 lifts
+/// midtones and gamma < 1 darkens them (alpha is untouched)
+pub fn apply_gamma(color: Color, gamma: f32) -> Color {
+    if gamma <= 0.0 || (gamma - 1.0).abs() < f32::EPSILON {
+        return color;
+    }
+    let exponent = 1.0 / gamma;
+    let [r, g, b, a] = color;
+    [
+        r.clamp(0.0, 1.0).powf(exponent),
+        g.clamp(0.0, 1.0).powf(exponent),
+        b.clamp(0.0, 1.0).powf(exponent),
+        a,
+    ]
+}
+
+/// Scale a color's RGB channels by `brightness`, clamped to 0.0-1.0
+pub fn apply_brightness(color: Color, brightness: f32) -> Color {
+    let [r, g, b, a] = color;
+    [
+        (r * brightness).clamp(0.0, 1.0),
+        (g * brightness).clamp(0.0, 1.0),
+        (b * brightness).clamp(0.0, 1.0),
+        a,
+    ]
 }
 
 /// Parse a color string (hex or named) to RGBA floats
@@ -216,3 +295,60 @@ fn parse_hex(hex: &str) -> Option<Color> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Color, expected: Color) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-4, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn gamma_lifts_or_darkens_midtones_only() {
+        let mid = [0.25, 0.5, 1.0, 0.5];
+        assert_close(
+            apply_gamma(mid, 2.0),
+            [0.5, std::f32::consts::FRAC_1_SQRT_2, 1.0, 0.5],
+        );
+        assert_close(apply_gamma(mid, 0.5), [0.0625, 0.25, 1.0, 0.5]);
+        // Neutral and invalid gammas leave the color alone
+        assert_eq!(apply_gamma(mid, 1.0), mid);
+        assert_eq!(apply_gamma(mid, 0.0), mid);
+        // Out-of-range channels are clamped before the curve
+        assert_close(
+            apply_gamma([-0.5, 1.5, 0.0, 1.0], 2.0),
+            [0.0, 1.0, 0.0, 1.0],
+        );
+    }
+
+    #[test]
+    fn brightness_scales_and_clamps_rgb() {
+        assert_close(
+            apply_brightness([0.2, 0.6, 0.8, 0.5], 1.5),
+            [0.3, 0.9, 1.0, 0.5],
+        );
+        assert_close(
+            apply_brightness([0.2, 0.6, 0.8, 0.5], 0.5),
+            [0.1, 0.3, 0.4, 0.5],
+        );
+    }
+
+    #[test]
+    fn adjusted_theme_transforms_colors_and_keeps_metrics() {
+        let theme = GlTheme::default();
+        let adjusted = theme.adjusted(2.0, 0.5);
+        assert_close(
+            adjusted.foreground,
+            apply_brightness(apply_gamma(theme.foreground, 2.0), 0.5),
+        );
+        assert_close(
+            adjusted.border_focus,
+            apply_brightness(apply_gamma(theme.border_focus, 2.0), 0.5),
+        );
+        assert_eq!(adjusted.font_size, theme.font_size);
+        assert_eq!(adjusted.panel_gap, theme.panel_gap);
+    }
+}